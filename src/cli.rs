@@ -0,0 +1,1448 @@
+use std::collections::BTreeMap;
+
+use anyhow::Context;
+
+use crate::crafting::{self, CraftNode, CraftPlan};
+use crate::diff::{CountChange, InventoryDiff};
+use crate::dps::DamageReport;
+use crate::drops::{DropEntry, DropTable, LocationReport};
+use crate::enriched::EnrichedInventory;
+use crate::foundry::FoundryReport;
+use crate::helminth::HelminthReport;
+use crate::inventory::Inventory;
+use crate::inventory::affiliation::Syndicate;
+use crate::itemdata::catalog::{self, Catalog};
+use crate::loadout::{self, Build};
+use crate::mastery::MasteryReport;
+use crate::metrics::{self, Aggregation, MetricsSample, MetricsStore, Point};
+use crate::primes::PrimeReport;
+use crate::quests::QuestReport;
+use crate::relics::{Refinement, RelicReport};
+use crate::riven::{self, Riven};
+use crate::session;
+use crate::shards::ShardReport;
+use crate::snapshots::SnapshotStore;
+use crate::sqlite;
+use crate::starchart::{self, NodeCatalog, StarChartReport};
+use crate::storage;
+use crate::survivability::{self, Faction, Survivability};
+use crate::syndicates::{self, SyndicateStanding};
+use crate::utils;
+use crate::wishlist::{Wishlist, WishlistReport};
+
+/// Reports available through `wf-info-2 report <name>`
+pub const REPORTS: [&str; 21] = [
+    "inventory",
+    "mastery",
+    "primes",
+    "relics",
+    "rivens",
+    "loadouts",
+    "dps",
+    "survivability",
+    "shards",
+    "helminth",
+    "syndicates",
+    "starchart",
+    "quests",
+    "foundry",
+    "craft",
+    "wishlist",
+    "drops",
+    "farm",
+    "diff",
+    "session",
+    "metrics",
+];
+
+/// Reports computed from the inventory alone, run by `offline <file> all`
+pub const OFFLINE_REPORTS: [&str; 16] = [
+    "inventory",
+    "mastery",
+    "primes",
+    "relics",
+    "rivens",
+    "loadouts",
+    "dps",
+    "survivability",
+    "shards",
+    "helminth",
+    "syndicates",
+    "starchart",
+    "quests",
+    "foundry",
+    "craft",
+    "wishlist",
+];
+
+/// Reports that read the local inventory history rather than one inventory
+const HISTORY_REPORTS: [&str; 3] = ["diff", "session", "metrics"];
+
+/// Runs `wf-info-2 report <name> [--json]` against the last saved inventory.
+pub fn run_report(args: &[String]) -> anyhow::Result<()> {
+    let inventory = storage::load_inventory()?;
+    let catalog = load_catalog()?;
+    println!("{}", render_report(args, &inventory, &catalog)?);
+    Ok(())
+}
+
+/// Runs `wf-info-2 offline <inventory file> [<name>|all] [--json]` against an inventory
+/// saved earlier, as plain JSON or an encrypted `lastData.dat`, without Warframe or the network.
+/// Without the catalog, item names fall back to their paths.
+pub fn run_offline(args: &[String]) -> anyhow::Result<()> {
+    let (path, args) = args
+        .split_first()
+        .ok_or_else(|| anyhow::anyhow!("Missing inventory file, e.g. `offline inventory.json`"))?;
+    let inventory = storage::load_inventory_file(std::path::Path::new(path))?;
+    let catalog = load_catalog().unwrap_or_else(|e| {
+        eprintln!("Warning: {:#}, showing item paths instead of names", e);
+        Catalog::default()
+    });
+
+    let name = args.iter().find(|arg| !arg.starts_with("--"));
+    match name.map(String::as_str) {
+        None | Some("all") => {
+            let flags: Vec<String> = args
+                .iter()
+                .filter(|arg| arg.starts_with("--"))
+                .cloned()
+                .collect();
+            for report in OFFLINE_REPORTS {
+                let report_args: Vec<String> = std::iter::once(report.to_string())
+                    .chain(flags.clone())
+                    .collect();
+                println!("== {} ==", report);
+                match render_report(&report_args, &inventory, &catalog) {
+                    Ok(output) => println!("{}\n", output),
+                    Err(e) => println!("Skipped: {:#}\n", e),
+                }
+            }
+        }
+        Some(name) if HISTORY_REPORTS.contains(&name) => anyhow::bail!(
+            "The {} report reads the local inventory history, use `report {}`",
+            name,
+            name
+        ),
+        Some(_) => println!("{}", render_report(args, &inventory, &catalog)?),
+    }
+    Ok(())
+}
+
+/// Output of `report <name> [target] [--json]` for the given inventory
+pub fn render_report(
+    args: &[String],
+    inventory: &Inventory,
+    catalog: &Catalog,
+) -> anyhow::Result<String> {
+    let json = args.iter().any(|arg| arg == "--json");
+    let name = args
+        .iter()
+        .find(|arg| !arg.starts_with("--"))
+        .ok_or_else(|| anyhow::anyhow!("Missing report name, one of: {}", REPORTS.join(", ")))?;
+
+    let output = match name.as_str() {
+        "inventory" => {
+            let enriched = EnrichedInventory::build(inventory, catalog);
+            if json {
+                serde_json::to_string_pretty(&enriched)?
+            } else {
+                inventory_table(&enriched)
+            }
+        }
+        "mastery" => {
+            let report = MasteryReport::build(inventory, catalog);
+            if json {
+                serde_json::to_string_pretty(&report)?
+            } else {
+                mastery_table(&report)
+            }
+        }
+        "primes" => {
+            let report = PrimeReport::build(inventory, catalog);
+            if json {
+                serde_json::to_string_pretty(&report)?
+            } else {
+                primes_table(&report)
+            }
+        }
+        "relics" => {
+            let report = RelicReport::build(inventory, catalog);
+            if json {
+                serde_json::to_string_pretty(&report)?
+            } else {
+                relics_table(&report)
+            }
+        }
+        "rivens" => {
+            let rivens = riven::decode_rivens(inventory, catalog);
+            if json {
+                serde_json::to_string_pretty(&rivens)?
+            } else {
+                rivens_table(&rivens)
+            }
+        }
+        "loadouts" => {
+            let builds = loadout::resolve_builds(inventory, catalog);
+            if json {
+                serde_json::to_string_pretty(&builds)?
+            } else {
+                builds
+                    .iter()
+                    .map(|build| {
+                        let capacity = build.capacity(catalog);
+                        let mut line = format!(
+                            "{} [capacity {}/{}]",
+                            build.summary(),
+                            capacity.used,
+                            capacity.base_capacity + capacity.bonus
+                        );
+                        let forma: Vec<String> = capacity
+                            .forma_candidates()
+                            .iter()
+                            .map(|slot| format!("{} (-{})", slot.name, slot.forma_saving()))
+                            .collect();
+                        if !forma.is_empty() {
+                            line.push_str(&format!(" forma: {}", forma.join(", ")));
+                        }
+                        line
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            }
+        }
+        "dps" => {
+            let builds = loadout::resolve_builds(inventory, catalog);
+            let reports: Vec<(&Build, DamageReport)> = builds
+                .iter()
+                .filter_map(|build| Some((build, DamageReport::for_build(build, catalog)?)))
+                .collect();
+            if json {
+                let reports: Vec<&DamageReport> =
+                    reports.iter().map(|(_, report)| report).collect();
+                serde_json::to_string_pretty(&reports)?
+            } else {
+                dps_table(&reports)
+            }
+        }
+        "survivability" => {
+            let rows = survivability::suit_survivability(inventory, catalog);
+            if json {
+                serde_json::to_string_pretty(&rows)?
+            } else {
+                survivability_table(&rows)
+            }
+        }
+        "shards" => {
+            let report = ShardReport::build(inventory, catalog);
+            if json {
+                serde_json::to_string_pretty(&report)?
+            } else {
+                shards_table(&report)
+            }
+        }
+        "helminth" => {
+            let report = HelminthReport::build(inventory, catalog)
+                .ok_or_else(|| anyhow::anyhow!("The Helminth is not unlocked on this account"))?;
+            if json {
+                serde_json::to_string_pretty(&report)?
+            } else {
+                helminth_summary(&report)
+            }
+        }
+        "syndicates" => {
+            let standings = syndicates::syndicate_standings(inventory);
+            if json {
+                serde_json::to_string_pretty(&standings)?
+            } else {
+                syndicates_table(&standings)
+            }
+        }
+        "starchart" => {
+            let nodes = load_nodes().unwrap_or_else(|e| {
+                eprintln!("Warning: {:#}, showing totals only", e);
+                NodeCatalog::default()
+            });
+            let report = StarChartReport::build(inventory, &nodes);
+            if json {
+                serde_json::to_string_pretty(&report)?
+            } else {
+                starchart_table(&report)
+            }
+        }
+        "quests" => {
+            let report = QuestReport::build(inventory, catalog);
+            if json {
+                serde_json::to_string_pretty(&report)?
+            } else {
+                quests_summary(&report)
+            }
+        }
+        "foundry" => {
+            let report = FoundryReport::build(inventory, catalog, chrono::Utc::now());
+            if json {
+                serde_json::to_string_pretty(&report)?
+            } else {
+                foundry_table(&report, chrono::Utc::now())
+            }
+        }
+        "craft" => {
+            let query = report_target(args);
+            if query.is_empty() {
+                let plans = crafting::craftable(inventory, catalog);
+                if json {
+                    serde_json::to_string_pretty(&plans)?
+                } else {
+                    craftable_table(&plans)
+                }
+            } else {
+                let item = crafting::find_item(catalog, &query)
+                    .ok_or_else(|| anyhow::anyhow!("No catalog item named '{}'", query))?;
+                let plan = CraftPlan::new(item.unique_name(), 1, inventory, catalog)
+                    .ok_or_else(|| anyhow::anyhow!("{} has no recipe", item.name()))?;
+                if json {
+                    serde_json::to_string_pretty(&plan)?
+                } else {
+                    craft_plan_summary(&plan)
+                }
+            }
+        }
+        "wishlist" => {
+            let wishlist = Wishlist::load(&Wishlist::path()?)?;
+            let report = WishlistReport::build(&wishlist, inventory, catalog);
+            if json {
+                serde_json::to_string_pretty(&report)?
+            } else {
+                wishlist_summary(&report)
+            }
+        }
+        "drops" => {
+            let location = report_target(args);
+            if location.is_empty() {
+                anyhow::bail!("Missing location, e.g. `report drops Axi K6 Relic`");
+            }
+            let table = DropTable::build(catalog);
+            let wishlist = Wishlist::load(&Wishlist::path()?)?;
+            let wishlist = WishlistReport::build(&wishlist, inventory, catalog);
+            let report = LocationReport::build(&table, &location, inventory, &wishlist);
+            if report.drops.is_empty() {
+                anyhow::bail!("Nothing in the drop tables drops at '{}'", location);
+            }
+            if json {
+                serde_json::to_string_pretty(&report)?
+            } else {
+                location_table(&report)
+            }
+        }
+        "farm" => {
+            let query = report_target(args);
+            if query.is_empty() {
+                anyhow::bail!("Missing item, e.g. `report farm Neural Sensors`");
+            }
+            let table = DropTable::build(catalog);
+            let unique_name = table
+                .find(&query)
+                .or_else(|| crafting::find_item(catalog, &query).map(|item| item.unique_name()))
+                .ok_or_else(|| anyhow::anyhow!("No drop data for '{}'", query))?;
+            let sources = table.sources(unique_name);
+            if json {
+                serde_json::to_string_pretty(&sources)?
+            } else {
+                farm_table(&sources)
+            }
+        }
+        "diff" => {
+            let store = SnapshotStore::open_default()?;
+            let snapshots = store.list()?;
+            let [.., previous, latest] = snapshots.as_slice() else {
+                anyhow::bail!("Need at least two inventory snapshots, log in again to take one");
+            };
+            let diff =
+                InventoryDiff::between(&store.load(previous)?, &store.load(latest)?, catalog);
+            if json {
+                serde_json::to_string_pretty(&diff)?
+            } else {
+                format!(
+                    "{} -> {}\n\n{}",
+                    previous
+                        .taken_at
+                        .with_timezone(&chrono::Local)
+                        .format("%Y-%m-%d %H:%M"),
+                    latest
+                        .taken_at
+                        .with_timezone(&chrono::Local)
+                        .format("%Y-%m-%d %H:%M"),
+                    diff_summary(&diff)
+                )
+            }
+        }
+        "session" => {
+            let store = SnapshotStore::open_default()?;
+            let report = session::current_report(&store, catalog, chrono::Utc::now())?;
+            if json {
+                serde_json::to_string_pretty(&report)?
+            } else {
+                report.to_markdown()
+            }
+        }
+        "metrics" => {
+            let days = match args.iter().find_map(|arg| arg.strip_prefix("--days=")) {
+                Some(days) => days
+                    .parse::<i64>()
+                    .ok()
+                    .filter(|days| *days > 0)
+                    .ok_or_else(|| anyhow::anyhow!("Invalid number of days '{}'", days))?,
+                None => 30,
+            };
+            let from = chrono::Utc::now() - chrono::Duration::days(days);
+            let store = MetricsStore::open_default()?;
+            let metric = report_target(args);
+            if metric.is_empty() {
+                let samples = store.samples(Some(from), None)?;
+                if args.iter().any(|arg| arg == "--csv") {
+                    metrics::to_csv(&samples)
+                } else if json {
+                    serde_json::to_string_pretty(&samples)?
+                } else {
+                    metrics_table(&samples)
+                }
+            } else {
+                let points = metrics::downsample(
+                    &store.series(&metric, Some(from), None)?,
+                    chrono::Duration::days(1),
+                    Aggregation::Last,
+                );
+                if points.is_empty() {
+                    anyhow::bail!("No '{}' values in the last {} days", metric, days);
+                }
+                if json {
+                    serde_json::to_string_pretty(&points)?
+                } else {
+                    series_table(&points)
+                }
+            }
+        }
+        other => anyhow::bail!(
+            "Unknown report '{}', expected one of: {}",
+            other,
+            REPORTS.join(", ")
+        ),
+    };
+    Ok(output)
+}
+
+/// Everything after the report name, e.g. "Oberon Prime" in `report craft Oberon Prime`
+fn report_target(args: &[String]) -> String {
+    let words: Vec<&str> = args
+        .iter()
+        .filter(|arg| !arg.starts_with("--"))
+        .skip(1)
+        .map(String::as_str)
+        .collect();
+    words.join(" ")
+}
+
+/// Runs `wf-info-2 wishlist add|remove <item name> [--quantity=N]` and `wf-info-2 wishlist list`.
+pub fn run_wishlist(args: &[String]) -> anyhow::Result<()> {
+    let words: Vec<&str> = args
+        .iter()
+        .filter(|arg| !arg.starts_with("--"))
+        .map(String::as_str)
+        .collect();
+    let quantity = match args.iter().find_map(|arg| arg.strip_prefix("--quantity=")) {
+        Some(quantity) => quantity
+            .parse::<i64>()
+            .ok()
+            .filter(|quantity| *quantity > 0)
+            .ok_or_else(|| anyhow::anyhow!("Invalid quantity '{}'", quantity))?,
+        None => 1,
+    };
+
+    let path = Wishlist::path()?;
+    let mut wishlist = Wishlist::load(&path)?;
+    let catalog = load_catalog()?;
+
+    match words.split_first() {
+        Some((&"add", name)) | Some((&"remove", name)) if !name.is_empty() => {
+            let query = name.join(" ");
+            let item = crafting::find_item(&catalog, &query)
+                .ok_or_else(|| anyhow::anyhow!("No catalog item named '{}'", query))?;
+            if words[0] == "add" {
+                wishlist.add(item.unique_name(), quantity);
+                println!("Added {} x{} to the wishlist", item.name(), quantity);
+            } else if wishlist.remove(item.unique_name()) {
+                println!("Removed {} from the wishlist", item.name());
+            } else {
+                anyhow::bail!("{} is not on the wishlist", item.name());
+            }
+            wishlist.save(&path)?;
+        }
+        Some((&"list", _)) | None => {
+            for entry in &wishlist.items {
+                let name = catalog
+                    .get(&entry.unique_name)
+                    .map(|item| item.name())
+                    .unwrap_or(&entry.unique_name);
+                println!("{} x{}", name, entry.quantity);
+            }
+        }
+        _ => anyhow::bail!("Usage: wishlist add|remove <item name> [--quantity=N], wishlist list"),
+    }
+    Ok(())
+}
+
+/// Runs `wf-info-2 export <file.db>`, writing the last saved inventory and the catalog to SQLite.
+pub fn run_export(args: &[String]) -> anyhow::Result<()> {
+    let path = args
+        .first()
+        .ok_or_else(|| anyhow::anyhow!("Missing database path, e.g. `export inventory.db`"))?;
+    let inventory = storage::load_inventory()?;
+    let catalog = load_catalog()?;
+    sqlite::export(&inventory, &catalog, std::path::Path::new(path))?;
+    println!("Exported inventory to {}", path);
+    Ok(())
+}
+
+fn load_catalog() -> anyhow::Result<Catalog> {
+    let dir = catalog::find_items_dir().ok_or_else(|| {
+        anyhow::anyhow!("Could not find warframe-items data, set WF_ITEMS_DIR to its json folder")
+    })?;
+    Catalog::load_from_dir(&dir)
+        .with_context(|| format!("Failed to load item catalog from {}", dir.display()))
+}
+
+fn load_nodes() -> anyhow::Result<NodeCatalog> {
+    let path = starchart::find_nodes_file().ok_or_else(|| {
+        anyhow::anyhow!(
+            "Could not find star chart node data, set WF_NODES_FILE to a nodes JSON file"
+        )
+    })?;
+    NodeCatalog::load(&path)
+}
+
+/// Every owned entry with its catalog name, then how many the catalog does not know
+pub fn inventory_table(enriched: &EnrichedInventory) -> String {
+    let rows: Vec<Vec<String>> = enriched
+        .items
+        .iter()
+        .map(|item| {
+            vec![
+                item.name.clone(),
+                format!("{:?}", item.section),
+                format!("{:?}", item.category),
+                item.item_count.to_string(),
+                item.mastery_req
+                    .map(|mastery_req| mastery_req.to_string())
+                    .unwrap_or_default(),
+                if item.tradable { "yes" } else { "" }.to_string(),
+            ]
+        })
+        .collect();
+
+    let mut table = render_table(
+        &["Item", "Section", "Category", "Count", "MR", "Tradable"],
+        &rows,
+    );
+    if !enriched.unresolved.is_empty() {
+        table.push_str(&format!(
+            "\n{} entries are not in the catalog\n",
+            enriched.unresolved.len()
+        ));
+    }
+    table
+}
+
+/// Mastery points, then what is left to level per category
+pub fn mastery_table(report: &MasteryReport) -> String {
+    let mut out = format!(
+        "{} mastery points from {} items\n\n",
+        report.mastery_points,
+        report.items.len()
+    );
+
+    let mut categories: Vec<_> = report
+        .unmaxed
+        .keys()
+        .chain(report.never_levelled.keys())
+        .collect();
+    categories.sort();
+    categories.dedup();
+    let rows: Vec<Vec<String>> = categories
+        .into_iter()
+        .map(|category| {
+            let unmaxed = report
+                .unmaxed
+                .get(category)
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+            let never_levelled = report
+                .never_levelled
+                .get(category)
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+            let points_left: i64 = unmaxed
+                .iter()
+                .map(|item| item.max_mastery_points - item.mastery_points)
+                .chain(never_levelled.iter().map(|item| item.mastery_points))
+                .sum();
+            vec![
+                format!("{:?}", category),
+                unmaxed.len().to_string(),
+                never_levelled.len().to_string(),
+                points_left.to_string(),
+            ]
+        })
+        .collect();
+    out.push_str(&render_table(
+        &[
+            "Category",
+            "Below max rank",
+            "Never levelled",
+            "Points left",
+        ],
+        &rows,
+    ));
+    out
+}
+
+/// Prime sets with at least one part owned
+pub fn primes_table(report: &PrimeReport) -> String {
+    let rows: Vec<Vec<String>> = report
+        .started()
+        .map(|set| {
+            let missing: Vec<&str> = set
+                .missing_parts()
+                .map(|part| {
+                    part.name
+                        .strip_prefix(&set.name)
+                        .unwrap_or(&part.name)
+                        .trim()
+                })
+                .collect();
+            vec![
+                set.name.clone(),
+                format!("{}/{}", set.owned_parts(), set.parts.len()),
+                set.complete_sets.to_string(),
+                if set.owned_item { "yes" } else { "" }.to_string(),
+                missing.join(", "),
+                set.duplicate_ducats().to_string(),
+            ]
+        })
+        .collect();
+
+    let mut table = render_table(
+        &["Item", "Parts", "Sets", "Built", "Missing", "Dup. ducats"],
+        &rows,
+    );
+    table.push_str(&format!(
+        "\nDuplicate parts are worth {} ducats\n",
+        report.duplicate_ducats
+    ));
+    table
+}
+
+/// Owned relics per refinement with their rare reward
+pub fn relics_table(report: &RelicReport) -> String {
+    let rows: Vec<Vec<String>> = report
+        .relics
+        .iter()
+        .map(|relic| {
+            let rare: Vec<&str> = relic
+                .rewards
+                .iter()
+                .filter(|reward| reward.rarity == "Rare")
+                .map(|reward| reward.name.as_str())
+                .collect();
+            let mut row = vec![relic.name.clone()];
+            row.extend(
+                Refinement::ALL
+                    .iter()
+                    .map(|refinement| relic.count(*refinement).to_string()),
+            );
+            row.push(rare.join(", "));
+            row
+        })
+        .collect();
+
+    let mut table = render_table(
+        &[
+            "Relic",
+            "Intact",
+            "Exceptional",
+            "Flawless",
+            "Radiant",
+            "Rare",
+        ],
+        &rows,
+    );
+    table.push_str(&format!("\n{} relics owned\n", report.total()));
+    table
+}
+
+pub fn rivens_table(rivens: &[Riven]) -> String {
+    let rows: Vec<Vec<String>> = rivens
+        .iter()
+        .map(|riven| match riven {
+            Riven::Unveiled(decoded) => {
+                let stats: Vec<String> = decoded.stats.iter().map(|stat| stat.describe()).collect();
+                vec![
+                    decoded
+                        .weapon_name
+                        .clone()
+                        .unwrap_or_else(|| decoded.compat.clone()),
+                    decoded
+                        .polarity
+                        .map(|polarity| format!("{:?}", polarity))
+                        .unwrap_or_default(),
+                    decoded
+                        .mastery_req
+                        .map(|mr| mr.to_string())
+                        .unwrap_or_default(),
+                    decoded.rank.to_string(),
+                    decoded.rerolls.to_string(),
+                    stats.join(", "),
+                ]
+            }
+            Riven::Veiled(veiled) => vec![
+                match veiled.weapon_class {
+                    Some(class) => format!("Veiled {:?} Riven", class),
+                    None => "Veiled Riven".to_string(),
+                },
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                format!(
+                    "{} ({}/{})",
+                    veiled.description, veiled.progress, veiled.required
+                ),
+            ],
+        })
+        .collect();
+
+    render_table(
+        &["Weapon", "Polarity", "MR", "Rank", "Rerolls", "Stats"],
+        &rows,
+    )
+}
+
+/// One row per attack mode of every weapon config
+pub fn dps_table(reports: &[(&Build, DamageReport)]) -> String {
+    let rows: Vec<Vec<String>> = reports
+        .iter()
+        .flat_map(|(build, report)| {
+            let config = match &build.config_name {
+                Some(name) => format!("{} / {}", report.name, name),
+                None => format!("{} / Config {}", report.name, build.config_index + 1),
+            };
+            report.attacks.iter().map(move |attack| {
+                vec![
+                    config.clone(),
+                    attack.name.clone(),
+                    format!("{:.1}", attack.hit_damage),
+                    format!(
+                        "{:.1}% x{:.1}",
+                        attack.critical_chance * 100.0,
+                        attack.critical_multiplier
+                    ),
+                    format!("{:.1}%", attack.status_chance * 100.0),
+                    format!("{:.0}", attack.burst_dps),
+                    format!("{:.0}", attack.sustained_dps),
+                    format!("{:.2}", attack.status_per_second),
+                ]
+            })
+        })
+        .collect();
+
+    render_table(
+        &[
+            "Weapon",
+            "Attack",
+            "Hit",
+            "Crit",
+            "Status",
+            "Burst",
+            "Sustained",
+            "Procs/s",
+        ],
+        &rows,
+    )
+}
+
+/// Warframes side by side with effective HP per faction
+pub fn survivability_table(rows: &[Survivability]) -> String {
+    let mut headers = vec![
+        "Warframe".to_string(),
+        "Rank".to_string(),
+        "Health".to_string(),
+        "Shield".to_string(),
+        "Armor".to_string(),
+        "DR".to_string(),
+        "Energy".to_string(),
+    ];
+    // Shield bypass shares are estimates, mark the columns that depend on them
+    headers.extend(Faction::ALL.iter().map(|faction| {
+        if faction.shield_bypass() > 0.0 {
+            format!("EHP {:?}*", faction)
+        } else {
+            format!("EHP {:?}", faction)
+        }
+    }));
+
+    let rows: Vec<Vec<String>> = rows
+        .iter()
+        .map(|row| {
+            let mut cells = vec![
+                row.name.clone(),
+                row.rank.to_string(),
+                format!("{:.0}", row.health),
+                format!("{:.0}", row.shield),
+                format!("{:.0}", row.armor),
+                format!("{:.1}%", row.damage_reduction * 100.0),
+                format!("{:.0}", row.energy),
+            ];
+            cells.extend(
+                Faction::ALL
+                    .iter()
+                    .map(|faction| format!("{:.0}", row.effective_hp[faction])),
+            );
+            cells
+        })
+        .collect();
+
+    let assumed: Vec<String> = Faction::ALL
+        .iter()
+        .filter(|faction| faction.shield_bypass() > 0.0)
+        .map(|faction| format!("{:.0}% of {:?}", faction.shield_bypass() * 100.0, faction))
+        .collect();
+
+    let headers: Vec<&str> = headers.iter().map(String::as_str).collect();
+    let mut table = render_table(&headers, &rows);
+    table.push_str(&format!(
+        "\n* Approximate, assumes {} damage skips shields\n",
+        assumed.join(" and ")
+    ));
+    table
+}
+
+/// Socketed shards per warframe followed by totals per color
+pub fn shards_table(report: &ShardReport) -> String {
+    let mut out: String = report
+        .frames
+        .iter()
+        .map(|frame| format!("{}\n", frame.summary()))
+        .collect();
+    out.push('\n');
+
+    let rows: Vec<Vec<String>> = report
+        .counts
+        .iter()
+        .map(|count| {
+            vec![
+                if count.tauforged {
+                    format!("Tauforged {}", count.color)
+                } else {
+                    count.color.to_string()
+                },
+                count.installed.to_string(),
+                count.loose.to_string(),
+                count.total().to_string(),
+            ]
+        })
+        .collect();
+    out.push_str(&render_table(
+        &["Shard", "Installed", "Loose", "Total"],
+        &rows,
+    ));
+    out
+}
+
+/// Rank, resources, infusable abilities and subsume candidates
+pub fn helminth_summary(report: &HelminthReport) -> String {
+    let mut out = format!(
+        "{} rank {} ({} XP)\n\n",
+        report.name.as_deref().unwrap_or("Helminth"),
+        report.rank,
+        report.xp
+    );
+
+    let rows: Vec<Vec<String>> = report
+        .resources
+        .iter()
+        .map(|resource| {
+            vec![
+                resource.name.clone(),
+                resource.count.to_string(),
+                resource.recently_converted.len().to_string(),
+            ]
+        })
+        .collect();
+    out.push_str(&render_table(&["Resource", "Count", "Recently fed"], &rows));
+
+    let abilities: Vec<String> = report
+        .abilities
+        .iter()
+        .map(|ability| match &ability.source {
+            Some(source) => format!("{} ({})", ability.name, source),
+            None => ability.name.clone(),
+        })
+        .collect();
+    out.push_str(&format!(
+        "\nInfusable abilities: {}\n",
+        abilities.join(", ")
+    ));
+
+    let candidates: Vec<String> = report
+        .candidates
+        .iter()
+        .map(|frame| {
+            let name = frame.name.as_deref().unwrap_or(&frame.item_type);
+            match &frame.ability {
+                Some(ability) => format!("{} ({})", name, ability.name),
+                None => name.to_string(),
+            }
+        })
+        .collect();
+    out.push_str(&format!("Subsume candidates: {}\n", candidates.join(", ")));
+    out
+}
+
+pub fn syndicates_table(standings: &[SyndicateStanding]) -> String {
+    let rows: Vec<Vec<String>> = standings
+        .iter()
+        .map(|standing| {
+            let name = match &standing.syndicate {
+                Syndicate::Nightwave(tag) => {
+                    format!("{} ({})", standing.name, tag)
+                }
+                _ => standing.name.clone(),
+            };
+            vec![
+                if standing.supported {
+                    format!("{} *", name)
+                } else {
+                    name
+                },
+                standing.rank.to_string(),
+                standing.standing.to_string(),
+                match standing.standing_to_next {
+                    Some(needed) => needed.to_string(),
+                    None if standing.is_max_rank() => "max".to_string(),
+                    None => String::new(),
+                },
+                standing
+                    .daily_remaining
+                    .map(|remaining| remaining.to_string())
+                    .unwrap_or_default(),
+            ]
+        })
+        .collect();
+
+    render_table(
+        &[
+            "Syndicate",
+            "Rank",
+            "Standing",
+            "To next rank",
+            "Left today",
+        ],
+        &rows,
+    )
+}
+
+/// Completion per planet, junctions and nodes never completed
+pub fn starchart_table(report: &StarChartReport) -> String {
+    if report.nodes == 0 {
+        return format!(
+            "{} missions completed, {} on the Steel Path, {}/{} junctions\n\
+             No star chart node data, set WF_NODES_FILE for progress per planet\n",
+            report.completed,
+            report.steel_path,
+            report.junctions_completed(),
+            report.junctions.len()
+        );
+    }
+
+    let mut out = format!(
+        "{}/{} nodes completed, {}/{} on the Steel Path, {}/{} junctions\n\n",
+        report.completed,
+        report.nodes,
+        report.steel_path,
+        report.nodes,
+        report.junctions_completed(),
+        report.junctions.len()
+    );
+
+    let rows: Vec<Vec<String>> = report
+        .planets
+        .iter()
+        .map(|planet| {
+            let missing: Vec<&str> = planet
+                .missing
+                .iter()
+                .map(|node| node.name.as_str())
+                .collect();
+            vec![
+                planet.planet.clone(),
+                format!("{}/{}", planet.completed, planet.nodes),
+                format!("{}/{}", planet.steel_path, planet.nodes),
+                missing.join(", "),
+            ]
+        })
+        .collect();
+    out.push_str(&render_table(
+        &["Planet", "Completed", "Steel Path", "Never completed"],
+        &rows,
+    ));
+
+    let junctions: Vec<String> = report
+        .junctions
+        .iter()
+        .filter(|junction| !junction.completed)
+        .map(|junction| format!("{} to {}", junction.from, junction.to))
+        .collect();
+    if !junctions.is_empty() {
+        out.push_str(&format!("\nJunctions left: {}\n", junctions.join(", ")));
+    }
+    out
+}
+
+/// Quests in progress stage by stage, then completed and never started ones
+pub fn quests_summary(report: &QuestReport) -> String {
+    let mut out = String::new();
+
+    for quest in &report.in_progress {
+        out.push_str(&format!(
+            "{} (stage {})\n",
+            quest.name,
+            quest.current_stage().unwrap_or_default()
+        ));
+        for (index, state) in quest.stage_states().iter().enumerate() {
+            out.push_str(&format!("  stage {}: {}\n", index + 1, state.label()));
+        }
+    }
+
+    let rows: Vec<Vec<String>> = report
+        .completed
+        .iter()
+        .map(|quest| {
+            vec![
+                quest.name.clone(),
+                quest
+                    .completion_date
+                    .map(|date| date.format("%Y-%m-%d").to_string())
+                    .unwrap_or_default(),
+            ]
+        })
+        .collect();
+    if !out.is_empty() {
+        out.push('\n');
+    }
+    out.push_str(&render_table(&["Completed", "Date"], &rows));
+
+    let not_started: Vec<&str> = report
+        .not_started
+        .iter()
+        .map(|quest| quest.name.as_str())
+        .collect();
+    out.push_str(&format!("\nNot started: {}\n", not_started.join(", ")));
+    out
+}
+
+/// Builds ready to claim first, then the ones still in progress
+pub fn foundry_table(report: &FoundryReport, now: chrono::DateTime<chrono::Utc>) -> String {
+    let rows: Vec<Vec<String>> = report
+        .ready
+        .iter()
+        .chain(&report.building)
+        .map(|build| {
+            vec![
+                build.name.clone(),
+                if build.is_ready(now) {
+                    "ready".to_string()
+                } else {
+                    utils::format_duration(build.remaining(now))
+                },
+                build
+                    .completion_date
+                    .map(|date| {
+                        date.with_timezone(&chrono::Local)
+                            .format("%Y-%m-%d %H:%M")
+                            .to_string()
+                    })
+                    .unwrap_or_default(),
+            ]
+        })
+        .collect();
+    render_table(&["Build", "Remaining", "Completes"], &rows)
+}
+
+/// Recipe tree with owned counts, then what is missing and the total cost
+pub fn craft_plan_summary(plan: &CraftPlan) -> String {
+    fn push_nodes(out: &mut String, nodes: &[CraftNode], depth: usize) {
+        for node in nodes {
+            out.push_str(&format!(
+                "{}{} {}/{}\n",
+                "  ".repeat(depth + 1),
+                node.name,
+                node.owned.min(node.required),
+                node.required
+            ));
+            push_nodes(out, &node.children, depth + 1);
+        }
+    }
+
+    let mut out = format!("{}\n", plan.name);
+    push_nodes(&mut out, &plan.ingredients, 0);
+
+    if plan.missing.is_empty() {
+        out.push_str("\nNothing missing\n");
+    } else {
+        let rows: Vec<Vec<String>> = plan
+            .missing
+            .iter()
+            .map(|shortfall| {
+                vec![
+                    shortfall.name.clone(),
+                    shortfall.missing().to_string(),
+                    shortfall.owned.to_string(),
+                ]
+            })
+            .collect();
+        out.push('\n');
+        out.push_str(&render_table(&["Missing", "Need", "Owned"], &rows));
+    }
+
+    out.push_str(&format!(
+        "\nCredits: {} ({} owned)\nBuild time: {}\n",
+        plan.credits,
+        plan.credits_owned,
+        utils::format_duration(chrono::Duration::seconds(plan.build_time))
+    ));
+    if plan.incomplete {
+        out.push_str("Parts to build have no recipe data, their resources, credits and time are not counted\n");
+    }
+    out
+}
+
+/// Items that can be built right now from owned blueprints and resources
+pub fn craftable_table(plans: &[CraftPlan]) -> String {
+    let rows: Vec<Vec<String>> = plans
+        .iter()
+        .map(|plan| {
+            vec![
+                plan.name.clone(),
+                plan.credits.to_string(),
+                utils::format_duration(chrono::Duration::seconds(plan.build_time)),
+            ]
+        })
+        .collect();
+    render_table(&["Item", "Credits", "Build time"], &rows)
+}
+
+/// Missing ingredients across the wishlist with their three best drop locations
+pub fn wishlist_summary(report: &WishlistReport) -> String {
+    let rows: Vec<Vec<String>> = report
+        .missing
+        .iter()
+        .map(|shortfall| {
+            let drops: Vec<String> = shortfall
+                .drops
+                .iter()
+                .take(3)
+                .map(|drop| format!("{} ({:.1}%)", drop.location, drop.chance * 100.0))
+                .collect();
+            vec![
+                shortfall.name.clone(),
+                shortfall.missing().to_string(),
+                shortfall.owned.to_string(),
+                shortfall.needed_for.join(", "),
+                drops.join(", "),
+            ]
+        })
+        .collect();
+
+    let mut out = if rows.is_empty() {
+        "Nothing missing\n".to_string()
+    } else {
+        render_table(&["Missing", "Need", "Owned", "For", "Drops"], &rows)
+    };
+    out.push_str(&format!(
+        "\nCredits: {} ({} owned)\n",
+        report.credits, report.credits_owned
+    ));
+    if !report.unknown.is_empty() {
+        out.push_str(&format!("No recipe: {}\n", report.unknown.join(", ")));
+    }
+    out
+}
+
+/// Drops at one location, marking the ones still needed
+pub fn location_table(report: &LocationReport) -> String {
+    let rows: Vec<Vec<String>> = report
+        .drops
+        .iter()
+        .map(|drop| {
+            vec![
+                drop.drop.item.clone(),
+                drop.drop.refinement.clone().unwrap_or_default(),
+                drop.drop.rarity.clone(),
+                format!("{:.2}%", drop.drop.chance * 100.0),
+                drop.owned.to_string(),
+                match (drop.needed(), drop.wishlisted) {
+                    (_, true) => "wishlist".to_string(),
+                    (true, false) => "yes".to_string(),
+                    (false, false) => String::new(),
+                },
+            ]
+        })
+        .collect();
+    render_table(
+        &["Item", "Refinement", "Rarity", "Chance", "Owned", "Needed"],
+        &rows,
+    )
+}
+
+/// Drop locations of one item, best chance first
+pub fn farm_table(sources: &[&DropEntry]) -> String {
+    let rows: Vec<Vec<String>> = sources
+        .iter()
+        .map(|entry| {
+            vec![
+                entry.full_location(),
+                entry.rarity.clone(),
+                format!("{:.2}%", entry.chance * 100.0),
+            ]
+        })
+        .collect();
+    render_table(&["Location", "Rarity", "Chance"], &rows)
+}
+
+/// Every section of a diff that has changes
+pub fn diff_summary(diff: &InventoryDiff) -> String {
+    fn counts_table(changes: &[CountChange]) -> String {
+        let rows: Vec<Vec<String>> = changes
+            .iter()
+            .map(|change| {
+                vec![
+                    change.name.clone(),
+                    format!("{:+}", change.delta()),
+                    change.after.to_string(),
+                ]
+            })
+            .collect();
+        render_table(&["Item", "Change", "Now"], &rows)
+    }
+
+    if diff.is_empty() {
+        return "No changes".to_string();
+    }
+
+    let mut out = format!("Credits: {:+}\n", diff.credits);
+    if !diff.items_gained.is_empty() {
+        let names: Vec<&str> = diff
+            .items_gained
+            .iter()
+            .map(|item| item.name.as_str())
+            .collect();
+        out.push_str(&format!("Gained: {}\n", names.join(", ")));
+    }
+    if !diff.items_lost.is_empty() {
+        let names: Vec<&str> = diff
+            .items_lost
+            .iter()
+            .map(|item| item.name.as_str())
+            .collect();
+        out.push_str(&format!("Lost: {}\n", names.join(", ")));
+    }
+    for (title, changes) in [
+        ("Resources", &diff.resources),
+        ("Blueprints", &diff.blueprints),
+        ("Mods", &diff.mods),
+    ] {
+        if !changes.is_empty() {
+            out.push_str(&format!("\n{}\n{}", title, counts_table(changes)));
+        }
+    }
+    if !diff.xp.is_empty() {
+        let rows: Vec<Vec<String>> = diff
+            .xp
+            .iter()
+            .map(|change| vec![change.name.clone(), format!("{:+}", change.gained())])
+            .collect();
+        out.push_str(&format!(
+            "\nAffinity\n{}",
+            render_table(&["Item", "XP"], &rows)
+        ));
+    }
+    if !diff.standing.is_empty() {
+        let rows: Vec<Vec<String>> = diff
+            .standing
+            .iter()
+            .map(|change| {
+                vec![
+                    change.syndicate.name().to_string(),
+                    format!("{:+}", change.delta()),
+                    change.after.to_string(),
+                ]
+            })
+            .collect();
+        out.push_str(&format!(
+            "\nStanding\n{}",
+            render_table(&["Syndicate", "Change", "Now"], &rows)
+        ));
+    }
+    out
+}
+
+/// Latest value of every metric and its change since the first sample that has it
+pub fn metrics_table(samples: &[MetricsSample]) -> String {
+    let mut range: BTreeMap<&str, (f64, f64)> = BTreeMap::new();
+    for sample in samples {
+        for (metric, value) in &sample.values {
+            range
+                .entry(metric.as_str())
+                .and_modify(|(_, last)| *last = *value)
+                .or_insert((*value, *value));
+        }
+    }
+    let rows: Vec<Vec<String>> = range
+        .into_iter()
+        .map(|(metric, (first, last))| {
+            vec![
+                metric.to_string(),
+                last.to_string(),
+                format!("{:+}", last - first),
+            ]
+        })
+        .collect();
+    render_table(&["Metric", "Now", "Change"], &rows)
+}
+
+/// One row per day of a downsampled metric
+pub fn series_table(points: &[Point]) -> String {
+    let rows: Vec<Vec<String>> = points
+        .iter()
+        .map(|point| {
+            vec![
+                point.at.format("%Y-%m-%d").to_string(),
+                point.value.to_string(),
+            ]
+        })
+        .collect();
+    render_table(&["Day", "Value"], &rows)
+}
+
+/// Left aligned plain text table with a header separator
+pub fn render_table(headers: &[&str], rows: &[Vec<String>]) -> String {
+    let mut widths: Vec<usize> = headers.iter().map(|h| h.chars().count()).collect();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let separator: Vec<String> = widths.iter().map(|width| "-".repeat(*width)).collect();
+
+    let mut out = pad_row(headers.iter().copied(), &widths);
+    out.push_str(&pad_row(separator.iter().map(String::as_str), &widths));
+    for row in rows {
+        out.push_str(&pad_row(row.iter().map(String::as_str), &widths));
+    }
+    out
+}
+
+fn pad_row<'a>(cells: impl Iterator<Item = &'a str>, widths: &[usize]) -> String {
+    let padded: Vec<String> = cells
+        .zip(widths)
+        .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+        .collect();
+    format!("{}\n", padded.join("  ").trim_end())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::quest::QuestStage;
+    use crate::inventory::tests::load_test_inventory;
+    use crate::quests::{QuestProgress, QuestStatus};
+
+    #[test]
+    fn test_render_table() {
+        let rows = vec![
+            vec!["Braton Prime".to_string(), "4/4".to_string()],
+            vec!["Lex".to_string(), "".to_string()],
+        ];
+        let table = render_table(&["Item", "Parts"], &rows);
+        assert_eq!(
+            table,
+            "Item          Parts\n------------  -----\nBraton Prime  4/4\nLex\n"
+        );
+    }
+
+    #[test]
+    fn test_quests_summary() {
+        let stage = |c: i64, i: bool, m: bool| QuestStage { c, i, m, b: None };
+        let quest = |name: &str, stages: Vec<QuestStage>| QuestProgress {
+            item_type: format!("/Lotus/Types/Keys/{}QuestKeyChain", name),
+            name: name.to_string(),
+            status: QuestStatus::InProgress,
+            stages,
+            completion_date: None,
+        };
+        let report = QuestReport {
+            in_progress: vec![
+                quest(
+                    "Jade Shadows",
+                    vec![stage(0, false, false), stage(0, true, false)],
+                ),
+                quest(
+                    "Whispers",
+                    vec![stage(1, true, true), stage(0, false, false)],
+                ),
+            ],
+            ..Default::default()
+        };
+        let summary = quests_summary(&report);
+        assert!(summary.starts_with(
+            "Jade Shadows (stage 2)\n  stage 1: done\n  stage 2: started\n\
+             Whispers (stage 2)\n  stage 1: done\n  stage 2: unlocked\n"
+        ));
+    }
+
+    #[test]
+    fn test_offline_reports() {
+        let inventory = load_test_inventory();
+        let catalog = Catalog::default();
+        // wishlist reads the cache directory
+        for report in OFFLINE_REPORTS
+            .into_iter()
+            .filter(|report| *report != "wishlist")
+        {
+            for flags in [vec![], vec!["--json".to_string()]] {
+                let args: Vec<String> = std::iter::once(report.to_string()).chain(flags).collect();
+                let output = render_report(&args, &inventory, &catalog)
+                    .unwrap_or_else(|e| panic!("{} failed: {:#}", report, e));
+                if args.len() > 1 {
+                    serde_json::from_str::<serde_json::Value>(&output).unwrap();
+                }
+            }
+        }
+
+        let survivability =
+            render_report(&["survivability".to_string()], &inventory, &catalog).unwrap();
+        assert!(survivability.ends_with(
+            "* Approximate, assumes 35% of Infested and 10% of Corrupted damage skips shields\n"
+        ));
+        let syndicates = render_report(&["syndicates".to_string()], &inventory, &catalog).unwrap();
+        assert!(syndicates.starts_with("Syndicate"));
+
+        // Without the catalog every entry is unresolved and relics keep their path names
+        let enriched = render_report(&["inventory".to_string()], &inventory, &catalog).unwrap();
+        assert!(enriched.ends_with("entries are not in the catalog\n"));
+        let mastery = render_report(&["mastery".to_string()], &inventory, &catalog).unwrap();
+        assert!(mastery.contains(&format!("from {} items", inventory.xp_info.len())));
+        let relics = render_report(&["relics".to_string()], &inventory, &catalog).unwrap();
+        assert!(relics.contains("Meso ChromaPrimeB"));
+        assert!(render_report(&["nope".to_string()], &inventory, &catalog).is_err());
+    }
+}
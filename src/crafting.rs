@@ -0,0 +1,524 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Serialize};
+
+use crate::inventory::Inventory;
+use crate::itemdata::catalog::{Catalog, CatalogItem};
+
+/// Crafted intermediates are expanded at most this deep, guards against recipe cycles
+const MAX_DEPTH: usize = 8;
+
+/// How an ingredient is obtained and counted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IngredientKind {
+    /// Blueprint in `Recipes`
+    Blueprint,
+    /// Warframe or weapon part in `MiscItems`, built from its own blueprint
+    Part,
+    /// Catalog item with its own recipe, e.g. Forma or a weapon used in another one
+    Item,
+    /// Anything else in `MiscItems`
+    Resource,
+}
+
+/// One ingredient of a recipe, with what its shortfall expands to.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CraftNode {
+    pub unique_name: String,
+    pub name: String,
+    pub kind: IngredientKind,
+    pub required: i64,
+    pub owned: i64,
+    /// Ingredients needed to build the missing amount
+    pub children: Vec<CraftNode>,
+}
+
+impl CraftNode {
+    pub fn missing(&self) -> i64 {
+        (self.required - self.owned).max(0)
+    }
+}
+
+/// Something that still has to be acquired for a plan.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Shortfall {
+    pub unique_name: String,
+    pub name: String,
+    pub kind: IngredientKind,
+    pub required: i64,
+    pub owned: i64,
+}
+
+impl Shortfall {
+    pub fn missing(&self) -> i64 {
+        (self.required - self.owned).max(0)
+    }
+}
+
+/// Full recipe tree of an item against what the account owns.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CraftPlan {
+    pub unique_name: String,
+    pub name: String,
+    pub quantity: i64,
+    pub ingredients: Vec<CraftNode>,
+    /// Ingredients to farm or buy, sorted by name
+    pub missing: Vec<Shortfall>,
+    /// Credits for every build in the tree
+    pub credits: i64,
+    pub credits_owned: i64,
+    /// Seconds, assuming independent builds run side by side in the foundry
+    pub build_time: i64,
+    /// A part that has to be built has no recipe data, so `missing`, `credits` and
+    /// `build_time` leave out what building it takes
+    pub incomplete: bool,
+}
+
+impl CraftPlan {
+    /// `None` if the item is not in the catalog or has no recipe
+    pub fn new(
+        unique_name: &str,
+        quantity: i64,
+        inventory: &Inventory,
+        catalog: &Catalog,
+    ) -> Option<CraftPlan> {
+        CraftPlan::from_stock(unique_name, quantity, &mut Stock::new(inventory), catalog)
+    }
+
+    /// Like `new`, using only what earlier plans on the same `stock` left over
+    pub(crate) fn from_stock(
+        unique_name: &str,
+        quantity: i64,
+        stock: &mut Stock,
+        catalog: &Catalog,
+    ) -> Option<CraftPlan> {
+        let item = catalog.get(unique_name)?;
+        let recipe = expand(item, quantity, stock, catalog, 0)?;
+
+        let mut missing: BTreeMap<&str, Shortfall> = BTreeMap::new();
+        collect_leaves(&recipe.ingredients, &mut missing);
+        let mut missing: Vec<Shortfall> = missing
+            .into_values()
+            .filter(|shortfall| shortfall.missing() > 0)
+            .collect();
+        missing.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Some(CraftPlan {
+            unique_name: unique_name.to_string(),
+            name: item.name().to_string(),
+            quantity,
+            ingredients: recipe.ingredients,
+            missing,
+            credits: recipe.credits,
+            credits_owned: stock.inventory.regular_credits,
+            build_time: recipe.build_time,
+            incomplete: recipe.incomplete,
+        })
+    }
+
+    pub fn credits_missing(&self) -> i64 {
+        (self.credits - self.credits_owned).max(0)
+    }
+
+    pub fn can_build(&self) -> bool {
+        self.missing.is_empty() && self.credits_missing() == 0 && !self.incomplete
+    }
+}
+
+struct Recipe {
+    ingredients: Vec<CraftNode>,
+    credits: i64,
+    build_time: i64,
+    incomplete: bool,
+}
+
+/// What the account owns and has not been set aside for another part of the tree yet
+pub(crate) struct Stock<'a> {
+    inventory: &'a Inventory,
+    left: HashMap<String, i64>,
+}
+
+impl<'a> Stock<'a> {
+    pub(crate) fn new(inventory: &'a Inventory) -> Stock<'a> {
+        Stock {
+            inventory,
+            left: HashMap::new(),
+        }
+    }
+
+    /// Sets aside up to `required` of an ingredient, returns how many were available
+    fn take(&mut self, path: &str, kind: IngredientKind, required: i64) -> i64 {
+        let inventory = self.inventory;
+        let left = self
+            .left
+            .entry(path.to_string())
+            .or_insert_with(|| owned_count(path, kind, inventory));
+        let available = *left;
+        *left -= required.min(available);
+        available
+    }
+}
+
+/// Ingredients for `quantity` of `item`, `None` if it has no recipe
+fn expand(
+    item: &CatalogItem,
+    quantity: i64,
+    stock: &mut Stock,
+    catalog: &Catalog,
+    depth: usize,
+) -> Option<Recipe> {
+    let components = item.components();
+    if components.is_empty() {
+        return None;
+    }
+    let build = item.build_info().unwrap_or_default();
+    let batches = (quantity + build.quantity.max(1) - 1) / build.quantity.max(1);
+
+    let mut recipe = Recipe {
+        ingredients: Vec::new(),
+        credits: build.price * batches,
+        build_time: build.time,
+        incomplete: false,
+    };
+    let mut slowest = 0;
+    for component in &components {
+        let path = component.unique_name.as_str();
+        // Blueprints that are not consumed are only needed once
+        let required = if path.ends_with("Blueprint") && !build.consume_on_build {
+            component.item_count
+        } else {
+            component.item_count * batches
+        };
+        let kind = ingredient_kind(path, catalog);
+        let owned = stock.take(path, kind, required);
+        let mut node = leaf(path, kind, required, owned, catalog);
+
+        let missing = node.missing();
+        if missing > 0 && depth < MAX_DEPTH {
+            let sub = match kind {
+                IngredientKind::Part | IngredientKind::Item => catalog
+                    .get(path)
+                    .and_then(|sub| expand(sub, missing, stock, catalog, depth + 1)),
+                IngredientKind::Blueprint | IngredientKind::Resource => None,
+            };
+            match sub {
+                Some(sub) => {
+                    recipe.credits += sub.credits;
+                    recipe.incomplete |= sub.incomplete;
+                    slowest = slowest.max(sub.build_time);
+                    node.children = sub.ingredients;
+                }
+                // Parts are built from their own blueprint, whose recipe the data may lack
+                None if kind == IngredientKind::Part => {
+                    let blueprint = match path.strip_suffix("Component") {
+                        Some(stem) => format!("{stem}Blueprint"),
+                        None => format!("{path}Blueprint"),
+                    };
+                    let owned = stock.take(&blueprint, IngredientKind::Blueprint, missing);
+                    node.children.push(leaf(
+                        &blueprint,
+                        IngredientKind::Blueprint,
+                        missing,
+                        owned,
+                        catalog,
+                    ));
+                    recipe.incomplete = true;
+                }
+                None => {}
+            }
+        }
+        recipe.ingredients.push(node);
+    }
+    recipe.build_time += slowest;
+    Some(recipe)
+}
+
+/// Classifies a component path
+fn ingredient_kind(path: &str, catalog: &Catalog) -> IngredientKind {
+    if path.ends_with("Blueprint") {
+        IngredientKind::Blueprint
+    } else if path.contains("/Recipes/") {
+        IngredientKind::Part
+    } else if catalog
+        .get(path)
+        .is_some_and(|item| !item.components().is_empty())
+    {
+        IngredientKind::Item
+    } else {
+        IngredientKind::Resource
+    }
+}
+
+/// How many of an ingredient the account owns
+fn owned_count(path: &str, kind: IngredientKind, inventory: &Inventory) -> i64 {
+    match kind {
+        IngredientKind::Blueprint => inventory.recipe_count(path),
+        IngredientKind::Item => inventory.misc_item_count(path) + inventory.equipment_count(path),
+        IngredientKind::Part | IngredientKind::Resource => inventory.misc_item_count(path),
+    }
+}
+
+fn leaf(
+    unique_name: &str,
+    kind: IngredientKind,
+    required: i64,
+    owned: i64,
+    catalog: &Catalog,
+) -> CraftNode {
+    let name = catalog
+        .resolve(unique_name)
+        .map(|resolved| resolved.name())
+        .unwrap_or_else(|| {
+            unique_name
+                .rsplit('/')
+                .next()
+                .unwrap_or(unique_name)
+                .to_string()
+        });
+    CraftNode {
+        unique_name: unique_name.to_string(),
+        name,
+        kind,
+        required,
+        owned,
+        children: Vec::new(),
+    }
+}
+
+/// Sums what the tree needs of every ingredient that is not built from something else
+pub(crate) fn collect_leaves<'a>(
+    nodes: &'a [CraftNode],
+    missing: &mut BTreeMap<&'a str, Shortfall>,
+) {
+    for node in nodes {
+        if !node.children.is_empty() {
+            collect_leaves(&node.children, missing);
+            continue;
+        }
+        missing
+            .entry(node.unique_name.as_str())
+            .or_insert_with(|| Shortfall {
+                unique_name: node.unique_name.clone(),
+                name: node.name.clone(),
+                kind: node.kind,
+                required: 0,
+                owned: node.owned,
+            })
+            .required += node.required;
+    }
+}
+
+/// Catalog item by unique name or case-insensitive display name
+pub fn find_item<'a>(catalog: &'a Catalog, query: &str) -> Option<&'a CatalogItem> {
+    catalog.get(query).or_else(|| {
+        catalog
+            .iter()
+            .filter(|item| item.name().eq_ignore_ascii_case(query))
+            .min_by_key(|item| item.unique_name())
+    })
+}
+
+/// Every item whose main blueprint is owned and that can be built right now, sorted by name
+pub fn craftable(inventory: &Inventory, catalog: &Catalog) -> Vec<CraftPlan> {
+    let mut plans: Vec<CraftPlan> = inventory
+        .recipes
+        .iter()
+        .filter(|recipe| recipe.item_count > 0)
+        .filter_map(|recipe| {
+            let entry = catalog.component(&recipe.item_type)?;
+            catalog
+                .get(&entry.parent)
+                .filter(|_| entry.info.name == "Blueprint")
+                .map(|item| item.unique_name())
+        })
+        .filter_map(|unique_name| CraftPlan::new(unique_name, 1, inventory, catalog))
+        .filter(CraftPlan::can_build)
+        .collect();
+    plans.sort_by(|a, b| a.name.cmp(&b.name));
+    plans.dedup_by(|a, b| a.unique_name == b.unique_name);
+    plans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::tests::load_test_inventory;
+    use crate::itemdata::catalog::tests::forma;
+    use crate::itemdata::primary::{self, Primary};
+    use crate::itemdata::warframe::{self, Warframe};
+
+    macro_rules! components {
+        ($module:ident, $(($name:expr, $unique_name:expr, $count:expr)),* $(,)?) => {
+            vec![$($module::Component {
+                name: $name.to_string(),
+                unique_name: $unique_name.to_string(),
+                item_count: $count,
+                ..Default::default()
+            }),*]
+        };
+    }
+
+    fn catalog() -> Catalog {
+        let oberon = Warframe {
+            name: "Oberon".to_string(),
+            unique_name: "/Lotus/Powersuits/Paladin/Paladin".to_string(),
+            build_price: Some(25_000),
+            build_time: Some(259_200),
+            components: components!(
+                warframe,
+                (
+                    "Blueprint",
+                    "/Lotus/Types/Recipes/WarframeRecipes/PaladinBlueprint",
+                    1
+                ),
+                (
+                    "Chassis",
+                    "/Lotus/Types/Recipes/WarframeRecipes/PaladinChassisComponent",
+                    1
+                ),
+                (
+                    "Neuroptics",
+                    "/Lotus/Types/Recipes/WarframeRecipes/PaladinHelmetComponent",
+                    1
+                ),
+                (
+                    "Systems",
+                    "/Lotus/Types/Recipes/WarframeRecipes/PaladinSystemsComponent",
+                    1
+                ),
+                ("Orokin Cell", "/Lotus/Types/Items/MiscItems/OrokinCell", 1),
+            ),
+            ..Default::default()
+        };
+        let gun = Primary {
+            name: "Forma Gun".to_string(),
+            unique_name: "/Lotus/Weapons/Test/FormaGun".to_string(),
+            build_price: Some(15_000),
+            build_time: Some(43_200),
+            components: components!(
+                primary,
+                (
+                    "Blueprint",
+                    "/Lotus/Types/Recipes/Weapons/FormaGunBlueprint",
+                    1
+                ),
+                ("Forma", "/Lotus/Types/Items/MiscItems/Forma", 50),
+                (
+                    "Neural Sensors",
+                    "/Lotus/Types/Items/MiscItems/NeuralSensor",
+                    2
+                ),
+            ),
+            ..Default::default()
+        };
+        [
+            CatalogItem::Warframe(oberon),
+            forma(),
+            CatalogItem::Primary(gun),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    #[test]
+    fn test_plan_owned_parts() {
+        let catalog = catalog();
+        let inventory = load_test_inventory();
+
+        let plan =
+            CraftPlan::new("/Lotus/Powersuits/Paladin/Paladin", 1, &inventory, &catalog).unwrap();
+        assert!(plan.can_build());
+        assert_eq!(plan.credits, 25_000);
+        assert_eq!(plan.build_time, 259_200);
+
+        // A second Oberon needs another main blueprint, the parts can be built from spares
+        let plan =
+            CraftPlan::new("/Lotus/Powersuits/Paladin/Paladin", 2, &inventory, &catalog).unwrap();
+        let missing: Vec<(&str, i64)> = plan
+            .missing
+            .iter()
+            .map(|shortfall| (shortfall.name.as_str(), shortfall.missing()))
+            .collect();
+        assert_eq!(missing, vec![("Oberon Blueprint", 1)]);
+        let chassis = &plan.ingredients[1];
+        assert_eq!(chassis.kind, IngredientKind::Part);
+        assert_eq!(
+            chassis.children[0].unique_name,
+            "/Lotus/Types/Recipes/WarframeRecipes/PaladinChassisBlueprint"
+        );
+        assert_eq!(chassis.children[0].owned, 5);
+        // The chassis blueprint recipe is not in the data
+        assert!(plan.incomplete);
+        assert!(!plan.can_build());
+    }
+
+    #[test]
+    fn test_plan_shares_owned_intermediates() {
+        let mut catalog = catalog();
+        // Needs the Forma Gun and 30 more Forma, the 44 owned Forma cannot cover both
+        catalog.insert(CatalogItem::Primary(Primary {
+            name: "Forma Rifle".to_string(),
+            unique_name: "/Lotus/Weapons/Test/FormaRifle".to_string(),
+            build_price: Some(10_000),
+            components: components!(
+                primary,
+                ("Forma Gun", "/Lotus/Weapons/Test/FormaGun", 1),
+                ("Forma", "/Lotus/Types/Items/MiscItems/Forma", 30),
+            ),
+            ..Default::default()
+        }));
+        let inventory = load_test_inventory();
+
+        let plan =
+            CraftPlan::new("/Lotus/Weapons/Test/FormaRifle", 1, &inventory, &catalog).unwrap();
+        let gun_forma = &plan.ingredients[0].children[1];
+        assert_eq!((gun_forma.required, gun_forma.owned), (50, 44));
+        let rifle_forma = &plan.ingredients[1];
+        assert_eq!((rifle_forma.required, rifle_forma.owned), (30, 0));
+
+        let forma_blueprints = plan
+            .missing
+            .iter()
+            .find(|shortfall| shortfall.name == "Forma Blueprint")
+            .unwrap();
+        // 6 for the gun and 30 for the rifle, one Forma blueprint is owned
+        assert_eq!(forma_blueprints.required, 36);
+        assert_eq!(forma_blueprints.missing(), 35);
+        assert!(!plan.incomplete);
+    }
+
+    #[test]
+    fn test_plan_expands_intermediates() {
+        let catalog = catalog();
+        let inventory = load_test_inventory();
+
+        let plan = CraftPlan::new("/Lotus/Weapons/Test/FormaGun", 1, &inventory, &catalog).unwrap();
+        let forma = &plan.ingredients[1];
+        assert_eq!(forma.kind, IngredientKind::Item);
+        assert_eq!((forma.required, forma.owned), (50, 44));
+        assert_eq!(forma.children[0].required, 6);
+
+        let missing: Vec<(&str, i64)> = plan
+            .missing
+            .iter()
+            .map(|shortfall| (shortfall.name.as_str(), shortfall.missing()))
+            .collect();
+        assert_eq!(
+            missing,
+            vec![("Forma Blueprint", 5), ("Forma Gun Blueprint", 1)]
+        );
+        assert_eq!(plan.credits, 15_000);
+        assert_eq!(plan.build_time, 43_200 + 86_400);
+    }
+
+    #[test]
+    fn test_craftable() {
+        let catalog = catalog();
+        let inventory = load_test_inventory();
+
+        assert!(find_item(&catalog, "oberon").is_some());
+        let plans = craftable(&inventory, &catalog);
+        let craftable: Vec<&str> = plans.iter().map(|plan| plan.name.as_str()).collect();
+        assert_eq!(craftable, vec!["Forma", "Oberon"]);
+    }
+}
@@ -0,0 +1,329 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Serialize};
+
+use crate::inventory::Inventory;
+use crate::inventory::affiliation::Syndicate;
+use crate::itemdata::catalog::Catalog;
+
+/// Change of a stack count, e.g. a resource in `MiscItems`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CountChange {
+    pub item_type: String,
+    pub name: String,
+    pub before: i64,
+    pub after: i64,
+}
+
+impl CountChange {
+    pub fn delta(&self) -> i64 {
+        self.after - self.before
+    }
+}
+
+/// A warframe, weapon or archwing that appeared or disappeared.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ItemChange {
+    pub item_id: String,
+    pub item_type: String,
+    pub name: String,
+}
+
+/// Affinity earned by one warframe, weapon or archwing.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct XpChange {
+    pub item_id: String,
+    pub item_type: String,
+    pub name: String,
+    pub before: i64,
+    pub after: i64,
+}
+
+impl XpChange {
+    pub fn gained(&self) -> i64 {
+        self.after - self.before
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StandingChange {
+    pub syndicate: Syndicate,
+    pub before: i64,
+    pub after: i64,
+}
+
+impl StandingChange {
+    pub fn delta(&self) -> i64 {
+        self.after - self.before
+    }
+}
+
+/// What changed between two inventories, e.g. over one farming session.
+/// Every list only holds entries that changed and is sorted by name.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InventoryDiff {
+    pub credits: i64,
+    pub items_gained: Vec<ItemChange>,
+    pub items_lost: Vec<ItemChange>,
+    /// `MiscItems`: resources, relics and built parts
+    pub resources: Vec<CountChange>,
+    /// Unbuilt blueprints in `Recipes`
+    pub blueprints: Vec<CountChange>,
+    /// Copies in `RawUpgrades` and `Upgrades`
+    pub mods: Vec<CountChange>,
+    pub xp: Vec<XpChange>,
+    pub standing: Vec<StandingChange>,
+}
+
+impl InventoryDiff {
+    pub fn between(before: &Inventory, after: &Inventory, catalog: &Catalog) -> InventoryDiff {
+        let old = equipment(before);
+        let new = equipment(after);
+
+        let item_change = |id: &str, item_type: &str| ItemChange {
+            item_id: id.to_string(),
+            item_type: item_type.to_string(),
+            name: display_name(item_type, catalog),
+        };
+        let mut items_gained: Vec<ItemChange> = new
+            .iter()
+            .filter(|(id, _)| !old.contains_key(*id))
+            .map(|(id, (item_type, _))| item_change(id, item_type))
+            .collect();
+        let mut items_lost: Vec<ItemChange> = old
+            .iter()
+            .filter(|(id, _)| !new.contains_key(*id))
+            .map(|(id, (item_type, _))| item_change(id, item_type))
+            .collect();
+
+        let mut xp: Vec<XpChange> = new
+            .iter()
+            .filter_map(|(id, (item_type, after))| {
+                let before = old.get(id).map(|(_, xp)| *xp).unwrap_or(0);
+                (*after != before).then(|| XpChange {
+                    item_id: id.to_string(),
+                    item_type: item_type.to_string(),
+                    name: display_name(item_type, catalog),
+                    before,
+                    after: *after,
+                })
+            })
+            .collect();
+
+        let mut standing: Vec<StandingChange> = Vec::new();
+        let old_standing: HashMap<&Syndicate, i64> = before
+            .affiliations
+            .iter()
+            .map(|affiliation| (&affiliation.tag, affiliation.standing))
+            .collect();
+        for affiliation in &after.affiliations {
+            let previous = old_standing.get(&affiliation.tag).copied().unwrap_or(0);
+            if previous != affiliation.standing {
+                standing.push(StandingChange {
+                    syndicate: affiliation.tag.clone(),
+                    before: previous,
+                    after: affiliation.standing,
+                });
+            }
+        }
+
+        items_gained.sort_by(|a, b| a.name.cmp(&b.name));
+        items_lost.sort_by(|a, b| a.name.cmp(&b.name));
+        xp.sort_by(|a, b| a.name.cmp(&b.name));
+        standing.sort_by(|a, b| a.syndicate.name().cmp(b.syndicate.name()));
+
+        InventoryDiff {
+            credits: after.regular_credits - before.regular_credits,
+            items_gained,
+            items_lost,
+            resources: count_changes(misc_counts(before), misc_counts(after), catalog),
+            blueprints: count_changes(recipe_counts(before), recipe_counts(after), catalog),
+            mods: count_changes(mod_counts(before), mod_counts(after), catalog),
+            xp,
+            standing,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == InventoryDiff::default()
+    }
+
+    /// Mods that were not owned at all before
+    pub fn new_mods(&self) -> impl Iterator<Item = &CountChange> {
+        self.mods
+            .iter()
+            .filter(|change| change.before == 0 && change.after > 0)
+    }
+
+    pub fn xp_gained(&self) -> i64 {
+        self.xp
+            .iter()
+            .map(XpChange::gained)
+            .filter(|xp| *xp > 0)
+            .sum()
+    }
+}
+
+/// Item type and affinity of every warframe, weapon and archwing by item id
+fn equipment(inventory: &Inventory) -> HashMap<&str, (&str, i64)> {
+    macro_rules! entries {
+        ($items:expr) => {
+            $items.iter().map(|item| {
+                (
+                    item.item_id.oid.as_str(),
+                    (item.item_type.as_str(), item.xp.unwrap_or(0)),
+                )
+            })
+        };
+    }
+    entries!(inventory.suits)
+        .chain(entries!(inventory.long_guns))
+        .chain(entries!(inventory.pistols))
+        .chain(entries!(inventory.melee))
+        .chain(entries!(inventory.space_suits))
+        .chain(entries!(inventory.space_guns))
+        .chain(entries!(inventory.space_melee))
+        .collect()
+}
+
+fn sum_by_type<'a>(items: impl Iterator<Item = (&'a str, i64)>) -> BTreeMap<String, i64> {
+    let mut counts = BTreeMap::new();
+    for (item_type, count) in items {
+        *counts.entry(item_type.to_string()).or_default() += count;
+    }
+    counts
+}
+
+fn misc_counts(inventory: &Inventory) -> BTreeMap<String, i64> {
+    sum_by_type(
+        inventory
+            .misc_items
+            .iter()
+            .map(|item| (item.item_type.as_str(), item.item_count)),
+    )
+}
+
+fn recipe_counts(inventory: &Inventory) -> BTreeMap<String, i64> {
+    sum_by_type(
+        inventory
+            .recipes
+            .iter()
+            .map(|item| (item.item_type.as_str(), item.item_count)),
+    )
+}
+
+fn mod_counts(inventory: &Inventory) -> BTreeMap<String, i64> {
+    let raw = inventory
+        .raw_upgrades
+        .iter()
+        .map(|item| (item.item_type.as_str(), item.item_count));
+    let ranked = inventory
+        .upgrades
+        .iter()
+        .map(|item| (item.item_type.as_str(), 1));
+    sum_by_type(raw.chain(ranked))
+}
+
+fn count_changes(
+    before: BTreeMap<String, i64>,
+    mut after: BTreeMap<String, i64>,
+    catalog: &Catalog,
+) -> Vec<CountChange> {
+    let mut changes: Vec<CountChange> = Vec::new();
+    for (item_type, count) in before {
+        let now = after.remove(&item_type).unwrap_or(0);
+        if now != count {
+            changes.push(CountChange {
+                name: display_name(&item_type, catalog),
+                item_type,
+                before: count,
+                after: now,
+            });
+        }
+    }
+    for (item_type, count) in after.into_iter().filter(|(_, count)| *count != 0) {
+        changes.push(CountChange {
+            name: display_name(&item_type, catalog),
+            item_type,
+            before: 0,
+            after: count,
+        });
+    }
+    changes.sort_by(|a, b| a.name.cmp(&b.name));
+    changes
+}
+
+/// Catalog name, or the last path segment for items the catalog does not know
+fn display_name(item_type: &str, catalog: &Catalog) -> String {
+    catalog
+        .resolve(item_type)
+        .map(|resolved| resolved.name())
+        .unwrap_or_else(|| {
+            item_type
+                .rsplit('/')
+                .next()
+                .unwrap_or(item_type)
+                .to_string()
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::tests::load_test_inventory;
+
+    #[test]
+    fn test_identical_inventories() {
+        let inventory = load_test_inventory();
+        let diff = InventoryDiff::between(&inventory, &inventory, &Catalog::default());
+        assert!(diff.is_empty());
+    }
+
+    #[test]
+    fn test_inventory_diff() {
+        let before = load_test_inventory();
+        let mut after = before.clone();
+
+        after.regular_credits += 150_000;
+        let sensors = after
+            .misc_items
+            .iter_mut()
+            .find(|item| item.item_type == "/Lotus/Types/Items/MiscItems/NeuralSensor")
+            .unwrap();
+        sensors.item_count += 3;
+        after.misc_items.retain(|item| {
+            item.item_type != "/Lotus/Types/Recipes/WarframeRecipes/PaladinChassisComponent"
+        });
+        after.suits[0].xp = Some(after.suits[0].xp.unwrap_or(0) + 10_000);
+        let sold = after.melee.remove(0);
+        after.raw_upgrades[0].item_type = "/Lotus/Upgrades/Mods/Test/BrandNewMod".to_string();
+        after.affiliations[0].standing += 5_000;
+
+        let diff = InventoryDiff::between(&before, &after, &Catalog::default());
+        assert_eq!(diff.credits, 150_000);
+
+        let resources: Vec<(&str, i64)> = diff
+            .resources
+            .iter()
+            .map(|change| (change.name.as_str(), change.delta()))
+            .collect();
+        assert_eq!(
+            resources,
+            vec![("NeuralSensor", 3), ("PaladinChassisComponent", -1)]
+        );
+
+        assert_eq!(diff.items_gained, vec![]);
+        assert_eq!(diff.items_lost.len(), 1);
+        assert_eq!(diff.items_lost[0].item_id, sold.item_id.oid);
+
+        assert_eq!(diff.xp.len(), 1);
+        assert_eq!(diff.xp_gained(), 10_000);
+
+        let new_mods: Vec<&str> = diff.new_mods().map(|change| change.name.as_str()).collect();
+        assert_eq!(new_mods, vec!["BrandNewMod"]);
+
+        assert_eq!(diff.standing.len(), 1);
+        assert_eq!(diff.standing[0].delta(), 5_000);
+        assert!(diff.blueprints.is_empty());
+    }
+}
@@ -0,0 +1,683 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::itemdata::catalog::{Catalog, CatalogItem};
+use crate::itemdata::mods::Mod;
+use crate::itemdata::{melee, primary, secondary};
+use crate::loadout::{Build, SlotKind};
+
+/// Damage types as listed in item data, combined elements included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum DamageType {
+    Impact,
+    Puncture,
+    Slash,
+    Heat,
+    Cold,
+    Electricity,
+    Toxin,
+    Blast,
+    Radiation,
+    Gas,
+    Magnetic,
+    Viral,
+    Corrosive,
+    Void,
+}
+
+impl DamageType {
+    /// Matches the names used in mod stat lines, e.g. "Heat" or "Electricity"
+    pub fn from_name(name: &str) -> Option<DamageType> {
+        let damage_type = match name {
+            "Impact" => DamageType::Impact,
+            "Puncture" => DamageType::Puncture,
+            "Slash" => DamageType::Slash,
+            "Heat" => DamageType::Heat,
+            "Cold" => DamageType::Cold,
+            "Electricity" => DamageType::Electricity,
+            "Toxin" => DamageType::Toxin,
+            "Blast" => DamageType::Blast,
+            "Radiation" => DamageType::Radiation,
+            "Gas" => DamageType::Gas,
+            "Magnetic" => DamageType::Magnetic,
+            "Viral" => DamageType::Viral,
+            "Corrosive" => DamageType::Corrosive,
+            "Void" => DamageType::Void,
+            _ => return None,
+        };
+        Some(damage_type)
+    }
+
+    pub fn is_physical(&self) -> bool {
+        matches!(
+            self,
+            DamageType::Impact | DamageType::Puncture | DamageType::Slash
+        )
+    }
+
+    /// Heat, cold, electricity and toxin, the ones that combine
+    pub fn is_primary_element(&self) -> bool {
+        matches!(
+            self,
+            DamageType::Heat | DamageType::Cold | DamageType::Electricity | DamageType::Toxin
+        )
+    }
+
+    /// The element two primary elements combine into
+    pub fn combine(self, other: DamageType) -> Option<DamageType> {
+        use DamageType::*;
+        let combined = match (self, other) {
+            (Heat, Cold) | (Cold, Heat) => Blast,
+            (Electricity, Toxin) | (Toxin, Electricity) => Corrosive,
+            (Heat, Toxin) | (Toxin, Heat) => Gas,
+            (Cold, Electricity) | (Electricity, Cold) => Magnetic,
+            (Cold, Toxin) | (Toxin, Cold) => Viral,
+            (Heat, Electricity) | (Electricity, Heat) => Radiation,
+            _ => return None,
+        };
+        Some(combined)
+    }
+}
+
+/// Weapon stat a mod can change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Stat {
+    /// Base damage, "Damage" on guns and "Melee Damage" on melee
+    Damage,
+    Multishot,
+    CriticalChance,
+    /// "Critical Damage"
+    CriticalMultiplier,
+    StatusChance,
+    /// "Fire Rate" on guns, "Attack Speed" on melee
+    FireRate,
+    MagazineCapacity,
+    ReloadSpeed,
+    /// Elemental damage added as a fraction of base damage,
+    /// physical types scale only that part of the base damage
+    Element(DamageType),
+}
+
+/// Additive bonus, `0.9` for "+90%".
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct StatBonus {
+    pub stat: Stat,
+    pub value: f64,
+}
+
+impl StatBonus {
+    pub fn new(stat: Stat, value: f64) -> StatBonus {
+        StatBonus { stat, value }
+    }
+}
+
+/// Parses one entry of `Mod::level_stats`, which may hold several lines
+/// like "+90% <DT_FIRE_COLOR>Heat\r\n+60% Status Chance".
+/// Lines without a stat the calculator knows are skipped.
+pub fn parse_stats(text: &str) -> Vec<StatBonus> {
+    text.lines().filter_map(parse_stat_line).collect()
+}
+
+fn parse_stat_line(line: &str) -> Option<StatBonus> {
+    let line = strip_tags(line);
+    let (value, name) = line.trim().split_once(' ')?;
+    let value: f64 = value
+        .trim_start_matches('+')
+        .strip_suffix('%')?
+        .parse()
+        .ok()?;
+    // Drop qualifiers like "Fire Rate (x2 for Bows)"
+    let name = name.split(" (").next()?.trim();
+
+    let stat = match name {
+        "Damage" | "Melee Damage" => Stat::Damage,
+        "Multishot" => Stat::Multishot,
+        "Critical Chance" => Stat::CriticalChance,
+        "Critical Damage" => Stat::CriticalMultiplier,
+        "Status Chance" => Stat::StatusChance,
+        "Fire Rate" | "Attack Speed" => Stat::FireRate,
+        "Magazine Capacity" => Stat::MagazineCapacity,
+        "Reload Speed" => Stat::ReloadSpeed,
+        other => Stat::Element(DamageType::from_name(other)?),
+    };
+    Some(StatBonus::new(stat, value / 100.0))
+}
+
+/// Removes `<DT_FIRE_COLOR>` style markup
+fn strip_tags(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut in_tag = false;
+    for c in line.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => out.push(c),
+            _ => {}
+        }
+    }
+    out
+}
+
+/// Bonuses of a mod at the given rank, clamped to its highest rank.
+pub fn mod_bonuses(item: &Mod, rank: i64) -> Vec<StatBonus> {
+    let Some(last) = item.level_stats.len().checked_sub(1) else {
+        return Vec::new();
+    };
+    let rank = (rank.max(0) as usize).min(last);
+    item.level_stats[rank]
+        .stats
+        .iter()
+        .flat_map(|text| parse_stats(text))
+        .collect()
+}
+
+/// Bonuses of the mods equipped in a build, in slot order so elements combine like in game.
+/// Rivens and arcanes are not included.
+pub fn build_bonuses(build: &Build, catalog: &Catalog) -> Vec<StatBonus> {
+    build
+        .slots
+        .iter()
+        .filter(|slot| matches!(slot.kind, SlotKind::Mod | SlotKind::Exilus))
+        .filter_map(|slot| match catalog.get(&slot.item_type) {
+            Some(CatalogItem::Mod(item)) => Some(mod_bonuses(item, slot.rank)),
+            _ => None,
+        })
+        .flatten()
+        .collect()
+}
+
+/// Unmodded stats of one attack mode, chances as fractions.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AttackStats {
+    pub name: String,
+    /// Damage per pellet or swing by type
+    pub damage: BTreeMap<DamageType, f64>,
+    pub critical_chance: f64,
+    pub critical_multiplier: f64,
+    pub status_chance: f64,
+    /// Shots or swings per second
+    pub fire_rate: f64,
+}
+
+/// Unmodded stats of a weapon.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WeaponStats {
+    pub unique_name: String,
+    pub name: String,
+    pub multishot: f64,
+    /// `None` for melee
+    pub magazine_size: Option<f64>,
+    pub reload_time: Option<f64>,
+    pub attacks: Vec<AttackStats>,
+}
+
+macro_rules! damage_map {
+    ($damage:expr, [$($field:ident => $damage_type:ident),* $(,)?]) => {{
+        let mut map = BTreeMap::new();
+        $(
+            if let Some(value) = $damage.$field {
+                let value = value as f64;
+                if value > 0.0 {
+                    map.insert(DamageType::$damage_type, value);
+                }
+            }
+        )*
+        map
+    }};
+}
+
+fn attack_stats(
+    name: &str,
+    damage: BTreeMap<DamageType, f64>,
+    crit_chance: f64,
+    crit_mult: f64,
+    status_chance: f64,
+    fire_rate: f64,
+) -> AttackStats {
+    // Attack entries give chances in percent
+    AttackStats {
+        name: name.to_string(),
+        damage,
+        critical_chance: crit_chance / 100.0,
+        critical_multiplier: crit_mult,
+        status_chance: status_chance / 100.0,
+        fire_rate,
+    }
+}
+
+impl WeaponStats {
+    /// Stats of a primary, secondary or melee weapon
+    pub fn from_item(item: &CatalogItem) -> Option<WeaponStats> {
+        match item {
+            CatalogItem::Primary(weapon) => Some(WeaponStats::from_primary(weapon)),
+            CatalogItem::Secondary(weapon) => Some(WeaponStats::from_secondary(weapon)),
+            CatalogItem::Melee(weapon) => Some(WeaponStats::from_melee(weapon)),
+            _ => None,
+        }
+    }
+
+    pub fn from_primary(weapon: &primary::Primary) -> WeaponStats {
+        let attacks = weapon
+            .attacks
+            .iter()
+            .map(|attack| {
+                let damage = damage_map!(attack.damage, [
+                    impact => Impact, puncture => Puncture, slash => Slash,
+                    heat => Heat, cold => Cold, electricity => Electricity, toxin => Toxin,
+                    blast => Blast, radiation => Radiation, magnetic => Magnetic,
+                    viral => Viral, corrosive => Corrosive, void => Void,
+                ]);
+                attack_stats(
+                    &attack.name,
+                    damage,
+                    attack.crit_chance,
+                    attack.crit_mult,
+                    attack.status_chance,
+                    attack.speed.unwrap_or(weapon.fire_rate),
+                )
+            })
+            .collect();
+
+        WeaponStats {
+            unique_name: weapon.unique_name.clone(),
+            name: weapon.name.clone(),
+            multishot: weapon.multishot as f64,
+            magazine_size: weapon.magazine_size.map(|size| size as f64),
+            reload_time: Some(weapon.reload_time),
+            attacks,
+        }
+    }
+
+    pub fn from_secondary(weapon: &secondary::Secondary) -> WeaponStats {
+        let attacks = weapon
+            .attacks
+            .iter()
+            .map(|attack| {
+                let damage = damage_map!(attack.damage, [
+                    impact => Impact, puncture => Puncture, slash => Slash,
+                    heat => Heat, cold => Cold, electricity => Electricity, toxin => Toxin,
+                    blast => Blast, radiation => Radiation, gas => Gas, magnetic => Magnetic,
+                    viral => Viral, corrosive => Corrosive,
+                ]);
+                attack_stats(
+                    &attack.name,
+                    damage,
+                    attack.crit_chance,
+                    attack.crit_mult,
+                    attack.status_chance,
+                    attack.speed.unwrap_or(weapon.fire_rate),
+                )
+            })
+            .collect();
+
+        WeaponStats {
+            unique_name: weapon.unique_name.clone(),
+            name: weapon.name.clone(),
+            multishot: weapon.multishot as f64,
+            magazine_size: weapon.magazine_size.map(|size| size as f64),
+            reload_time: Some(weapon.reload_time),
+            attacks,
+        }
+    }
+
+    pub fn from_melee(weapon: &melee::Melee) -> WeaponStats {
+        let attacks = weapon
+            .attacks
+            .iter()
+            .map(|attack| {
+                let damage = damage_map!(attack.damage, [
+                    impact => Impact, puncture => Puncture, slash => Slash,
+                    heat => Heat, cold => Cold, electricity => Electricity, toxin => Toxin,
+                    blast => Blast, radiation => Radiation, gas => Gas, magnetic => Magnetic,
+                    viral => Viral, corrosive => Corrosive,
+                ]);
+                attack_stats(
+                    &attack.name,
+                    damage,
+                    attack.crit_chance,
+                    attack.crit_mult,
+                    attack.status_chance,
+                    attack.speed,
+                )
+            })
+            .collect();
+
+        WeaponStats {
+            unique_name: weapon.unique_name.clone(),
+            name: weapon.name.clone(),
+            multishot: 1.0,
+            magazine_size: None,
+            reload_time: None,
+            attacks,
+        }
+    }
+
+    /// Applies the bonuses to every attack mode
+    pub fn calculate(&self, bonuses: &[StatBonus]) -> DamageReport {
+        let total = |stat: Stat| -> f64 {
+            bonuses
+                .iter()
+                .filter(|bonus| bonus.stat == stat)
+                .map(|bonus| bonus.value)
+                .sum()
+        };
+
+        let multishot = self.multishot * (1.0 + total(Stat::Multishot));
+        let magazine_size = self.magazine_size.map(|size| {
+            (size * (1.0 + total(Stat::MagazineCapacity)))
+                .round()
+                .max(1.0)
+        });
+        let reload_time = self
+            .reload_time
+            .map(|time| time / (1.0 + total(Stat::ReloadSpeed)));
+
+        let attacks = self
+            .attacks
+            .iter()
+            .map(|attack| {
+                let damage = modded_damage(&attack.damage, bonuses, 1.0 + total(Stat::Damage));
+                let hit_damage: f64 = damage.values().sum();
+                let critical_chance = attack.critical_chance * (1.0 + total(Stat::CriticalChance));
+                let critical_multiplier =
+                    attack.critical_multiplier * (1.0 + total(Stat::CriticalMultiplier));
+                let status_chance = attack.status_chance * (1.0 + total(Stat::StatusChance));
+                let fire_rate = attack.fire_rate * (1.0 + total(Stat::FireRate));
+
+                // Crit tiers above 100% keep the same average
+                let average_crit = 1.0 + critical_chance * (critical_multiplier - 1.0);
+                let burst_dps = hit_damage * average_crit * multishot * fire_rate;
+
+                let sustained_dps = match (magazine_size, reload_time) {
+                    (Some(magazine), Some(reload)) if fire_rate > 0.0 => {
+                        let firing = magazine / fire_rate;
+                        burst_dps * firing / (firing + reload)
+                    }
+                    _ => burst_dps,
+                };
+
+                AttackReport {
+                    name: attack.name.clone(),
+                    damage,
+                    hit_damage,
+                    critical_chance,
+                    critical_multiplier,
+                    status_chance,
+                    multishot,
+                    fire_rate,
+                    burst_dps,
+                    sustained_dps,
+                    status_per_second: status_chance * multishot * fire_rate,
+                }
+            })
+            .collect();
+
+        DamageReport {
+            unique_name: self.unique_name.clone(),
+            name: self.name.clone(),
+            magazine_size,
+            reload_time,
+            attacks,
+        }
+    }
+}
+
+/// Applies damage and element bonuses to one hit.
+/// Mod elements combine in slot order, innate elements are added last.
+fn modded_damage(
+    base: &BTreeMap<DamageType, f64>,
+    bonuses: &[StatBonus],
+    damage_multiplier: f64,
+) -> BTreeMap<DamageType, f64> {
+    let base_total: f64 = base.values().sum();
+    let mut damage = BTreeMap::new();
+
+    // Physical bonuses only scale the matching base damage
+    for (&damage_type, &value) in base.iter().filter(|(t, _)| t.is_physical()) {
+        let bonus: f64 = bonuses
+            .iter()
+            .filter(|bonus| bonus.stat == Stat::Element(damage_type))
+            .map(|bonus| bonus.value)
+            .sum();
+        damage.insert(damage_type, value * damage_multiplier * (1.0 + bonus));
+    }
+
+    // Primary elements in the order they first appear, mods before innate
+    let mut elements: Vec<(DamageType, f64)> = Vec::new();
+    let mut add = |damage_type: DamageType, value: f64| match elements
+        .iter_mut()
+        .find(|(t, _)| *t == damage_type)
+    {
+        Some((_, total)) => *total += value,
+        None => elements.push((damage_type, value)),
+    };
+    for bonus in bonuses {
+        if let Stat::Element(damage_type) = bonus.stat
+            && !damage_type.is_physical()
+        {
+            add(damage_type, base_total * damage_multiplier * bonus.value);
+        }
+    }
+    for (&damage_type, &value) in base.iter().filter(|(t, _)| !t.is_physical()) {
+        add(damage_type, value * damage_multiplier);
+    }
+
+    let mut pending: Option<(DamageType, f64)> = None;
+    for (damage_type, value) in elements {
+        if !damage_type.is_primary_element() {
+            *damage.entry(damage_type).or_insert(0.0) += value;
+            continue;
+        }
+        match pending.take() {
+            Some((first, first_value)) => {
+                let combined = first
+                    .combine(damage_type)
+                    .expect("distinct primary elements always combine");
+                *damage.entry(combined).or_insert(0.0) += first_value + value;
+            }
+            None => pending = Some((damage_type, value)),
+        }
+    }
+    if let Some((damage_type, value)) = pending {
+        *damage.entry(damage_type).or_insert(0.0) += value;
+    }
+
+    damage
+}
+
+/// Modded numbers for one attack mode.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AttackReport {
+    pub name: String,
+    /// Damage per pellet or swing by type, before crits
+    pub damage: BTreeMap<DamageType, f64>,
+    pub hit_damage: f64,
+    pub critical_chance: f64,
+    pub critical_multiplier: f64,
+    pub status_chance: f64,
+    pub multishot: f64,
+    pub fire_rate: f64,
+    /// Damage per second while firing, average crits included
+    pub burst_dps: f64,
+    /// Burst DPS averaged over magazine and reload
+    pub sustained_dps: f64,
+    /// Expected status procs per second
+    pub status_per_second: f64,
+}
+
+/// Modded numbers for a weapon.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DamageReport {
+    pub unique_name: String,
+    pub name: String,
+    pub magazine_size: Option<f64>,
+    pub reload_time: Option<f64>,
+    pub attacks: Vec<AttackReport>,
+}
+
+impl DamageReport {
+    /// Damage report for a build, `None` if the item is not a weapon in the catalog
+    pub fn for_build(build: &Build, catalog: &Catalog) -> Option<DamageReport> {
+        let weapon = WeaponStats::from_item(catalog.get(&build.item_type)?)?;
+        Some(weapon.calculate(&build_bonuses(build, catalog)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::itemdata::mods::LevelStat;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-6,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    fn rifle() -> WeaponStats {
+        WeaponStats {
+            unique_name: "/Lotus/Weapons/Tenno/Rifle/TestRifle".to_string(),
+            name: "Test Rifle".to_string(),
+            multishot: 1.0,
+            magazine_size: Some(30.0),
+            reload_time: Some(2.0),
+            attacks: vec![AttackStats {
+                name: "Normal Attack".to_string(),
+                damage: BTreeMap::from([
+                    (DamageType::Impact, 10.0),
+                    (DamageType::Puncture, 10.0),
+                    (DamageType::Slash, 20.0),
+                ]),
+                critical_chance: 0.2,
+                critical_multiplier: 2.0,
+                status_chance: 0.1,
+                fire_rate: 10.0,
+            }],
+        }
+    }
+
+    #[test]
+    fn test_parse_stats() {
+        assert_eq!(
+            parse_stats("+90% <DT_FIRE_COLOR>Heat\r\n+60% Status Chance"),
+            vec![
+                StatBonus::new(Stat::Element(DamageType::Heat), 0.9),
+                StatBonus::new(Stat::StatusChance, 0.6),
+            ]
+        );
+        assert_eq!(
+            parse_stats("+60% Fire Rate (x2 for Bows)"),
+            vec![StatBonus::new(Stat::FireRate, 0.6)]
+        );
+        assert_eq!(
+            parse_stats("+120% Melee Damage"),
+            vec![StatBonus::new(Stat::Damage, 1.2)]
+        );
+        assert!(parse_stats("+30% Damage to Grineer").is_empty());
+    }
+
+    #[test]
+    fn test_mod_bonuses_clamp_rank() {
+        let serration = Mod {
+            level_stats: (0..=10)
+                .map(|rank| LevelStat {
+                    stats: vec![format!("+{}% Damage", 15 * (rank + 1))],
+                })
+                .collect(),
+            ..Default::default()
+        };
+        assert_eq!(
+            mod_bonuses(&serration, 10),
+            vec![StatBonus::new(Stat::Damage, 1.65)]
+        );
+        assert_eq!(mod_bonuses(&serration, 20), mod_bonuses(&serration, 10));
+        assert!(mod_bonuses(&Mod::default(), 3).is_empty());
+    }
+
+    #[test]
+    fn test_unmodded() {
+        let report = rifle().calculate(&[]);
+        let attack = &report.attacks[0];
+        assert_close(attack.hit_damage, 40.0);
+        // 40 * (1 + 0.2 * (2 - 1)) * 10
+        assert_close(attack.burst_dps, 480.0);
+        // 3s firing, 2s reloading
+        assert_close(attack.sustained_dps, 480.0 * 3.0 / 5.0);
+        assert_close(attack.status_per_second, 1.0);
+    }
+
+    #[test]
+    fn test_modded() {
+        let bonuses = [
+            StatBonus::new(Stat::Damage, 1.65),
+            StatBonus::new(Stat::Multishot, 0.9),
+            StatBonus::new(Stat::CriticalChance, 1.5),
+            StatBonus::new(Stat::CriticalMultiplier, 1.2),
+            StatBonus::new(Stat::Element(DamageType::Slash), 1.2),
+            StatBonus::new(Stat::MagazineCapacity, 0.3),
+            StatBonus::new(Stat::ReloadSpeed, 1.0),
+        ];
+        let report = rifle().calculate(&bonuses);
+        assert_eq!(report.magazine_size, Some(39.0));
+        assert_eq!(report.reload_time, Some(1.0));
+
+        let attack = &report.attacks[0];
+        assert_close(attack.damage[&DamageType::Impact], 26.5);
+        assert_close(attack.damage[&DamageType::Slash], 20.0 * 2.65 * 2.2);
+        let hit = 26.5 + 26.5 + 116.6;
+        assert_close(attack.hit_damage, hit);
+        assert_close(attack.critical_chance, 0.5);
+        assert_close(attack.critical_multiplier, 4.4);
+        assert_close(attack.multishot, 1.9);
+        let burst = hit * (1.0 + 0.5 * 3.4) * 1.9 * 10.0;
+        assert_close(attack.burst_dps, burst);
+        assert_close(attack.sustained_dps, burst * 3.9 / 4.9);
+    }
+
+    #[test]
+    fn test_elements_combine_in_order() {
+        let mut weapon = rifle();
+        weapon.attacks[0].damage.insert(DamageType::Toxin, 10.0);
+        let bonuses = [
+            StatBonus::new(Stat::Element(DamageType::Heat), 1.0),
+            StatBonus::new(Stat::Element(DamageType::Cold), 0.5),
+            StatBonus::new(Stat::Element(DamageType::Electricity), 0.5),
+        ];
+        let attack = &weapon.calculate(&bonuses).attacks[0];
+        // Heat and cold from mods make blast, electricity pairs with the innate toxin
+        assert_close(attack.damage[&DamageType::Blast], 50.0 + 25.0);
+        assert_close(attack.damage[&DamageType::Corrosive], 25.0 + 10.0);
+        assert!(!attack.damage.contains_key(&DamageType::Heat));
+        assert!(!attack.damage.contains_key(&DamageType::Toxin));
+        assert_close(attack.hit_damage, 40.0 + 75.0 + 35.0);
+    }
+
+    #[test]
+    fn test_melee_has_no_reload() {
+        let weapon = WeaponStats::from_melee(&melee::Melee {
+            name: "Test Sword".to_string(),
+            attacks: vec![melee::Attack {
+                name: "Normal Attack".to_string(),
+                speed: 1.0,
+                crit_chance: 10.0,
+                crit_mult: 2.0,
+                status_chance: 20.0,
+                damage: melee::Damage {
+                    slash: Some(100.0),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+        let attack = &weapon
+            .calculate(&[StatBonus::new(Stat::FireRate, 0.5)])
+            .attacks[0];
+        assert_close(attack.fire_rate, 1.5);
+        assert_close(attack.burst_dps, 100.0 * 1.1 * 1.5);
+        assert_close(attack.sustained_dps, attack.burst_dps);
+        assert_close(attack.status_per_second, 0.3);
+    }
+}
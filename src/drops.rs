@@ -0,0 +1,397 @@
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::inventory::Inventory;
+use crate::itemdata::catalog::{Catalog, DropInfo};
+use crate::wishlist::WishlistReport;
+
+/// Relic refinements, itemdata appends them to relic drop locations
+pub const REFINEMENTS: [&str; 4] = ["Intact", "Exceptional", "Flawless", "Radiant"];
+
+/// Splits "Axi K6 Relic (Radiant)" into "Axi K6 Relic" and "Radiant".
+/// Other parentheses such as "Earth/Mantle (Capture)" are part of the location.
+pub fn split_refinement(location: &str) -> (&str, Option<&str>) {
+    REFINEMENTS
+        .iter()
+        .find_map(|refinement| {
+            location
+                .strip_suffix(&format!(" ({})", refinement))
+                .map(|base| (base, Some(*refinement)))
+        })
+        .unwrap_or((location, None))
+}
+
+/// One row of the drop table.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DropEntry {
+    /// Catalog item or component the drop data belongs to
+    pub unique_name: String,
+    /// What drops, e.g. "Harrow Prime Chassis Blueprint"
+    pub item: String,
+    /// Without the relic refinement, e.g. "Axi K6 Relic" or "Earth/Mantle (Capture)"
+    pub location: String,
+    pub refinement: Option<String>,
+    pub rarity: String,
+    /// Probability between 0 and 1
+    pub chance: f64,
+}
+
+impl DropEntry {
+    fn new(unique_name: &str, drop: &DropInfo) -> DropEntry {
+        let (location, refinement) = split_refinement(&drop.location);
+        DropEntry {
+            unique_name: unique_name.to_string(),
+            item: drop.name.clone(),
+            location: location.to_string(),
+            refinement: refinement.map(str::to_string),
+            rarity: drop.rarity.clone(),
+            chance: drop.chance,
+        }
+    }
+
+    /// Location as itemdata writes it, with the refinement
+    pub fn full_location(&self) -> String {
+        match &self.refinement {
+            Some(refinement) => format!("{} ({})", self.location, refinement),
+            None => self.location.clone(),
+        }
+    }
+}
+
+/// Every `drops` array of the catalog in one table, indexed by location and by item.
+/// Components shared by several items carry the same drops and are only counted once.
+#[derive(Default, Debug, Clone)]
+pub struct DropTable {
+    entries: Vec<DropEntry>,
+    /// Lowercase location to entries
+    by_location: HashMap<String, Vec<usize>>,
+    /// `unique_name` to entries
+    by_item: HashMap<String, Vec<usize>>,
+}
+
+impl DropTable {
+    pub fn build(catalog: &Catalog) -> DropTable {
+        let mut table = DropTable::default();
+        let mut seen: HashSet<(String, String, String)> = HashSet::new();
+
+        for item in catalog.iter() {
+            let sources = std::iter::once((item.unique_name().to_string(), item.drops())).chain(
+                item.components()
+                    .into_iter()
+                    .map(|component| (component.unique_name, component.drops)),
+            );
+            for (unique_name, drops) in sources {
+                for drop in &drops {
+                    let key = (
+                        unique_name.clone(),
+                        drop.name.clone(),
+                        drop.location.clone(),
+                    );
+                    if seen.insert(key) {
+                        table.push(DropEntry::new(&unique_name, drop));
+                    }
+                }
+            }
+        }
+        table
+    }
+
+    fn push(&mut self, entry: DropEntry) {
+        let index = self.entries.len();
+        self.by_location
+            .entry(entry.location.to_lowercase())
+            .or_default()
+            .push(index);
+        self.by_item
+            .entry(entry.unique_name.clone())
+            .or_default()
+            .push(index);
+        self.entries.push(entry);
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &DropEntry> {
+        self.entries.iter()
+    }
+
+    /// Everything that drops at a location, best chance first.
+    /// Case-insensitive, a relic location without refinement matches every refinement.
+    pub fn at_location(&self, location: &str) -> Vec<&DropEntry> {
+        let (base, refinement) = split_refinement(location.trim());
+        let mut entries: Vec<&DropEntry> = self
+            .by_location
+            .get(&base.to_lowercase())
+            .into_iter()
+            .flatten()
+            .map(|index| &self.entries[*index])
+            .filter(|entry| refinement.is_none() || entry.refinement.as_deref() == refinement)
+            .collect();
+        entries.sort_by(|a, b| b.chance.total_cmp(&a.chance).then(a.item.cmp(&b.item)));
+        entries
+    }
+
+    /// Where an item or component drops, best chance first, one entry per location
+    pub fn sources(&self, unique_name: &str) -> Vec<&DropEntry> {
+        let mut entries: Vec<&DropEntry> = self
+            .by_item
+            .get(unique_name)
+            .into_iter()
+            .flatten()
+            .map(|index| &self.entries[*index])
+            .collect();
+        entries.sort_by(|a, b| b.chance.total_cmp(&a.chance));
+
+        let mut locations = HashSet::new();
+        entries.retain(|entry| locations.insert(entry.location.as_str()));
+        entries
+    }
+
+    /// `unique_name` of the item a query names, by unique name or case-insensitive drop name
+    pub fn find(&self, query: &str) -> Option<&str> {
+        if let Some((unique_name, _)) = self.by_item.get_key_value(query) {
+            return Some(unique_name);
+        }
+        self.entries
+            .iter()
+            .find(|entry| entry.item.eq_ignore_ascii_case(query))
+            .map(|entry| entry.unique_name.as_str())
+    }
+}
+
+/// A drop at a location with what the account has of it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LocationDrop {
+    pub drop: DropEntry,
+    pub owned: i64,
+    /// Missing for an item on the wishlist
+    pub wishlisted: bool,
+}
+
+impl LocationDrop {
+    pub fn needed(&self) -> bool {
+        self.owned == 0 || self.wishlisted
+    }
+}
+
+/// What a location drops, answering "what do I still need from here".
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LocationReport {
+    pub location: String,
+    /// Best chance first
+    pub drops: Vec<LocationDrop>,
+}
+
+impl LocationReport {
+    pub fn build(
+        table: &DropTable,
+        location: &str,
+        inventory: &Inventory,
+        wishlist: &WishlistReport,
+    ) -> LocationReport {
+        let wanted: HashSet<&str> = wishlist
+            .missing
+            .iter()
+            .map(|shortfall| shortfall.unique_name.as_str())
+            .collect();
+
+        let drops = table
+            .at_location(location)
+            .into_iter()
+            .map(|entry| {
+                let blueprint = blueprint_of(&entry.unique_name);
+                LocationDrop {
+                    drop: entry.clone(),
+                    owned: owned_count(inventory, &entry.unique_name),
+                    wishlisted: wanted.contains(entry.unique_name.as_str())
+                        || wanted.contains(blueprint.as_str()),
+                }
+            })
+            .collect();
+        LocationReport {
+            location: location.trim().to_string(),
+            drops,
+        }
+    }
+
+    pub fn needed(&self) -> impl Iterator<Item = &LocationDrop> {
+        self.drops.iter().filter(|drop| drop.needed())
+    }
+}
+
+/// `XChassisComponent` is built from `XChassisBlueprint`, `XReceiver` from `XReceiverBlueprint`
+fn blueprint_of(unique_name: &str) -> String {
+    match unique_name.strip_suffix("Component") {
+        Some(stem) => format!("{stem}Blueprint"),
+        None => format!("{unique_name}Blueprint"),
+    }
+}
+
+/// Copies owned in any form: blueprint, built part, resource, mod or arsenal item
+fn owned_count(inventory: &Inventory, unique_name: &str) -> i64 {
+    let blueprints = if unique_name.ends_with("Blueprint") {
+        0
+    } else {
+        inventory.recipe_count(&blueprint_of(unique_name))
+    };
+    blueprints
+        + inventory.recipe_count(unique_name)
+        + inventory.misc_item_count(unique_name)
+        + inventory.mod_count(unique_name)
+        + inventory.equipment_count(unique_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::tests::load_test_inventory;
+    use crate::itemdata::catalog::CatalogItem;
+    use crate::itemdata::catalog::tests::{neural_sensors, sensor_gun};
+    use crate::itemdata::warframe::{self, Warframe};
+
+    fn warframe_part(unique_name: &str, name: &str, drops: &[(&str, f64)]) -> warframe::Component {
+        warframe::Component {
+            name: name.to_string(),
+            unique_name: unique_name.to_string(),
+            item_count: 1,
+            drops: drops
+                .iter()
+                .map(|(location, chance)| warframe::Drop {
+                    chance: *chance,
+                    location: location.to_string(),
+                    rarity: "Rare".to_string(),
+                    type_field: format!("{} Blueprint", name),
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    fn catalog() -> Catalog {
+        let harrow = Warframe {
+            name: "Harrow Prime".to_string(),
+            unique_name: "/Lotus/Powersuits/Priest/HarrowPrime".to_string(),
+            components: vec![warframe_part(
+                "/Lotus/Types/Recipes/WarframeRecipes/HarrowPrimeChassisComponent",
+                "Harrow Prime Chassis",
+                &[
+                    ("Axi K6 Relic (Intact)", 0.02),
+                    ("Axi K6 Relic (Radiant)", 0.1),
+                ],
+            )],
+            ..Default::default()
+        };
+        let oberon = Warframe {
+            name: "Oberon".to_string(),
+            unique_name: "/Lotus/Powersuits/Paladin/Paladin".to_string(),
+            components: vec![warframe_part(
+                "/Lotus/Types/Recipes/WarframeRecipes/PaladinChassisComponent",
+                "Oberon Chassis",
+                &[("Axi K6 Relic (Intact)", 0.2533)],
+            )],
+            ..Default::default()
+        };
+        [
+            CatalogItem::Warframe(harrow),
+            CatalogItem::Warframe(oberon),
+            sensor_gun("Gun A", "/Lotus/Weapons/Test/GunA"),
+            sensor_gun("Gun B", "/Lotus/Weapons/Test/GunB"),
+            neural_sensors(),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    #[test]
+    fn test_split_refinement() {
+        assert_eq!(
+            split_refinement("Axi K6 Relic (Radiant)"),
+            ("Axi K6 Relic", Some("Radiant"))
+        );
+        assert_eq!(
+            split_refinement("Earth/Mantle (Capture)"),
+            ("Earth/Mantle (Capture)", None)
+        );
+    }
+
+    #[test]
+    fn test_drop_table() {
+        let table = DropTable::build(&catalog());
+        // The Io drop repeated by the Neural Sensors of both guns is only listed once
+        assert_eq!(table.len(), 5);
+
+        let relic: Vec<(&str, Option<&str>)> = table
+            .at_location("axi k6 relic")
+            .iter()
+            .map(|entry| (entry.item.as_str(), entry.refinement.as_deref()))
+            .collect();
+        assert_eq!(
+            relic,
+            vec![
+                ("Oberon Chassis Blueprint", Some("Intact")),
+                ("Harrow Prime Chassis Blueprint", Some("Radiant")),
+                ("Harrow Prime Chassis Blueprint", Some("Intact")),
+            ]
+        );
+        assert_eq!(table.at_location("Axi K6 Relic (Radiant)").len(), 1);
+        assert_eq!(
+            table.at_location("Axi K6 Relic (Radiant)")[0].full_location(),
+            "Axi K6 Relic (Radiant)"
+        );
+
+        let sensors = table.find("neural sensors").unwrap();
+        let locations: Vec<&str> = table
+            .sources(sensors)
+            .iter()
+            .map(|entry| entry.location.as_str())
+            .collect();
+        assert_eq!(
+            locations,
+            vec!["Jupiter/Themisto (Assassination)", "Jupiter/Io (Defense)"]
+        );
+
+        let chassis = table.find("Harrow Prime Chassis Blueprint").unwrap();
+        let sources = table.sources(chassis);
+        assert_eq!(sources.len(), 1);
+        assert_eq!(sources[0].refinement.as_deref(), Some("Radiant"));
+    }
+
+    #[test]
+    fn test_location_report() {
+        let table = DropTable::build(&catalog());
+        let inventory = load_test_inventory();
+
+        let report = LocationReport::build(
+            &table,
+            "Axi K6 Relic",
+            &inventory,
+            &WishlistReport::default(),
+        );
+        let owned: Vec<(&str, i64)> = report
+            .drops
+            .iter()
+            .map(|drop| (drop.drop.item.as_str(), drop.owned))
+            .collect();
+        assert_eq!(
+            owned,
+            vec![
+                ("Oberon Chassis Blueprint", 6),
+                ("Harrow Prime Chassis Blueprint", 0),
+                ("Harrow Prime Chassis Blueprint", 0),
+            ]
+        );
+        let needed: Vec<&str> = report
+            .needed()
+            .map(|drop| drop.drop.item.as_str())
+            .collect();
+        assert_eq!(needed.len(), 2);
+        assert!(needed.iter().all(|item| item.starts_with("Harrow")));
+    }
+}
@@ -0,0 +1,233 @@
+use serde::{Deserialize, Serialize};
+
+use crate::inventory::Inventory;
+use crate::itemdata::catalog::{Catalog, ItemCategory};
+
+/// Inventory array an entry came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum InventorySection {
+    Suits,
+    LongGuns,
+    Pistols,
+    Melee,
+    SpaceSuits,
+    SpaceGuns,
+    SpaceMelee,
+    RawUpgrades,
+    Upgrades,
+    Recipes,
+    MiscItems,
+    Consumables,
+    LevelKeys,
+    FusionTreasures,
+}
+
+/// Inventory entry joined with its catalog metadata.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EnrichedItem {
+    pub section: InventorySection,
+    pub item_type: String,
+    pub name: String,
+    /// Category of the catalog item (for components, of the item they build)
+    pub category: ItemCategory,
+    pub mastery_req: Option<i64>,
+    pub tradable: bool,
+    pub xp: Option<i64>,
+    pub polarized: Option<i64>,
+    /// Stack size, 1 for items that are stored individually
+    pub item_count: i64,
+}
+
+/// Inventory entry whose `item_type` is not in the catalog.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UnresolvedItem {
+    pub section: InventorySection,
+    pub item_type: String,
+    pub item_count: i64,
+}
+
+/// Inventory with every entry resolved against the item catalog.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EnrichedInventory {
+    pub items: Vec<EnrichedItem>,
+    /// Entries the catalog does not know about, usually new items after a game update
+    pub unresolved: Vec<UnresolvedItem>,
+}
+
+/// Owned data shared by every inventory array, before the catalog join.
+struct OwnedEntry<'a> {
+    section: InventorySection,
+    item_type: &'a str,
+    xp: Option<i64>,
+    polarized: Option<i64>,
+    item_count: i64,
+}
+
+impl EnrichedInventory {
+    pub fn build(inventory: &Inventory, catalog: &Catalog) -> EnrichedInventory {
+        let mut enriched = EnrichedInventory::default();
+
+        for entry in owned_entries(inventory) {
+            match catalog.resolve(entry.item_type) {
+                Some(resolved) => {
+                    let item = resolved.item();
+                    enriched.items.push(EnrichedItem {
+                        section: entry.section,
+                        item_type: entry.item_type.to_string(),
+                        name: resolved.name(),
+                        category: item.category(),
+                        mastery_req: item.mastery_req(),
+                        tradable: item.tradable(),
+                        xp: entry.xp,
+                        polarized: entry.polarized,
+                        item_count: entry.item_count,
+                    });
+                }
+                None => enriched.unresolved.push(UnresolvedItem {
+                    section: entry.section,
+                    item_type: entry.item_type.to_string(),
+                    item_count: entry.item_count,
+                }),
+            }
+        }
+
+        if !enriched.unresolved.is_empty() {
+            log::debug!(
+                "{} inventory entries not found in catalog",
+                enriched.unresolved.len()
+            );
+        }
+
+        enriched
+    }
+
+    pub fn section(&self, section: InventorySection) -> impl Iterator<Item = &EnrichedItem> {
+        self.items
+            .iter()
+            .filter(move |item| item.section == section)
+    }
+}
+
+fn owned_entries(inventory: &Inventory) -> Vec<OwnedEntry<'_>> {
+    let mut entries = Vec::new();
+
+    macro_rules! equipment {
+        ($list:expr, $section:expr) => {
+            entries.extend($list.iter().map(|item| OwnedEntry {
+                section: $section,
+                item_type: &item.item_type,
+                xp: item.xp,
+                polarized: item.polarized,
+                item_count: 1,
+            }))
+        };
+    }
+
+    equipment!(inventory.suits, InventorySection::Suits);
+    equipment!(inventory.long_guns, InventorySection::LongGuns);
+    equipment!(inventory.pistols, InventorySection::Pistols);
+    equipment!(inventory.melee, InventorySection::Melee);
+    equipment!(inventory.space_suits, InventorySection::SpaceSuits);
+    equipment!(inventory.space_guns, InventorySection::SpaceGuns);
+    equipment!(inventory.space_melee, InventorySection::SpaceMelee);
+
+    macro_rules! counted {
+        ($list:expr, $section:expr) => {
+            entries.extend($list.iter().map(|item| OwnedEntry {
+                section: $section,
+                item_type: &item.item_type,
+                xp: None,
+                polarized: None,
+                item_count: item.item_count,
+            }))
+        };
+    }
+
+    counted!(inventory.raw_upgrades, InventorySection::RawUpgrades);
+    entries.extend(inventory.upgrades.iter().map(|item| OwnedEntry {
+        section: InventorySection::Upgrades,
+        item_type: &item.item_type,
+        xp: None,
+        polarized: None,
+        item_count: 1,
+    }));
+    counted!(inventory.recipes, InventorySection::Recipes);
+    counted!(inventory.misc_items, InventorySection::MiscItems);
+    counted!(inventory.consumables, InventorySection::Consumables);
+    counted!(inventory.level_keys, InventorySection::LevelKeys);
+    counted!(
+        inventory.fusion_treasures,
+        InventorySection::FusionTreasures
+    );
+
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::tests::load_test_inventory;
+    use crate::itemdata::catalog::CatalogItem;
+    use crate::itemdata::warframe;
+
+    #[test]
+    fn test_enrich_inventory() {
+        let catalog: Catalog = [CatalogItem::Warframe(warframe::Warframe {
+            name: "Zephyr Prime".to_string(),
+            unique_name: "/Lotus/Powersuits/Tengu/ZephyrPrime".to_string(),
+            mastery_req: Some(0),
+            masterable: true,
+            components: vec![warframe::Component {
+                unique_name: "/Lotus/Types/Recipes/WarframeRecipes/ZephyrPrimeChassisComponent"
+                    .to_string(),
+                name: "Chassis".to_string(),
+                item_count: 1,
+                ..Default::default()
+            }],
+            ..Default::default()
+        })]
+        .into_iter()
+        .collect();
+
+        let inventory = load_test_inventory();
+        let enriched = EnrichedInventory::build(&inventory, &catalog);
+
+        let suit = enriched
+            .section(InventorySection::Suits)
+            .find(|item| item.item_type == "/Lotus/Powersuits/Tengu/ZephyrPrime")
+            .unwrap();
+        assert_eq!(suit.name, "Zephyr Prime");
+        assert_eq!(suit.category, ItemCategory::Warframe);
+        assert!(suit.xp.is_some());
+
+        let blueprint = enriched
+            .section(InventorySection::Recipes)
+            .find(|item| {
+                item.item_type == "/Lotus/Types/Recipes/WarframeRecipes/ZephyrPrimeChassisBlueprint"
+            })
+            .unwrap();
+        assert_eq!(blueprint.name, "Zephyr Prime Chassis Blueprint");
+
+        let total = inventory.suits.len()
+            + inventory.long_guns.len()
+            + inventory.pistols.len()
+            + inventory.melee.len()
+            + inventory.space_suits.len()
+            + inventory.space_guns.len()
+            + inventory.space_melee.len()
+            + inventory.raw_upgrades.len()
+            + inventory.upgrades.len()
+            + inventory.recipes.len()
+            + inventory.misc_items.len()
+            + inventory.consumables.len()
+            + inventory.level_keys.len()
+            + inventory.fusion_treasures.len();
+        assert_eq!(enriched.items.len() + enriched.unresolved.len(), total);
+        assert!(
+            enriched
+                .unresolved
+                .iter()
+                .any(|item| item.item_type == "/Lotus/Powersuits/Ninja/Ninja")
+        );
+    }
+}
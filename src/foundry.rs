@@ -0,0 +1,352 @@
+use std::collections::HashSet;
+use std::env;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::process::Command;
+use tokio::time::sleep;
+
+use crate::inventory::Inventory;
+use crate::inventory::recipe::PendingRecipe;
+use crate::itemdata::catalog::Catalog;
+use crate::storage;
+
+/// How often the scheduler checks for finished builds and a newer saved inventory
+pub const POLL_INTERVAL: Duration = Duration::from_secs(30);
+
+/// One build in the foundry.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FoundryBuild {
+    pub item_id: String,
+    /// Blueprint being built, e.g. `/Lotus/Types/Recipes/WarframeRecipes/XakuPrimeChassisBlueprint`
+    pub item_type: String,
+    /// Product name, e.g. "Xaku Prime Chassis"
+    pub name: String,
+    pub completion_date: Option<DateTime<Utc>>,
+}
+
+impl FoundryBuild {
+    pub fn new(recipe: &PendingRecipe, catalog: &Catalog) -> FoundryBuild {
+        FoundryBuild {
+            item_id: recipe.item_id.oid.clone(),
+            item_type: recipe.item_type.clone(),
+            name: product_name(&recipe.item_type, catalog),
+            completion_date: recipe.completion_date.date,
+        }
+    }
+
+    /// Builds without a completion date are treated as ready
+    pub fn is_ready(&self, now: DateTime<Utc>) -> bool {
+        self.completion_date.is_none_or(|date| date <= now)
+    }
+
+    /// Zero once the build is ready
+    pub fn remaining(&self, now: DateTime<Utc>) -> chrono::Duration {
+        self.completion_date
+            .map(|date| (date - now).max(chrono::Duration::zero()))
+            .unwrap_or_else(chrono::Duration::zero)
+    }
+}
+
+/// Name of what a blueprint builds, from the catalog or else from the path
+fn product_name(item_type: &str, catalog: &Catalog) -> String {
+    match catalog.resolve(item_type) {
+        Some(resolved) => {
+            let name = resolved.name();
+            name.strip_suffix(" Blueprint")
+                .map(str::to_string)
+                .unwrap_or(name)
+        }
+        None => {
+            let name = item_type.rsplit('/').next().unwrap_or(item_type);
+            name.strip_suffix("Blueprint")
+                .or_else(|| name.strip_suffix("Recipe"))
+                .unwrap_or(name)
+                .to_string()
+        }
+    }
+}
+
+/// Pending builds split by whether they can be claimed, each sorted by completion date.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FoundryReport {
+    pub building: Vec<FoundryBuild>,
+    pub ready: Vec<FoundryBuild>,
+}
+
+impl FoundryReport {
+    pub fn build(inventory: &Inventory, catalog: &Catalog, now: DateTime<Utc>) -> FoundryReport {
+        let mut builds: Vec<FoundryBuild> = inventory
+            .pending_recipes
+            .iter()
+            .map(|recipe| FoundryBuild::new(recipe, catalog))
+            .collect();
+        builds.sort_by_key(|build| build.completion_date);
+
+        let (ready, building) = builds.into_iter().partition(|build| build.is_ready(now));
+        FoundryReport { building, ready }
+    }
+}
+
+/// A build that finished while `wf-info-2` was running.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BuildReady {
+    pub item_id: String,
+    pub name: String,
+    pub completion_date: Option<DateTime<Utc>>,
+}
+
+impl BuildReady {
+    pub fn message(&self) -> String {
+        format!("{} is ready to claim in the foundry", self.name)
+    }
+}
+
+/// Where `BuildReady` events are sent.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Notifier {
+    Stdout,
+    /// Program run with a title and the message, e.g. `notify-send`
+    Command(String),
+    /// URL that receives a JSON POST, `content` makes it work with Discord webhooks
+    Webhook(String),
+}
+
+impl Notifier {
+    /// Stdout, plus `WF_NOTIFY_COMMAND` and `WF_NOTIFY_WEBHOOK` when set
+    pub fn from_env() -> Vec<Notifier> {
+        let mut notifiers = vec![Notifier::Stdout];
+        if let Ok(command) = env::var("WF_NOTIFY_COMMAND") {
+            notifiers.push(Notifier::Command(command));
+        }
+        if let Ok(url) = env::var("WF_NOTIFY_WEBHOOK") {
+            notifiers.push(Notifier::Webhook(url));
+        }
+        notifiers
+    }
+
+    pub async fn notify(&self, event: &BuildReady) -> anyhow::Result<()> {
+        match self {
+            Notifier::Stdout => println!("{}", event.message()),
+            Notifier::Command(command) => {
+                let status = Command::new(command)
+                    .arg("wf-info-2")
+                    .arg(event.message())
+                    .status()
+                    .await?;
+                if !status.success() {
+                    anyhow::bail!("{} exited with {}", command, status);
+                }
+            }
+            Notifier::Webhook(url) => {
+                let body = serde_json::json!({
+                    "content": event.message(),
+                    "item_id": event.item_id,
+                    "name": event.name,
+                    "completion_date": event.completion_date,
+                });
+                reqwest::Client::new()
+                    .post(url)
+                    .json(&body)
+                    .send()
+                    .await?
+                    .error_for_status()?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Tracks pending builds and reports each one once when its completion time passes.
+#[derive(Default, Debug, Clone)]
+pub struct FoundryScheduler {
+    builds: Vec<FoundryBuild>,
+    notified: HashSet<String>,
+    /// Last time builds were checked, `None` before the first inventory
+    checked_at: Option<DateTime<Utc>>,
+}
+
+impl FoundryScheduler {
+    /// Replaces the tracked builds. Builds already finished on the first load, or before the
+    /// last check, are not reported; builds finishing after the last check still are.
+    pub fn update(&mut self, builds: Vec<FoundryBuild>, now: DateTime<Utc>) {
+        let ids: HashSet<&str> = builds.iter().map(|build| build.item_id.as_str()).collect();
+        self.notified.retain(|id| ids.contains(id.as_str()));
+        let cutoff = *self.checked_at.get_or_insert(now);
+        for build in &builds {
+            if build.is_ready(cutoff) {
+                self.notified.insert(build.item_id.clone());
+            }
+        }
+        self.builds = builds;
+    }
+
+    /// Builds that finished since the last call
+    pub fn due(&mut self, now: DateTime<Utc>) -> Vec<BuildReady> {
+        self.checked_at = Some(now);
+        let mut events = Vec::new();
+        for build in &self.builds {
+            if build.is_ready(now) && self.notified.insert(build.item_id.clone()) {
+                events.push(BuildReady {
+                    item_id: build.item_id.clone(),
+                    name: build.name.clone(),
+                    completion_date: build.completion_date,
+                });
+            }
+        }
+        events
+    }
+
+    pub fn next_completion(&self) -> Option<DateTime<Utc>> {
+        self.builds
+            .iter()
+            .filter(|build| !self.notified.contains(&build.item_id))
+            .filter_map(|build| build.completion_date)
+            .min()
+    }
+}
+
+/// Watches the saved inventory and notifies when builds finish. Runs until the task is dropped.
+pub async fn run_scheduler(catalog: Arc<Catalog>, notifiers: Vec<Notifier>) {
+    let mut scheduler = FoundryScheduler::default();
+    let mut loaded: Option<SystemTime> = None;
+
+    loop {
+        let modified = storage::app_cache_dir()
+            .ok()
+            .and_then(|dir| std::fs::metadata(dir.join("inventory.json")).ok())
+            .and_then(|meta| meta.modified().ok());
+        if modified.is_some() && modified != loaded {
+            match storage::load_inventory() {
+                Ok(inventory) => {
+                    let builds = inventory
+                        .pending_recipes
+                        .iter()
+                        .map(|recipe| FoundryBuild::new(recipe, &catalog))
+                        .collect();
+                    scheduler.update(builds, Utc::now());
+                    loaded = modified;
+                    log::debug!("Foundry next completion: {:?}", scheduler.next_completion());
+                }
+                Err(e) => log::error!("Failed to load inventory for the foundry: {:#}", e),
+            }
+        }
+
+        for event in scheduler.due(Utc::now()) {
+            for notifier in &notifiers {
+                if let Err(e) = notifier.notify(&event).await {
+                    log::error!("Failed to send foundry notification: {:#}", e);
+                }
+            }
+        }
+
+        sleep(POLL_INTERVAL).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::tests::load_test_inventory;
+    use crate::itemdata::catalog::CatalogItem;
+    use crate::itemdata::warframe::{Component, Warframe};
+    use chrono::TimeZone;
+
+    fn xaku_prime() -> CatalogItem {
+        CatalogItem::Warframe(Warframe {
+            name: "Xaku Prime".to_string(),
+            unique_name: "/Lotus/Powersuits/Xaku/XakuPrime".to_string(),
+            components: vec![Component {
+                name: "Chassis".to_string(),
+                unique_name: "/Lotus/Types/Recipes/WarframeRecipes/XakuPrimeChassisComponent"
+                    .to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        })
+    }
+
+    fn at(ms: i64) -> DateTime<Utc> {
+        Utc.timestamp_millis_opt(ms).unwrap()
+    }
+
+    #[test]
+    fn test_foundry_report() {
+        let catalog: Catalog = [xaku_prime()].into_iter().collect();
+        let inventory = load_test_inventory();
+
+        // Between the Xaku Prime chassis and the Yareli Prime blueprint
+        let now = at(1_768_500_000_000);
+        let report = FoundryReport::build(&inventory, &catalog, now);
+
+        let ready: Vec<&str> = report
+            .ready
+            .iter()
+            .map(|build| build.name.as_str())
+            .collect();
+        assert_eq!(ready, vec!["DistillPrint", "Xaku Prime Chassis"]);
+
+        let building: Vec<&str> = report
+            .building
+            .iter()
+            .map(|build| build.name.as_str())
+            .collect();
+        assert_eq!(building, vec!["EntSphereHammer", "YareliPrime"]);
+        assert_eq!(
+            report.building[0].remaining(now),
+            chrono::Duration::seconds(4_211)
+        );
+        assert_eq!(report.ready[1].remaining(now), chrono::Duration::zero());
+    }
+
+    #[test]
+    fn test_scheduler_fires_once() {
+        let catalog = Catalog::default();
+        let builds: Vec<FoundryBuild> = load_test_inventory()
+            .pending_recipes
+            .iter()
+            .map(|recipe| FoundryBuild::new(recipe, &catalog))
+            .collect();
+
+        let mut scheduler = FoundryScheduler::default();
+        scheduler.update(builds.clone(), at(1_768_500_000_000));
+        assert_eq!(scheduler.due(at(1_768_500_000_000)), vec![]);
+        assert_eq!(scheduler.next_completion(), Some(at(1_768_504_211_000)));
+
+        let due = scheduler.due(at(1_768_600_000_000));
+        let names: Vec<&str> = due.iter().map(|event| event.name.as_str()).collect();
+        assert_eq!(names, vec!["YareliPrime", "EntSphereHammer"]);
+        assert_eq!(
+            due[0].message(),
+            "YareliPrime is ready to claim in the foundry"
+        );
+        assert!(scheduler.due(at(1_768_700_000_000)).is_empty());
+
+        // A refreshed inventory with the same builds must not fire again
+        scheduler.update(builds, at(1_768_700_000_000));
+        assert!(scheduler.due(at(1_768_800_000_000)).is_empty());
+        assert_eq!(scheduler.next_completion(), None);
+    }
+
+    #[test]
+    fn test_scheduler_build_finished_between_updates() {
+        let catalog = Catalog::default();
+        let builds: Vec<FoundryBuild> = load_test_inventory()
+            .pending_recipes
+            .iter()
+            .map(|recipe| FoundryBuild::new(recipe, &catalog))
+            .collect();
+
+        let mut scheduler = FoundryScheduler::default();
+        scheduler.update(builds.clone(), at(1_768_500_000_000));
+        assert!(scheduler.due(at(1_768_500_000_000)).is_empty());
+
+        // The hammer finishes at 1_768_504_211_000, before the inventory is reloaded
+        scheduler.update(builds, at(1_768_505_000_000));
+        let due = scheduler.due(at(1_768_505_000_000));
+        let names: Vec<&str> = due.iter().map(|event| event.name.as_str()).collect();
+        assert_eq!(names, vec!["EntSphereHammer"]);
+    }
+}
@@ -0,0 +1,352 @@
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::inventory::Inventory;
+use crate::inventory::infested_foundry::InfestedFoundry;
+use crate::itemdata::catalog::{Catalog, CatalogItem};
+
+/// Helminth XP needed for ranks 1 to 8.
+/// Rank 1 costs 225,000 XP and every rank after it 112,500 more than the one before.
+pub const HELMINTH_RANK_XP: [i64; 8] = helminth_rank_xp();
+
+const fn helminth_rank_xp() -> [i64; 8] {
+    let mut thresholds = [0; 8];
+    let mut rank = 1;
+    while rank <= 8 {
+        thresholds[rank - 1] = 112_500 * (rank as i64) * (rank as i64 + 3) / 2;
+        rank += 1;
+    }
+    thresholds
+}
+
+/// Abilities the Helminth offers without subsuming anything
+pub const HELMINTH_ABILITIES: [&str; 8] = [
+    "Infested Mobility",
+    "Expedite Suffering",
+    "Energized Munitions",
+    "Empower",
+    "Marked for Death",
+    "Perspicacity",
+    "Rebuild Shields",
+    "Master's Summons",
+];
+
+/// Ability each warframe grants when subsumed, by warframe name, from
+/// <https://wiki.warframe.com/w/Helminth>. warframe-items does not say which ability is
+/// subsumable, so frames released after this table get no ability until it is updated.
+#[rustfmt::skip]
+const SUBSUMED_ABILITIES: &[(&str, &str)] = &[
+    ("Ash", "Shuriken"), ("Atlas", "Petrify"), ("Banshee", "Silence"),
+    ("Baruuk", "Elude"), ("Caliban", "Sentient Wrath"), ("Chroma", "Elemental Ward"),
+    ("Citrine", "Preserving Shell"), ("Cyte-09", "Resupply"), ("Dagath", "Wyrd Scythes"),
+    ("Dante", "Light Verse"), ("Ember", "Fire Blast"), ("Equinox", "Rest & Rage"),
+    ("Excalibur", "Radial Javelin"), ("Frost", "Ice Wave"), ("Gara", "Spectrorage"),
+    ("Garuda", "Blood Altar"), ("Gauss", "Thermal Sunder"), ("Grendel", "Nourish"),
+    ("Gyre", "Coil Horizon"), ("Harrow", "Condemn"), ("Hildryn", "Pillage"),
+    ("Hydroid", "Tempest Barrage"), ("Inaros", "Desiccation"), ("Ivara", "Quiver"),
+    ("Jade", "Ophanim Eyes"), ("Khora", "Ensnare"), ("Koumei", "Omamori"),
+    ("Kullervo", "Wrathful Advance"), ("Lavos", "Vial Rush"), ("Limbo", "Banish"),
+    ("Loki", "Decoy"), ("Mag", "Pull"), ("Mesa", "Shooting Gallery"),
+    ("Mirage", "Eclipse"), ("Nekros", "Terrify"), ("Nezha", "Fire Walker"),
+    ("Nidus", "Larva"), ("Nova", "Null Star"), ("Nyx", "Mind Control"),
+    ("Oberon", "Smite"), ("Octavia", "Resonator"), ("Protea", "Dispensary"),
+    ("Qorvex", "Containment Wall"), ("Revenant", "Reave"), ("Rhino", "Roar"),
+    ("Saryn", "Molt"), ("Sevagoth", "Gloom"), ("Styanax", "Tharros Strike"),
+    ("Titania", "Spellbind"), ("Trinity", "Well of Life"), ("Valkyr", "Warcry"),
+    ("Vauban", "Tesla Nervos"), ("Volt", "Shock"), ("Voruna", "Ulfrun's Endurance"),
+    ("Wisp", "Breach Surge"), ("Wukong", "Defy"), ("Xaku", "Xata's Whisper"),
+    ("Yareli", "Aquablades"), ("Zephyr", "Airburst"),
+];
+
+/// Ability `warframe` grants when subsumed, by warframe name
+pub fn subsumed_ability(warframe: &str) -> Option<&'static str> {
+    SUBSUMED_ABILITIES
+        .iter()
+        .find(|(frame, _)| *frame == warframe)
+        .map(|(_, ability)| *ability)
+}
+
+pub fn helminth_rank(xp: i64) -> i64 {
+    HELMINTH_RANK_XP
+        .iter()
+        .take_while(|threshold| xp >= **threshold)
+        .count() as i64
+}
+
+/// Stored amount of one Helminth resource.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResourceLevel {
+    /// e.g. "Calx"
+    pub name: String,
+    pub count: i64,
+    /// Item types fed recently, they give less until they cool down
+    pub recently_converted: Vec<String>,
+}
+
+/// An ability that can be infused.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InfusableAbility {
+    pub name: String,
+    pub unique_name: Option<String>,
+    pub description: Option<String>,
+    /// Warframe it was subsumed from, `None` for the Helminth's own
+    pub source: Option<String>,
+}
+
+/// A warframe that was or can be subsumed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HelminthFrame {
+    pub item_type: String,
+    pub name: Option<String>,
+    pub ability: Option<InfusableAbility>,
+}
+
+impl HelminthFrame {
+    fn new(item_type: &str, catalog: &Catalog) -> HelminthFrame {
+        let warframe = match catalog.get(item_type) {
+            Some(CatalogItem::Warframe(warframe)) => Some(warframe),
+            _ => None,
+        };
+        let name = warframe.map(|warframe| warframe.name.clone());
+
+        let ability = warframe.and_then(|warframe| {
+            let Some(ability) = subsumed_ability(&warframe.name) else {
+                log::warn!("No subsumable ability known for {}", warframe.name);
+                return None;
+            };
+            let data = warframe.abilities.iter().find(|data| data.name == ability);
+            if data.is_none() {
+                log::warn!(
+                    "{} has no ability named {} in the catalog",
+                    warframe.name,
+                    ability
+                );
+            }
+            Some(InfusableAbility {
+                name: ability.to_string(),
+                unique_name: data.map(|data| data.unique_name.clone()),
+                description: data.map(|data| data.description.clone()),
+                source: Some(warframe.name.clone()),
+            })
+        });
+
+        HelminthFrame {
+            item_type: item_type.to_string(),
+            name,
+            ability,
+        }
+    }
+}
+
+/// Helminth state joined with the catalog.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HelminthReport {
+    pub name: Option<String>,
+    pub xp: i64,
+    pub rank: i64,
+    pub resources: Vec<ResourceLevel>,
+    pub subsumed: Vec<HelminthFrame>,
+    /// Helminth abilities followed by the subsumed ones
+    pub abilities: Vec<InfusableAbility>,
+    /// Owned warframes that can still be fed to the Helminth
+    pub candidates: Vec<HelminthFrame>,
+}
+
+impl HelminthReport {
+    /// `None` if the Helminth has not been unlocked yet
+    pub fn build(inventory: &Inventory, catalog: &Catalog) -> Option<HelminthReport> {
+        let foundry: &InfestedFoundry = inventory.infested_foundry.as_ref()?;
+        let xp = foundry.xp.unwrap_or(0);
+
+        let resources = foundry
+            .resources
+            .iter()
+            .map(|resource| ResourceLevel {
+                name: resource.name().to_string(),
+                count: resource.count,
+                recently_converted: resource
+                    .recently_converted_resources
+                    .iter()
+                    .map(|converted| converted.item_type.clone())
+                    .collect(),
+            })
+            .collect();
+
+        let subsumed: Vec<HelminthFrame> = foundry
+            .consumed_suits
+            .iter()
+            .map(|suit| HelminthFrame::new(&suit.item_type, catalog))
+            .collect();
+
+        let mut abilities: Vec<InfusableAbility> = HELMINTH_ABILITIES
+            .iter()
+            .map(|name| InfusableAbility {
+                name: name.to_string(),
+                unique_name: None,
+                description: None,
+                source: None,
+            })
+            .collect();
+        abilities.extend(subsumed.iter().filter_map(|frame| frame.ability.clone()));
+
+        // Primes and Umbra variants cannot be subsumed, only the base warframe
+        let consumed: HashSet<&str> = foundry
+            .consumed_suits
+            .iter()
+            .map(|suit| suit.item_type.as_str())
+            .collect();
+        let mut candidates: Vec<HelminthFrame> = inventory
+            .suits
+            .iter()
+            .map(|suit| suit.item_type.as_str())
+            .filter(|item_type| !consumed.contains(item_type))
+            .filter(|item_type| !item_type.ends_with("Prime") && !item_type.ends_with("Umbra"))
+            .filter(|item_type| !catalog.get(item_type).is_some_and(CatalogItem::is_prime))
+            .collect::<HashSet<&str>>()
+            .into_iter()
+            .map(|item_type| HelminthFrame::new(item_type, catalog))
+            .collect();
+        candidates.sort_by(|a, b| a.item_type.cmp(&b.item_type));
+
+        Some(HelminthReport {
+            name: foundry.name.clone(),
+            xp,
+            rank: helminth_rank(xp),
+            resources,
+            subsumed,
+            abilities,
+            candidates,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::tests::load_test_inventory;
+    use crate::itemdata::warframe::{Ability, Warframe};
+
+    fn warframe(name: &str, unique_name: &str, abilities: &[&str]) -> CatalogItem {
+        CatalogItem::Warframe(Warframe {
+            name: name.to_string(),
+            unique_name: unique_name.to_string(),
+            abilities: abilities
+                .iter()
+                .map(|ability| Ability {
+                    name: ability.to_string(),
+                    unique_name: format!("/Lotus/Powersuits/Abilities/{}", ability),
+                    description: format!("{} description", ability),
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    #[cfg(feature = "test_with_wf_items")]
+    fn test_subsumed_abilities_match_catalog() {
+        let f = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/warframe-items-data/json/Warframes.json"
+        );
+        let raw = std::fs::read_to_string(f).unwrap();
+        let warframes: crate::itemdata::warframe::Root = serde_json::from_str(&raw).unwrap();
+
+        // Every subsumable warframe has an entry whose ability it actually has
+        let base: Vec<&Warframe> = warframes
+            .iter()
+            .filter(|warframe| warframe.product_category.as_deref() == Some("Suits"))
+            .filter(|warframe| !warframe.is_prime && !warframe.name.contains("Umbra"))
+            .collect();
+        for warframe in &base {
+            let ability = subsumed_ability(&warframe.name)
+                .unwrap_or_else(|| panic!("No subsumed ability for {}", warframe.name));
+            assert!(
+                warframe.abilities.iter().any(|data| data.name == ability),
+                "{} has no ability named {}",
+                warframe.name,
+                ability
+            );
+        }
+        for (frame, _) in SUBSUMED_ABILITIES {
+            assert!(
+                base.iter().any(|warframe| warframe.name == *frame),
+                "{} is not a warframe in the catalog",
+                frame
+            );
+        }
+    }
+
+    #[test]
+    fn test_helminth_rank() {
+        assert_eq!(
+            HELMINTH_RANK_XP,
+            [
+                225_000, 562_500, 1_012_500, 1_575_000, 2_250_000, 3_037_500, 3_937_500, 4_950_000
+            ]
+        );
+        assert_eq!(helminth_rank(0), 0);
+        assert_eq!(helminth_rank(225_000), 1);
+        assert_eq!(helminth_rank(1_000_000), 2);
+        assert_eq!(helminth_rank(15_187_500), 8);
+    }
+
+    #[test]
+    fn test_helminth_report() {
+        let catalog: Catalog = [
+            warframe(
+                "Volt",
+                "/Lotus/Powersuits/Volt/Volt",
+                &["Shock", "Speed", "Electric Shield", "Discharge"],
+            ),
+            warframe(
+                "Ash",
+                "/Lotus/Powersuits/Ninja/Ninja",
+                &["Shuriken", "Smoke Screen", "Teleport", "Blade Storm"],
+            ),
+        ]
+        .into_iter()
+        .collect();
+
+        let report = HelminthReport::build(&load_test_inventory(), &catalog).unwrap();
+        assert_eq!(report.name.as_deref(), Some("Inferix"));
+        assert_eq!(report.rank, 8);
+        assert_eq!(report.resources.len(), 6);
+        assert_eq!(report.resources[2].name, "Calx");
+        assert_eq!(report.resources[2].count, 930);
+        assert_eq!(report.resources[2].recently_converted.len(), 2);
+        assert_eq!(report.subsumed.len(), 22);
+
+        let shock = report
+            .abilities
+            .iter()
+            .find(|ability| ability.source.as_deref() == Some("Volt"))
+            .unwrap();
+        assert_eq!(shock.name, "Shock");
+        assert_eq!(shock.description.as_deref(), Some("Shock description"));
+
+        // Wisp is consumed already, primes and Excalibur Umbra never qualify
+        let candidates: Vec<&str> = report
+            .candidates
+            .iter()
+            .map(|frame| frame.item_type.as_str())
+            .collect();
+        assert!(candidates.contains(&"/Lotus/Powersuits/Ninja/Ninja"));
+        assert!(!candidates.contains(&"/Lotus/Powersuits/Wisp/Wisp"));
+        assert!(
+            !candidates
+                .iter()
+                .any(|item_type| item_type.ends_with("Prime"))
+        );
+        assert!(!candidates.contains(&"/Lotus/Powersuits/Excalibur/ExcaliburUmbra"));
+
+        let ash = report
+            .candidates
+            .iter()
+            .find(|frame| frame.name.as_deref() == Some("Ash"))
+            .unwrap();
+        assert_eq!(ash.ability.as_ref().unwrap().name, "Shuriken");
+    }
+}
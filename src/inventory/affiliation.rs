@@ -0,0 +1,273 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Syndicate tag as used by `Affiliations` and `SupportedSyndicate`.
+/// Tags this enum does not know are kept as `Unknown` instead of failing.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum Syndicate {
+    SteelMeridian,
+    Arbiters,
+    CephalonSuda,
+    Perrin,
+    RedVeil,
+    NewLoka,
+    Conclave,
+    Simaris,
+    Ostron,
+    Quills,
+    SolarisUnited,
+    Ventkids,
+    VoxSolaris,
+    Entrati,
+    Necraloid,
+    Holdfasts,
+    Kahl,
+    Cavia,
+    Hex,
+    /// Operation event syndicate
+    Event,
+    /// One Nightwave series, e.g. `RadioLegion3Syndicate`
+    Nightwave(String),
+    Unknown(String),
+}
+
+/// Known tags, Nightwave series are matched by prefix
+const SYNDICATE_TAGS: &[(&str, Syndicate)] = &[
+    ("SteelMeridianSyndicate", Syndicate::SteelMeridian),
+    ("ArbitersSyndicate", Syndicate::Arbiters),
+    ("CephalonSudaSyndicate", Syndicate::CephalonSuda),
+    ("PerrinSyndicate", Syndicate::Perrin),
+    ("RedVeilSyndicate", Syndicate::RedVeil),
+    ("NewLokaSyndicate", Syndicate::NewLoka),
+    ("ConclaveSyndicate", Syndicate::Conclave),
+    ("LibrarySyndicate", Syndicate::Simaris),
+    ("CetusSyndicate", Syndicate::Ostron),
+    ("QuillsSyndicate", Syndicate::Quills),
+    ("SolarisSyndicate", Syndicate::SolarisUnited),
+    ("VentKidsSyndicate", Syndicate::Ventkids),
+    ("VoxSyndicate", Syndicate::VoxSolaris),
+    ("EntratiSyndicate", Syndicate::Entrati),
+    ("NecraloidSyndicate", Syndicate::Necraloid),
+    ("ZarimanSyndicate", Syndicate::Holdfasts),
+    ("KahlSyndicate", Syndicate::Kahl),
+    ("EntratiLabSyndicate", Syndicate::Cavia),
+    ("HexSyndicate", Syndicate::Hex),
+    ("EventSyndicate", Syndicate::Event),
+];
+
+impl Syndicate {
+    pub fn from_tag(tag: &str) -> Syndicate {
+        if let Some((_, syndicate)) = SYNDICATE_TAGS.iter().find(|(known, _)| *known == tag) {
+            return syndicate.clone();
+        }
+        if tag.starts_with("RadioLegion") {
+            return Syndicate::Nightwave(tag.to_string());
+        }
+        Syndicate::Unknown(tag.to_string())
+    }
+
+    pub fn tag(&self) -> &str {
+        match self {
+            Syndicate::Nightwave(tag) | Syndicate::Unknown(tag) => tag,
+            known => SYNDICATE_TAGS
+                .iter()
+                .find(|(_, syndicate)| syndicate == known)
+                .map(|(tag, _)| *tag)
+                .unwrap_or_default(),
+        }
+    }
+
+    /// In-game name, the tag for unknown syndicates
+    pub fn name(&self) -> &str {
+        match self {
+            Syndicate::SteelMeridian => "Steel Meridian",
+            Syndicate::Arbiters => "Arbiters of Hexis",
+            Syndicate::CephalonSuda => "Cephalon Suda",
+            Syndicate::Perrin => "The Perrin Sequence",
+            Syndicate::RedVeil => "Red Veil",
+            Syndicate::NewLoka => "New Loka",
+            Syndicate::Conclave => "Conclave",
+            Syndicate::Simaris => "Cephalon Simaris",
+            Syndicate::Ostron => "Ostron",
+            Syndicate::Quills => "The Quills",
+            Syndicate::SolarisUnited => "Solaris United",
+            Syndicate::Ventkids => "Ventkids",
+            Syndicate::VoxSolaris => "Vox Solaris",
+            Syndicate::Entrati => "Entrati",
+            Syndicate::Necraloid => "Necraloid",
+            Syndicate::Holdfasts => "The Holdfasts",
+            Syndicate::Kahl => "Kahl's Garrison",
+            Syndicate::Cavia => "Cavia",
+            Syndicate::Hex => "The Hex",
+            Syndicate::Event => "Operation",
+            Syndicate::Nightwave(_) => "Nightwave",
+            Syndicate::Unknown(tag) => tag,
+        }
+    }
+
+    /// The six relay syndicates that share one daily cap and pledge
+    pub fn is_faction(&self) -> bool {
+        matches!(
+            self,
+            Syndicate::SteelMeridian
+                | Syndicate::Arbiters
+                | Syndicate::CephalonSuda
+                | Syndicate::Perrin
+                | Syndicate::RedVeil
+                | Syndicate::NewLoka
+        )
+    }
+}
+
+impl From<String> for Syndicate {
+    fn from(tag: String) -> Syndicate {
+        Syndicate::from_tag(&tag)
+    }
+}
+
+impl From<Syndicate> for String {
+    fn from(syndicate: Syndicate) -> String {
+        syndicate.tag().to_string()
+    }
+}
+
+/// Standing with one syndicate.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Affiliation {
+    #[serde(rename = "Tag")]
+    pub tag: Syndicate,
+
+    /// Total standing, including what was spent ranking up
+    #[serde(rename = "Standing", default)]
+    pub standing: i64,
+
+    /// Rank, negative for enemy ranks of relay syndicates
+    #[serde(rename = "Title")]
+    pub title: Option<i64>,
+
+    /// Ranks whose free rank-up reward has been earned
+    #[serde(rename = "FreeFavorsEarned", default)]
+    pub free_favors_earned: Vec<i64>,
+
+    #[serde(rename = "FreeFavorsUsed", default)]
+    pub free_favors_used: Vec<i64>,
+
+    #[serde(rename = "Initiated")]
+    pub initiated: Option<bool>,
+
+    #[serde(flatten)]
+    pub other: Option<Value>,
+}
+
+impl Affiliation {
+    pub fn rank(&self) -> i64 {
+        self.title.unwrap_or(0)
+    }
+
+    /// Earned rank-up rewards that have not been claimed
+    pub fn unused_favors(&self) -> impl Iterator<Item = i64> + '_ {
+        self.free_favors_earned
+            .iter()
+            .copied()
+            .filter(|favor| !self.free_favors_used.contains(favor))
+    }
+}
+
+/// Standing still available today per syndicate group, `DailyAffiliation*` in the inventory.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DailyAffiliations {
+    /// Shared by the six relay syndicates
+    #[serde(rename = "DailyAffiliation")]
+    pub faction: Option<i64>,
+    #[serde(rename = "DailyAffiliationPvp")]
+    pub pvp: Option<i64>,
+    #[serde(rename = "DailyAffiliationLibrary")]
+    pub library: Option<i64>,
+    #[serde(rename = "DailyAffiliationCetus")]
+    pub cetus: Option<i64>,
+    #[serde(rename = "DailyAffiliationQuills")]
+    pub quills: Option<i64>,
+    #[serde(rename = "DailyAffiliationSolaris")]
+    pub solaris: Option<i64>,
+    #[serde(rename = "DailyAffiliationVentkids")]
+    pub ventkids: Option<i64>,
+    #[serde(rename = "DailyAffiliationVox")]
+    pub vox: Option<i64>,
+    #[serde(rename = "DailyAffiliationEntrati")]
+    pub entrati: Option<i64>,
+    #[serde(rename = "DailyAffiliationNecraloid")]
+    pub necraloid: Option<i64>,
+    #[serde(rename = "DailyAffiliationZariman")]
+    pub zariman: Option<i64>,
+    #[serde(rename = "DailyAffiliationKahl")]
+    pub kahl: Option<i64>,
+    #[serde(rename = "DailyAffiliationCavia")]
+    pub cavia: Option<i64>,
+    #[serde(rename = "DailyAffiliationHex")]
+    pub hex: Option<i64>,
+}
+
+impl DailyAffiliations {
+    /// Standing left today for a syndicate, `None` if it has no daily cap
+    pub fn remaining(&self, syndicate: &Syndicate) -> Option<i64> {
+        match syndicate {
+            s if s.is_faction() => self.faction,
+            Syndicate::Conclave => self.pvp,
+            Syndicate::Simaris => self.library,
+            Syndicate::Ostron => self.cetus,
+            Syndicate::Quills => self.quills,
+            Syndicate::SolarisUnited => self.solaris,
+            Syndicate::Ventkids => self.ventkids,
+            Syndicate::VoxSolaris => self.vox,
+            Syndicate::Entrati => self.entrati,
+            Syndicate::Necraloid => self.necraloid,
+            Syndicate::Holdfasts => self.zariman,
+            Syndicate::Kahl => self.kahl,
+            Syndicate::Cavia => self.cavia,
+            Syndicate::Hex => self.hex,
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{from_str, to_string};
+
+    #[test]
+    fn test_syndicate_tags() {
+        let tags: Vec<Syndicate> =
+            from_str(r#"["CetusSyndicate", "RadioLegion3Syndicate", "SomeNewSyndicate"]"#).unwrap();
+        assert_eq!(
+            tags,
+            vec![
+                Syndicate::Ostron,
+                Syndicate::Nightwave("RadioLegion3Syndicate".to_string()),
+                Syndicate::Unknown("SomeNewSyndicate".to_string()),
+            ]
+        );
+        assert_eq!(
+            to_string(&tags).unwrap(),
+            r#"["CetusSyndicate","RadioLegion3Syndicate","SomeNewSyndicate"]"#
+        );
+        assert_eq!(Syndicate::Holdfasts.tag(), "ZarimanSyndicate");
+    }
+
+    #[test]
+    fn test_deserialize_affiliation() {
+        let js = r#"{
+  "Initiated": true,
+  "Standing": 262670,
+  "Title": 5,
+  "FreeFavorsEarned": [1, 2, 3, -2, -1, 4, 5],
+  "FreeFavorsUsed": [1, 2, 3, -2, -1, 4],
+  "Tag": "CephalonSudaSyndicate"
+}"#;
+        let affiliation: Affiliation = from_str(js).unwrap();
+        assert_eq!(affiliation.tag, Syndicate::CephalonSuda);
+        assert_eq!(affiliation.rank(), 5);
+        assert_eq!(affiliation.unused_favors().collect::<Vec<_>>(), vec![5]);
+    }
+}
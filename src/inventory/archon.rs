@@ -0,0 +1,226 @@
+use serde::{Deserialize, Serialize};
+
+use crate::inventory::suit::ArchonCrystalUpgrade;
+
+/// Number of shard sockets on a warframe
+pub const SHARD_SOCKETS: usize = 5;
+
+/// Tauforged shards give this much more than regular ones
+pub const TAUFORGED_MULTIPLIER: f64 = 1.5;
+
+/// Archon shard color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum ShardColor {
+    Crimson,
+    Amber,
+    Azure,
+    Emerald,
+    Violet,
+    Topaz,
+}
+
+impl ShardColor {
+    pub const ALL: [ShardColor; 6] = [
+        ShardColor::Crimson,
+        ShardColor::Amber,
+        ShardColor::Azure,
+        ShardColor::Emerald,
+        ShardColor::Violet,
+        ShardColor::Topaz,
+    ];
+
+    /// Socketed shard color, e.g. `ACC_BLUE` or `ACC_BLUE_MYTHIC`
+    pub fn from_code(code: &str) -> Option<ShardColor> {
+        match code.strip_suffix("_MYTHIC").unwrap_or(code) {
+            "ACC_RED" => Some(ShardColor::Crimson),
+            "ACC_YELLOW" => Some(ShardColor::Amber),
+            "ACC_BLUE" => Some(ShardColor::Azure),
+            "ACC_GREEN" => Some(ShardColor::Emerald),
+            "ACC_PURPLE" => Some(ShardColor::Violet),
+            "ACC_ORANGE" => Some(ShardColor::Topaz),
+            _ => None,
+        }
+    }
+
+    /// Loose shard in `MiscItems`, e.g. `/Lotus/Types/Gameplay/NarmerSorties/ArchonCrystalBorealMythic`.
+    /// Returns the color and whether it is tauforged.
+    pub fn from_item_type(item_type: &str) -> Option<(ShardColor, bool)> {
+        let name = item_type.strip_prefix("/Lotus/Types/Gameplay/NarmerSorties/ArchonCrystal")?;
+        let (name, tauforged) = match name.strip_suffix("Mythic") {
+            Some(name) => (name, true),
+            None => (name, false),
+        };
+        let color = match name {
+            "Amar" => ShardColor::Crimson,
+            "Nira" => ShardColor::Amber,
+            "Boreal" => ShardColor::Azure,
+            "Green" => ShardColor::Emerald,
+            "Violet" => ShardColor::Violet,
+            "Orange" => ShardColor::Topaz,
+            _ => return None,
+        };
+        Some((color, tauforged))
+    }
+}
+
+impl std::fmt::Display for ShardColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Effect of a socketed shard at its regular strength.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct ShardBonus {
+    /// e.g. "Casting Speed"
+    pub stat: &'static str,
+    pub value: f64,
+    pub percent: bool,
+}
+
+/// Upgrade name after `ArchonCrystalUpgrade`, without the `Mythic` suffix
+#[rustfmt::skip]
+const SHARD_BONUSES: &[(&str, &str, f64, bool)] = &[
+    ("WarframeAbilityStrength", "Ability Strength", 10.0, true),
+    ("WarframeAbilityDuration", "Ability Duration", 10.0, true),
+    ("MeleeCritDamage", "Melee Critical Damage", 25.0, true),
+    ("PrimaryStatusChance", "Primary Status Chance", 25.0, true),
+    ("SecondaryCritChance", "Secondary Critical Chance", 25.0, true),
+    ("WarframeCastingSpeed", "Casting Speed", 25.0, true),
+    ("WarframeStartingEnergy", "Energy on Spawn", 30.0, true),
+    ("WarframeGlobeEffectEnergy", "Energy Orb Effectiveness", 50.0, true),
+    ("WarframeGlobeEffectHealth", "Health Orb Effectiveness", 100.0, true),
+    ("WarframeParkourVelocity", "Parkour Velocity", 15.0, true),
+    ("WarframeHealthMax", "Health", 150.0, false),
+    ("WarframeShieldMax", "Shield Capacity", 150.0, false),
+    ("WarframeEnergyMax", "Energy Max", 50.0, false),
+    ("WarframeArmourMax", "Armor", 150.0, false),
+    ("WarframeHealthRegen", "Health Regen/s", 5.0, false),
+    ("WarframeToxinDamage", "Toxin Status Damage", 30.0, true),
+    ("WarframeToxinHeal", "Health on Toxin Status Tick", 2.0, false),
+    ("WarframeCorrosiveDamageBoost", "Ability Damage vs Corroded", 10.0, true),
+    ("WarframeCorrosiveStack", "Corrosive Status Stacks", 2.0, false),
+    ("WarframeElectricDamageBoost", "Ability Damage vs Electrified", 10.0, true),
+    ("WarframeElectricDamage", "Primary Electricity Damage", 10.0, true),
+    ("WarframeCritDamageBoost", "Critical Damage above 500 Energy", 25.0, true),
+];
+
+/// A shard socketed in a warframe.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArchonShard {
+    pub color: ShardColor,
+    pub tauforged: bool,
+    /// e.g. `/Lotus/Upgrades/Invigorations/ArchonCrystalUpgrades/ArchonCrystalUpgradeWarframeArmourMax`
+    pub upgrade_type: String,
+}
+
+impl ArchonShard {
+    /// `None` when the color or upgrade is missing or unknown
+    pub fn from_upgrade(upgrade: &ArchonCrystalUpgrade) -> Option<ArchonShard> {
+        let code = upgrade.color.as_deref()?;
+        let upgrade_type = upgrade.upgrade_type.clone()?;
+        Some(ArchonShard {
+            color: ShardColor::from_code(code)?,
+            tauforged: code.ends_with("_MYTHIC") || upgrade_type.ends_with("Mythic"),
+            upgrade_type,
+        })
+    }
+
+    /// Upgrade name without path, prefix and tauforged suffix, e.g. `WarframeArmourMax`
+    pub fn upgrade_name(&self) -> &str {
+        let name = self
+            .upgrade_type
+            .rsplit('/')
+            .next()
+            .unwrap_or(&self.upgrade_type);
+        let name = name.strip_prefix("ArchonCrystalUpgrade").unwrap_or(name);
+        name.strip_suffix("Mythic").unwrap_or(name)
+    }
+
+    /// Regular strength bonus, `None` for upgrades not in the table
+    pub fn bonus(&self) -> Option<ShardBonus> {
+        let name = self.upgrade_name();
+        SHARD_BONUSES
+            .iter()
+            .find(|(upgrade, ..)| *upgrade == name)
+            .map(|&(_, stat, value, percent)| ShardBonus {
+                stat,
+                value,
+                percent,
+            })
+    }
+
+    /// Bonus value with the tauforged multiplier applied
+    pub fn value(&self) -> Option<f64> {
+        let value = self.bonus()?.value;
+        Some(if self.tauforged {
+            value * TAUFORGED_MULTIPLIER
+        } else {
+            value
+        })
+    }
+
+    /// e.g. "+225 Armor" or "+37.5% Casting Speed", the upgrade name if it is not known
+    pub fn describe(&self) -> String {
+        match (self.bonus(), self.value()) {
+            (Some(bonus), Some(value)) => {
+                let unit = if bonus.percent { "%" } else { "" };
+                format!("+{}{} {}", value, unit, bonus.stat)
+            }
+            _ => self.upgrade_name().to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shard(color: &str, upgrade: &str) -> ArchonShard {
+        ArchonShard::from_upgrade(&ArchonCrystalUpgrade {
+            color: Some(color.to_string()),
+            upgrade_type: Some(format!(
+                "/Lotus/Upgrades/Invigorations/ArchonCrystalUpgrades/ArchonCrystalUpgrade{upgrade}"
+            )),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_shard_bonus() {
+        let azure = shard("ACC_BLUE_MYTHIC", "WarframeArmourMaxMythic");
+        assert_eq!(azure.color, ShardColor::Azure);
+        assert!(azure.tauforged);
+        assert_eq!(azure.upgrade_name(), "WarframeArmourMax");
+        assert_eq!(azure.describe(), "+225 Armor");
+
+        let amber = shard("ACC_YELLOW_MYTHIC", "WarframeCastingSpeedMythic");
+        assert_eq!(amber.describe(), "+37.5% Casting Speed");
+
+        let crimson = shard("ACC_RED", "MeleeCritDamage");
+        assert!(!crimson.tauforged);
+        assert_eq!(crimson.describe(), "+25% Melee Critical Damage");
+
+        let unknown = shard("ACC_ORANGE", "WarframeSomethingNew");
+        assert_eq!(unknown.bonus(), None);
+        assert_eq!(unknown.describe(), "WarframeSomethingNew");
+    }
+
+    #[test]
+    fn test_loose_shard() {
+        assert_eq!(
+            ShardColor::from_item_type(
+                "/Lotus/Types/Gameplay/NarmerSorties/ArchonCrystalBorealMythic"
+            ),
+            Some((ShardColor::Azure, true))
+        );
+        assert_eq!(
+            ShardColor::from_item_type("/Lotus/Types/Gameplay/NarmerSorties/ArchonCrystalOrange"),
+            Some((ShardColor::Topaz, false))
+        );
+        assert_eq!(
+            ShardColor::from_item_type("/Lotus/Types/Items/MiscItems/OrokinCell"),
+            None
+        );
+    }
+}
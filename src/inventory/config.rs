@@ -0,0 +1,144 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// One loadout slot of an equipment item: mods, cosmetics and colors.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Config {
+    #[serde(rename = "Name")]
+    pub name: Option<String>,
+
+    /// Cosmetic `ItemId`s or skin paths, empty strings for unused slots
+    #[serde(rename = "Skins", default)]
+    pub skins: Vec<String>,
+
+    /// Equipped mods, see `Config::slots`
+    #[serde(rename = "Upgrades", default)]
+    pub upgrades: Vec<String>,
+
+    #[serde(rename = "pricol")]
+    pub primary_colors: Option<Colors>,
+
+    #[serde(rename = "sigcol")]
+    pub sigil_colors: Option<Colors>,
+
+    #[serde(rename = "attcol")]
+    pub attachment_colors: Option<Colors>,
+
+    #[serde(rename = "syancol")]
+    pub syandana_colors: Option<Colors>,
+
+    #[serde(rename = "eyecol")]
+    pub eye_colors: Option<Colors>,
+
+    #[serde(rename = "facial")]
+    pub facial_colors: Option<Colors>,
+
+    #[serde(rename = "AbilityOverride")]
+    pub ability_override: Option<AbilityOverride>,
+
+    #[serde(flatten)]
+    pub other: Option<Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ConfigWrapper {
+    Config(Box<Config>),
+    Array(Vec<Value>), // unused configs are sometimes stored as empty arrays
+}
+
+impl ConfigWrapper {
+    pub fn config(&self) -> Option<&Config> {
+        match self {
+            ConfigWrapper::Config(config) => Some(config),
+            ConfigWrapper::Array(_) => None,
+        }
+    }
+}
+
+/// Palette entries as packed ARGB values.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Colors {
+    pub t0: Option<i64>,
+    pub t1: Option<i64>,
+    pub t2: Option<i64>,
+    pub t3: Option<i64>,
+    pub m0: Option<i64>,
+    pub m1: Option<i64>,
+    pub en: Option<i64>,
+    pub e1: Option<i64>,
+}
+
+/// Helminth ability replacing one of the frame's own.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AbilityOverride {
+    #[serde(rename = "Ability")]
+    pub ability: String,
+
+    #[serde(rename = "Index")]
+    pub index: i64,
+}
+
+/// What a `Config::upgrades` entry refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigSlot<'a> {
+    Empty,
+    /// `ItemId` of an entry in `Upgrades`
+    ItemId(&'a str),
+    /// Unranked mod path, matched against `RawUpgrades`
+    Path(&'a str),
+}
+
+impl Config {
+    pub fn slots(&self) -> impl Iterator<Item = ConfigSlot<'_>> {
+        self.upgrades.iter().map(|upgrade| {
+            if upgrade.is_empty() {
+                ConfigSlot::Empty
+            } else if upgrade.starts_with('/') {
+                ConfigSlot::Path(upgrade)
+            } else {
+                ConfigSlot::ItemId(upgrade)
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::from_str;
+
+    #[test]
+    fn test_deserialize_config() {
+        let js = r#"
+{
+    "Skins": ["5df0c07a3f8d4a16212f205f", "", "/Lotus/Upgrades/Skins/Trinity/TrinityPrimeSkin"],
+    "pricol": { "t0": -14934241, "en": -11289495 },
+    "Name": "spread out",
+    "AbilityOverride": { "Ability": "/Lotus/Powersuits/PowersuitAbilities/WraithGloomAbility", "Index": 3 },
+    "Upgrades": ["62b8895f177cd730342c7dda", "", "/Lotus/Upgrades/Mods/Sets/Nira/NiraExilusMod"]
+}
+"#;
+        let config: Config = from_str(js).unwrap();
+        assert_eq!(config.name.as_deref(), Some("spread out"));
+        assert_eq!(config.skins.len(), 3);
+        assert_eq!(config.primary_colors.as_ref().unwrap().t0, Some(-14934241));
+        assert_eq!(config.ability_override.as_ref().unwrap().index, 3);
+
+        let slots: Vec<ConfigSlot> = config.slots().collect();
+        assert_eq!(
+            slots,
+            vec![
+                ConfigSlot::ItemId("62b8895f177cd730342c7dda"),
+                ConfigSlot::Empty,
+                ConfigSlot::Path("/Lotus/Upgrades/Mods/Sets/Nira/NiraExilusMod"),
+            ]
+        );
+
+        let configs: Vec<ConfigWrapper> = from_str(r#"[{"Upgrades": [""]}, [], {}]"#).unwrap();
+        assert_eq!(configs.len(), 3);
+        assert!(configs[0].config().is_some());
+        assert!(configs[1].config().is_none());
+        assert!(configs[2].config().is_some());
+    }
+}
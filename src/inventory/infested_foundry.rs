@@ -0,0 +1,134 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::{TimestampSeconds, serde_as};
+
+use crate::inventory::config::Colors;
+
+/// The Helminth chair in the Infested room of the orbiter.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InfestedFoundry {
+    #[serde(rename = "Name")]
+    pub name: Option<String>,
+
+    /// Bile, Biotics, Calx, Oxides, Pheromones and Synthetics
+    #[serde(rename = "Resources", default)]
+    pub resources: Vec<HelminthResource>,
+
+    #[serde(rename = "Slots")]
+    pub slots: Option<i64>,
+
+    #[serde(rename = "XP")]
+    pub xp: Option<i64>,
+
+    /// Warframes fed to the Helminth
+    #[serde(rename = "ConsumedSuits", default)]
+    pub consumed_suits: Vec<ConsumedSuit>,
+
+    #[serde(rename = "InvigorationIndex")]
+    pub invigoration_index: Option<i64>,
+
+    /// Warframes offered for invigoration this week
+    #[serde(rename = "InvigorationSuitOfferings", default)]
+    pub invigoration_suit_offerings: Vec<String>,
+
+    #[serde(rename = "InvigorationsApplied")]
+    pub invigorations_applied: Option<i64>,
+
+    #[serde(flatten)]
+    pub other: Option<Value>,
+}
+
+/// Stored amount of one Helminth resource.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HelminthResource {
+    /// e.g. `/Lotus/Types/Items/InfestedFoundry/HelminthCalx`
+    #[serde(rename = "ItemType")]
+    pub item_type: String,
+
+    #[serde(rename = "Count")]
+    pub count: i64,
+
+    /// Items fed recently, these give less until they cool down
+    #[serde(rename = "RecentlyConvertedResources", default)]
+    pub recently_converted_resources: Vec<ConvertedResource>,
+
+    #[serde(flatten)]
+    pub other: Option<Value>,
+}
+
+impl HelminthResource {
+    /// e.g. "Calx"
+    pub fn name(&self) -> &str {
+        let name = self.item_type.rsplit('/').next().unwrap_or(&self.item_type);
+        name.strip_prefix("Helminth").unwrap_or(name)
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConvertedResource {
+    #[serde(rename = "ItemType")]
+    pub item_type: String,
+
+    #[serde(rename = "Date")]
+    #[serde_as(as = "TimestampSeconds<i64>")]
+    pub date: DateTime<Utc>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConsumedSuit {
+    /// Warframe type, e.g. `/Lotus/Powersuits/Volt/Volt`
+    #[serde(rename = "s")]
+    pub item_type: String,
+
+    /// Colors of the warframe when it was consumed
+    #[serde(rename = "c")]
+    pub colors: Option<Colors>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use serde_json::from_str;
+
+    #[test]
+    fn test_deserialize_infested_foundry() {
+        let js = r#"{
+  "Name": "Inferix",
+  "Resources": [
+    { "ItemType": "/Lotus/Types/Items/InfestedFoundry/HelminthBile", "Count": 1000 },
+    {
+      "ItemType": "/Lotus/Types/Items/InfestedFoundry/HelminthCalx",
+      "Count": 930,
+      "RecentlyConvertedResources": [
+        { "ItemType": "/Lotus/Types/Items/MiscItems/Rubedo", "Date": 1768586945 }
+      ]
+    }
+  ],
+  "Slots": 100010,
+  "XP": 15187500,
+  "ConsumedSuits": [
+    { "s": "/Lotus/Powersuits/Volt/Volt" },
+    { "s": "/Lotus/Powersuits/Nezha/Nezha", "c": { "t0": -15846587, "en": -7004124 } }
+  ],
+  "InvigorationIndex": 622,
+  "InvigorationSuitOfferings": ["/Lotus/Powersuits/Dragon/DragonBaseSuit"],
+  "InvigorationsApplied": 9
+}"#;
+        let foundry: InfestedFoundry = from_str(js).unwrap();
+        assert_eq!(foundry.name.as_deref(), Some("Inferix"));
+        assert_eq!(foundry.resources[1].name(), "Calx");
+        assert_eq!(
+            foundry.resources[1].recently_converted_resources[0].date,
+            Utc.timestamp_opt(1768586945, 0).unwrap()
+        );
+        assert_eq!(foundry.consumed_suits.len(), 2);
+        assert_eq!(
+            foundry.consumed_suits[1].colors.as_ref().unwrap().t0,
+            Some(-15846587)
+        );
+        assert_eq!(foundry.invigorations_applied, Some(9));
+    }
+}
@@ -0,0 +1,87 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::{TimestampSeconds, serde_as};
+
+/// Stackable item stored as a type and a count.
+/// Used by `MiscItems`, `Consumables`, `LevelKeys`, `ShipDecorations` and similar arrays.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MiscItem {
+    #[serde(rename = "ItemType")]
+    pub item_type: String,
+
+    #[serde(rename = "ItemCount")]
+    pub item_count: i64,
+
+    #[serde(flatten)]
+    pub other: Option<Value>,
+}
+
+/// Ayatan treasure, stacked per socket state.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FusionTreasure {
+    #[serde(rename = "ItemType")]
+    pub item_type: String,
+
+    #[serde(rename = "ItemCount")]
+    pub item_count: i64,
+
+    /// Bitmask of the sockets that hold a star
+    #[serde(rename = "Sockets")]
+    pub sockets: i64,
+
+    #[serde(flatten)]
+    pub other: Option<Value>,
+}
+
+impl FusionTreasure {
+    pub fn filled_sockets(&self) -> u32 {
+        self.sockets.count_ones()
+    }
+}
+
+/// Active or expired booster.
+#[serde_as]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Booster {
+    #[serde(rename = "ItemType")]
+    pub item_type: String,
+
+    #[serde(rename = "ExpiryDate")]
+    #[serde_as(as = "TimestampSeconds<i64>")]
+    pub expiry_date: DateTime<Utc>,
+
+    #[serde(flatten)]
+    pub other: Option<Value>,
+}
+
+impl Booster {
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        self.expiry_date > now
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use serde_json::from_str;
+
+    #[test]
+    fn test_deserialize_fusion_treasure() {
+        let js = r#"{ "ItemCount": 1, "ItemType": "/Lotus/Types/Items/FusionTreasures/OroFusexF", "Sockets": 15 }"#;
+        let t: FusionTreasure = from_str(js).unwrap();
+        assert_eq!(t.item_count, 1);
+        assert_eq!(t.filled_sockets(), 4);
+    }
+
+    #[test]
+    fn test_deserialize_booster() {
+        let js =
+            r#"{ "ExpiryDate": 1768435902, "ItemType": "/Lotus/Types/Boosters/CreditBooster" }"#;
+        let b: Booster = from_str(js).unwrap();
+        assert_eq!(b.expiry_date, Utc.timestamp_opt(1768435902, 0).unwrap());
+        assert!(b.is_active(Utc.timestamp_opt(1768435901, 0).unwrap()));
+        assert!(!b.is_active(Utc.timestamp_opt(1768435902, 0).unwrap()));
+    }
+}
@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// `Tier` value once a node has been completed on the Steel Path
+pub const STEEL_PATH_TIER: i64 = 1;
+
+/// Completion record of one node.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Mission {
+    /// Node id, e.g. `SolNode27` or `EarthToVenusJunction`
+    #[serde(rename = "Tag")]
+    pub tag: String,
+
+    /// Completions on the normal star chart
+    #[serde(rename = "Completes", default)]
+    pub completes: i64,
+
+    #[serde(rename = "Tier")]
+    pub tier: Option<i64>,
+
+    #[serde(flatten)]
+    pub other: Option<Value>,
+}
+
+impl Mission {
+    pub fn completed(&self) -> bool {
+        self.completes > 0
+    }
+
+    pub fn steel_path(&self) -> bool {
+        self.tier.is_some_and(|tier| tier >= STEEL_PATH_TIER)
+    }
+
+    /// Planets a junction connects, e.g. `("Earth", "Venus")` for `EarthToVenusJunction`
+    pub fn junction(&self) -> Option<(&str, &str)> {
+        self.tag.strip_suffix("Junction")?.split_once("To")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::from_str;
+
+    #[test]
+    fn test_deserialize_missions() {
+        let js = r#"[
+  { "Completes": 45, "Tier": 1, "Tag": "SolNode27" },
+  { "Completes": 2, "Tag": "EarthToVenusJunction" }
+]"#;
+        let missions: Vec<Mission> = from_str(js).unwrap();
+        assert!(missions[0].completed());
+        assert!(missions[0].steel_path());
+        assert_eq!(missions[0].junction(), None);
+        assert!(!missions[1].steel_path());
+        assert_eq!(missions[1].junction(), Some(("Earth", "Venus")));
+    }
+}
@@ -28,14 +28,41 @@ pub mod space_melee;
 /// Blueprints
 pub mod recipe;
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub enum FractionSyndicates {
-    SteelMeridianSyndicate,
-    ArbitersSyndicate,
-    CephalonSudaSyndicate,
-    PerrinSyndicate,
-    RedVeilSyndicate,
-}
+/// Equipment loadout configurations
+pub mod config;
+
+/// Affinity history
+pub mod xp_info;
+
+/// Resources, consumables, keys and other counted items
+pub mod misc_item;
+
+/// Slot polarities and mod capacity
+pub mod polarity;
+
+/// Archon shards socketed in warframes
+pub mod archon;
+
+/// Helminth resources and subsumed warframes
+pub mod infested_foundry;
+
+/// Syndicate standing and ranks
+pub mod affiliation;
+
+/// Star chart node completions
+pub mod mission;
+
+/// Quest key chains and stage progress
+pub mod quest;
+
+pub use polarity::{Polarity, PolarityKind};
+
+/// `Features` bit set once an Orokin Reactor or Catalyst is installed
+pub const FEATURE_DOUBLE_CAPACITY: i64 = 1;
+/// `Features` bit set once the Exilus slot is unlocked
+pub const FEATURE_UTILITY_SLOT: i64 = 2;
+/// `Features` bit set once the arcane slot is unlocked
+pub const FEATURE_ARCANE_SLOT: i64 = 32;
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ObjectId {
@@ -43,15 +70,6 @@ pub struct ObjectId {
     pub oid: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Polarity {
-    #[serde(rename = "Value")]
-    pub value: Option<String>,
-
-    #[serde(flatten)]
-    pub other: Option<Value>,
-}
-
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DateWrapper {
     #[serde(rename = "$date")]
@@ -105,18 +123,155 @@ pub struct Inventory {
     #[serde(rename = "PendingRecipes")]
     pub pending_recipes: Vec<recipe::PendingRecipe>,
 
+    /// Resources, relics, built components
+    #[serde(rename = "MiscItems", default)]
+    pub misc_items: Vec<misc_item::MiscItem>,
+
+    /// Gear items (ciphers, restores, ...)
+    #[serde(rename = "Consumables", default)]
+    pub consumables: Vec<misc_item::MiscItem>,
+
+    /// Mission keys
+    #[serde(rename = "LevelKeys", default)]
+    pub level_keys: Vec<misc_item::MiscItem>,
+
+    /// Ayatan treasures
+    #[serde(rename = "FusionTreasures", default)]
+    pub fusion_treasures: Vec<misc_item::FusionTreasure>,
+
+    /// Credit, affinity and resource boosters
+    #[serde(rename = "Boosters", default)]
+    pub boosters: Vec<misc_item::Booster>,
+
+    /// Orbiter and dojo decorations
+    #[serde(rename = "ShipDecorations", default)]
+    pub ship_decorations: Vec<misc_item::MiscItem>,
+
+    /// Inbox attachments
+    #[serde(rename = "EmailItems", default)]
+    pub email_items: Vec<misc_item::MiscItem>,
+
+    /// Railjack ordnance ammo
+    #[serde(rename = "CrewShipAmmo", default)]
+    pub crew_ship_ammo: Vec<misc_item::MiscItem>,
+
+    /// Railjack salvage
+    #[serde(rename = "CrewShipRawSalvage", default)]
+    pub crew_ship_raw_salvage: Vec<misc_item::MiscItem>,
+
+    /// Affinity of every item ever levelled
+    #[serde(rename = "XPInfo", default)]
+    pub xp_info: Vec<xp_info::XpInfo>,
+
     /// Player remaining trades for the day
     #[serde(rename = "TradesRemaining")]
     pub trades_remaining: Option<i64>,
 
+    /// Helminth state
+    #[serde(rename = "InfestedFoundry")]
+    pub infested_foundry: Option<infested_foundry::InfestedFoundry>,
+
     /// Syndicate
     #[serde(rename = "SupportedSyndicate")]
-    pub supported_syndicates: Option<FractionSyndicates>,
+    pub supported_syndicates: Option<affiliation::Syndicate>,
+
+    /// Standing with every syndicate the player has met
+    #[serde(rename = "Affiliations", default)]
+    pub affiliations: Vec<affiliation::Affiliation>,
+
+    /// Nodes completed at least once, with their Steel Path tier
+    #[serde(rename = "Missions", default)]
+    pub missions: Vec<mission::Mission>,
+
+    #[serde(rename = "RegularCredits", default)]
+    pub regular_credits: i64,
+
+    /// Platinum, free and paid
+    #[serde(rename = "PremiumCredits", default)]
+    pub premium_credits: i64,
+
+    /// Mastery rank
+    #[serde(rename = "PlayerLevel")]
+    pub player_level: Option<i64>,
+
+    /// Quests received, completed or in progress
+    #[serde(rename = "QuestKeys", default)]
+    pub quest_keys: Vec<quest::QuestKey>,
+
+    /// Standing left to earn today
+    #[serde(flatten)]
+    pub daily_affiliations: affiliation::DailyAffiliations,
 
     #[serde(flatten)]
     pub other: Option<Value>,
 }
 
+impl Inventory {
+    /// Count of a resource, relic or built component in `MiscItems`, 0 when not owned
+    pub fn misc_item_count(&self, item_type: &str) -> i64 {
+        self.misc_items
+            .iter()
+            .filter(|item| item.item_type == item_type)
+            .map(|item| item.item_count)
+            .sum()
+    }
+
+    /// Count of an unbuilt blueprint in `Recipes`, 0 when not owned
+    pub fn recipe_count(&self, item_type: &str) -> i64 {
+        self.recipes
+            .iter()
+            .filter(|item| item.item_type == item_type)
+            .map(|item| item.item_count)
+            .sum()
+    }
+
+    /// Unranked copies in `RawUpgrades` plus ranked or equipped ones in `Upgrades`
+    pub fn mod_count(&self, item_type: &str) -> i64 {
+        let raw: i64 = self
+            .raw_upgrades
+            .iter()
+            .filter(|item| item.item_type == item_type)
+            .map(|item| item.item_count)
+            .sum();
+        let ranked = self
+            .upgrades
+            .iter()
+            .filter(|item| item.item_type == item_type)
+            .count() as i64;
+        raw + ranked
+    }
+
+    /// Whether a built warframe, weapon or archwing of this type is in the arsenal
+    pub fn owns_equipment(&self, item_type: &str) -> bool {
+        self.equipment_count(item_type) > 0
+    }
+
+    /// Built copies of a warframe, weapon or archwing in the arsenal
+    pub fn equipment_count(&self, item_type: &str) -> i64 {
+        let types = self
+            .suits
+            .iter()
+            .map(|item| &item.item_type)
+            .chain(self.long_guns.iter().map(|item| &item.item_type))
+            .chain(self.pistols.iter().map(|item| &item.item_type))
+            .chain(self.melee.iter().map(|item| &item.item_type))
+            .chain(self.space_suits.iter().map(|item| &item.item_type))
+            .chain(self.space_guns.iter().map(|item| &item.item_type))
+            .chain(self.space_melee.iter().map(|item| &item.item_type));
+        types.filter(|owned| *owned == item_type).count() as i64
+    }
+
+    /// Boosters that have not expired yet
+    pub fn active_boosters(
+        &self,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> impl Iterator<Item = &misc_item::Booster> {
+        self.boosters
+            .iter()
+            .filter(move |booster| booster.is_active(now))
+    }
+}
+
 #[cfg(test)]
 pub mod tests {
     use super::*;
@@ -143,5 +298,14 @@ pub mod tests {
             !inventory.raw_upgrades.is_empty(),
             "Upgrades should not be empty"
         );
+        assert_eq!(inventory.xp_info.len(), 482);
+        assert_eq!(inventory.misc_items.len(), 791);
+        assert_eq!(inventory.boosters.len(), 11);
+        assert_eq!(
+            inventory.misc_item_count("/Lotus/Types/Items/MiscItems/Rubedo"),
+            193286
+        );
+        assert_eq!(inventory.premium_credits, 749);
+        assert_eq!(inventory.player_level, Some(27));
     }
 }
@@ -0,0 +1,282 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Polarity {
+    /// Index in `Config::upgrades` the polarity applies to
+    #[serde(rename = "Slot")]
+    pub slot: Option<usize>,
+
+    #[serde(rename = "Value")]
+    pub value: Option<String>,
+
+    #[serde(flatten)]
+    pub other: Option<Value>,
+}
+
+impl Polarity {
+    pub fn kind(&self) -> Option<PolarityKind> {
+        self.value.as_deref().and_then(PolarityKind::from_code)
+    }
+}
+
+/// Mod and slot polarity, stored as `AP_*` codes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum PolarityKind {
+    Madurai,
+    Vazarin,
+    Naramon,
+    Zenurik,
+    Unairu,
+    Penjaga,
+    Umbra,
+    /// Universal Forma / Aura Forma slot, matches any polarity
+    Universal,
+}
+
+impl PolarityKind {
+    pub fn from_code(code: &str) -> Option<PolarityKind> {
+        match code {
+            "AP_ATTACK" => Some(PolarityKind::Madurai),
+            "AP_DEFENSE" => Some(PolarityKind::Vazarin),
+            "AP_TACTIC" => Some(PolarityKind::Naramon),
+            "AP_POWER" => Some(PolarityKind::Zenurik),
+            "AP_WARD" => Some(PolarityKind::Unairu),
+            "AP_PRECEPT" => Some(PolarityKind::Penjaga),
+            "AP_UMBRA" => Some(PolarityKind::Umbra),
+            "AP_UNIVERSAL" | "AP_ANY" => Some(PolarityKind::Universal),
+            _ => None,
+        }
+    }
+
+    /// Polarity as named in itemdata, e.g. `madurai`
+    pub fn from_name(name: &str) -> Option<PolarityKind> {
+        match name.to_ascii_lowercase().as_str() {
+            "madurai" => Some(PolarityKind::Madurai),
+            "vazarin" => Some(PolarityKind::Vazarin),
+            "naramon" => Some(PolarityKind::Naramon),
+            "zenurik" => Some(PolarityKind::Zenurik),
+            "unairu" => Some(PolarityKind::Unairu),
+            "penjaga" => Some(PolarityKind::Penjaga),
+            "umbra" => Some(PolarityKind::Umbra),
+            "universal" | "any" => Some(PolarityKind::Universal),
+            _ => None,
+        }
+    }
+}
+
+/// How a mod's polarity relates to the slot it sits in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PolarityMatch {
+    /// Slot without polarity, or mod without one
+    Neutral,
+    Match,
+    Mismatch,
+}
+
+impl PolarityMatch {
+    pub fn of(slot: Option<PolarityKind>, mod_polarity: Option<PolarityKind>) -> PolarityMatch {
+        match (slot, mod_polarity) {
+            (None, _) | (_, None) => PolarityMatch::Neutral,
+            (Some(PolarityKind::Universal), _) => PolarityMatch::Match,
+            (Some(slot), Some(mod_polarity)) if slot == mod_polarity => PolarityMatch::Match,
+            _ => PolarityMatch::Mismatch,
+        }
+    }
+}
+
+/// Capacity a mod costs once slotted: halved (rounded up) on a matching polarity,
+/// +25% (rounded) on a mismatching one.
+pub fn mod_drain(base_drain: i64, rank: i64, matched: PolarityMatch) -> i64 {
+    let drain = base_drain.abs() + rank;
+    match matched {
+        PolarityMatch::Neutral => drain,
+        PolarityMatch::Match => (drain + 1) / 2,
+        PolarityMatch::Mismatch => (drain * 5 + 2) / 4,
+    }
+}
+
+/// Capacity an aura or stance adds: doubled on a matching polarity, -25% (rounded) otherwise.
+pub fn bonus_capacity(base_drain: i64, rank: i64, matched: PolarityMatch) -> i64 {
+    let bonus = base_drain.abs() + rank;
+    match matched {
+        PolarityMatch::Neutral => bonus,
+        PolarityMatch::Match => bonus * 2,
+        PolarityMatch::Mismatch => (bonus * 3 + 2) / 4,
+    }
+}
+
+/// A mod placed in a slot, as input to `calculate_capacity`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModSlot {
+    pub index: usize,
+    pub name: String,
+    pub base_drain: i64,
+    pub rank: i64,
+    pub polarity: Option<PolarityKind>,
+    /// Aura and stance slots add capacity instead of using it
+    pub bonus: bool,
+}
+
+/// Capacity effect of one slot.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SlotCapacity {
+    pub index: usize,
+    pub name: String,
+    pub slot_polarity: Option<PolarityKind>,
+    pub mod_polarity: Option<PolarityKind>,
+    pub matched: PolarityMatch,
+    /// Capacity used, negative for aura and stance bonuses
+    pub drain: i64,
+    /// Drain with a matching Forma in this slot, when that would be lower
+    pub forma_drain: Option<i64>,
+}
+
+impl SlotCapacity {
+    pub fn forma_saving(&self) -> i64 {
+        self.forma_drain
+            .map(|forma_drain| self.drain - forma_drain)
+            .unwrap_or(0)
+    }
+}
+
+/// Capacity used by a build against what the item offers.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CapacityReport {
+    /// Item rank, doubled with an Orokin Reactor or Catalyst
+    pub base_capacity: i64,
+    /// Added by aura or stance
+    pub bonus: i64,
+    /// Drained by all other mods
+    pub used: i64,
+    pub remaining: i64,
+    pub slots: Vec<SlotCapacity>,
+}
+
+impl CapacityReport {
+    pub fn is_valid(&self) -> bool {
+        self.remaining >= 0
+    }
+
+    /// Slots a Forma would help, largest saving first
+    pub fn forma_candidates(&self) -> Vec<&SlotCapacity> {
+        let mut candidates: Vec<&SlotCapacity> = self
+            .slots
+            .iter()
+            .filter(|slot| slot.forma_saving() > 0)
+            .collect();
+        candidates.sort_by_key(|slot| std::cmp::Reverse(slot.forma_saving()));
+        candidates
+    }
+}
+
+/// Capacity of `mods` slotted into an item of `rank` with the given slot `polarities`.
+pub fn calculate_capacity(
+    mods: &[ModSlot],
+    polarities: &[Polarity],
+    rank: i64,
+    double_capacity: bool,
+) -> CapacityReport {
+    let base_capacity = if double_capacity { rank * 2 } else { rank };
+    let mut report = CapacityReport {
+        base_capacity,
+        bonus: 0,
+        used: 0,
+        remaining: 0,
+        slots: Vec::with_capacity(mods.len()),
+    };
+
+    for slotted in mods {
+        let slot_polarity = polarities
+            .iter()
+            .find(|polarity| polarity.slot == Some(slotted.index))
+            .and_then(Polarity::kind);
+        let matched = PolarityMatch::of(slot_polarity, slotted.polarity);
+
+        let cost = |matched| {
+            if slotted.bonus {
+                -bonus_capacity(slotted.base_drain, slotted.rank, matched)
+            } else {
+                mod_drain(slotted.base_drain, slotted.rank, matched)
+            }
+        };
+        let drain = cost(matched);
+        let forma_drain = match matched {
+            PolarityMatch::Match => None,
+            _ if slotted.polarity.is_none() => None,
+            _ => Some(cost(PolarityMatch::Match)),
+        };
+
+        if drain < 0 {
+            report.bonus -= drain;
+        } else {
+            report.used += drain;
+        }
+        report.slots.push(SlotCapacity {
+            index: slotted.index,
+            name: slotted.name.clone(),
+            slot_polarity,
+            mod_polarity: slotted.polarity,
+            matched,
+            drain,
+            forma_drain,
+        });
+    }
+
+    report.remaining = report.base_capacity + report.bonus - report.used;
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::from_str;
+
+    #[test]
+    fn test_mod_drain() {
+        assert_eq!(mod_drain(6, 10, PolarityMatch::Neutral), 16);
+        assert_eq!(mod_drain(6, 10, PolarityMatch::Match), 8);
+        assert_eq!(mod_drain(6, 10, PolarityMatch::Mismatch), 20);
+        assert_eq!(mod_drain(4, 9, PolarityMatch::Match), 7);
+        assert_eq!(bonus_capacity(-2, 5, PolarityMatch::Match), 14);
+        assert_eq!(bonus_capacity(-2, 5, PolarityMatch::Mismatch), 5);
+    }
+
+    #[test]
+    fn test_calculate_capacity() {
+        let polarities: Vec<Polarity> =
+            from_str(r#"[{"Slot":0,"Value":"AP_ATTACK"},{"Slot":1,"Value":"AP_DEFENSE"},{"Slot":8,"Value":"AP_ATTACK"}]"#)
+                .unwrap();
+        let slot = |index, base_drain, rank, polarity, bonus| ModSlot {
+            index,
+            name: format!("Mod {}", index),
+            base_drain,
+            rank,
+            polarity: Some(polarity),
+            bonus,
+        };
+        let mods = [
+            slot(0, 6, 10, PolarityKind::Madurai, false),
+            slot(1, 6, 10, PolarityKind::Madurai, false),
+            slot(2, 4, 5, PolarityKind::Naramon, false),
+            slot(8, -2, 5, PolarityKind::Madurai, true),
+        ];
+
+        let report = calculate_capacity(&mods, &polarities, 30, true);
+        assert_eq!(report.base_capacity, 60);
+        assert_eq!(report.bonus, 14);
+        assert_eq!(report.used, 8 + 20 + 9);
+        assert_eq!(report.remaining, 60 + 14 - 37);
+        assert!(report.is_valid());
+
+        let candidates = report.forma_candidates();
+        assert_eq!(candidates.len(), 2);
+        assert_eq!(candidates[0].index, 1);
+        assert_eq!(candidates[0].forma_saving(), 12);
+        assert_eq!(candidates[1].index, 2);
+
+        let report = calculate_capacity(&mods, &[], 30, false);
+        assert_eq!(report.remaining, 30 + 7 - 16 - 16 - 9);
+        assert!(!report.is_valid());
+    }
+}
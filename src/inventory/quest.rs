@@ -0,0 +1,107 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::inventory::DateWrapper;
+
+/// Quest key chain, one per quest the player has received.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuestKey {
+    /// e.g. `/Lotus/Types/Keys/VorsPrize/VorsPrizeQuestKeyChain`
+    #[serde(rename = "ItemType")]
+    pub item_type: String,
+
+    /// One entry per stage reached
+    #[serde(rename = "Progress", default)]
+    pub progress: Vec<QuestStage>,
+
+    #[serde(rename = "Completed", default)]
+    pub completed: bool,
+
+    /// Only recorded for quests completed in recent years
+    #[serde(rename = "CompletionDate")]
+    pub completion_date: Option<DateWrapper>,
+
+    #[serde(rename = "unlock")]
+    pub unlock: Option<bool>,
+
+    /// Quest specific choices as a JSON string
+    #[serde(rename = "CustomData")]
+    pub custom_data: Option<String>,
+
+    #[serde(flatten)]
+    pub other: Option<Value>,
+}
+
+impl QuestKey {
+    pub fn is_started(&self) -> bool {
+        self.completed || !self.progress.is_empty()
+    }
+
+    pub fn completion_date(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.completion_date.as_ref().and_then(|date| date.date)
+    }
+}
+
+/// Progress of one quest stage. A stage gets an entry once it is unlocked and the next
+/// entry is only appended after it is finished, so every entry but the last is done.
+/// The flags describe the stage itself and are mostly left unset on older accounts.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuestStage {
+    /// Times the stage objective was completed
+    #[serde(rename = "c", default)]
+    pub c: i64,
+
+    /// The stage item or inbox message was received
+    #[serde(rename = "i", default)]
+    pub i: bool,
+
+    /// The stage mission was played
+    #[serde(rename = "m", default)]
+    pub m: bool,
+
+    /// Stage specific data, usually empty
+    #[serde(rename = "b")]
+    pub b: Option<Value>,
+}
+
+impl QuestStage {
+    pub fn is_completed(&self) -> bool {
+        self.c > 0
+    }
+
+    /// Work on the stage began without completing it yet
+    pub fn is_started(&self) -> bool {
+        !self.is_completed() && (self.i || self.m)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use serde_json::from_str;
+
+    #[test]
+    fn test_deserialize_quest_keys() {
+        let js = r#"[
+  {
+    "Progress": [{ "c": 0, "i": false, "m": false }, { "c": 0, "i": false, "m": true, "b": [] }],
+    "unlock": true,
+    "Completed": true,
+    "CompletionDate": { "$date": { "$numberLong": "1652554441071" } },
+    "ItemType": "/Lotus/Types/Keys/ZarimanQuest/ZarimanQuestKeyChain"
+  },
+  { "unlock": true, "ItemType": "/Lotus/Types/Keys/1999Quest/1999QuestGoodEndKeyChain" }
+]"#;
+        let quests: Vec<QuestKey> = from_str(js).unwrap();
+        assert!(quests[0].completed);
+        assert_eq!(quests[0].progress.len(), 2);
+        assert!(quests[0].progress[1].m);
+        assert_eq!(
+            quests[0].completion_date(),
+            Some(Utc.timestamp_millis_opt(1652554441071).unwrap())
+        );
+        assert!(!quests[1].is_started());
+        assert_eq!(quests[1].completion_date(), None);
+    }
+}
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
-use crate::inventory::{ObjectId, Polarity};
+use crate::inventory::{ObjectId, Polarity, config::ConfigWrapper};
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SpaceMelee {
@@ -23,12 +23,19 @@ pub struct SpaceMelee {
     #[serde(rename = "Polarized")]
     pub polarized: Option<i64>,
 
+    /// Bit set of `FEATURE_*` upgrades such as the Orokin Reactor
+    #[serde(rename = "Features")]
+    pub features: Option<i64>,
+
     #[serde(rename = "ModSlotPurchases")]
     pub mod_slot_purchases: Option<i64>,
 
     #[serde(rename = "IsNew")]
     pub is_new: Option<bool>,
 
+    #[serde(rename = "Configs", default)]
+    pub configs: Vec<ConfigWrapper>,
+
     #[serde(flatten)]
     pub other: Option<Value>,
 }
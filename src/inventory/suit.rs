@@ -1,7 +1,8 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
-use crate::inventory::{DateWrapper, ObjectId, Polarity};
+use crate::inventory::archon::{ArchonShard, SHARD_SOCKETS};
+use crate::inventory::{DateWrapper, ObjectId, Polarity, config::ConfigWrapper};
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ArchonCrystalUpgrade {
@@ -42,6 +43,10 @@ pub struct Suit {
     #[serde(rename = "Polarized")]
     pub polarized: Option<i64>,
 
+    /// Bit set of `FEATURE_*` upgrades such as the Orokin Reactor
+    #[serde(rename = "Features")]
+    pub features: Option<i64>,
+
     #[serde(rename = "ModSlotPurchases")]
     pub mod_slot_purchases: Option<i64>,
 
@@ -51,10 +56,32 @@ pub struct Suit {
     #[serde(rename = "IsNew")]
     pub is_new: Option<bool>,
 
+    #[serde(rename = "Configs", default)]
+    pub configs: Vec<ConfigWrapper>,
+
     #[serde(flatten)]
     pub other: Option<Value>,
 }
 
+impl Suit {
+    /// The five shard sockets in order, `None` for empty ones.
+    /// Empty sockets are stored as `[]` placeholders or left off the end.
+    pub fn shard_sockets(&self) -> [Option<ArchonShard>; SHARD_SOCKETS] {
+        let mut sockets: [Option<ArchonShard>; SHARD_SOCKETS] = Default::default();
+        let upgrades = self.archon_crystal_upgrades.iter().flatten();
+        for (socket, wrapper) in sockets.iter_mut().zip(upgrades) {
+            if let ArchonCrystalUpgradeWrapper::ArchonCrystalUpgrade(upgrade) = wrapper {
+                *socket = ArchonShard::from_upgrade(upgrade);
+            }
+        }
+        sockets
+    }
+
+    pub fn archon_shards(&self) -> impl Iterator<Item = ArchonShard> {
+        self.shard_sockets().into_iter().flatten()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -59,6 +59,8 @@ pub struct RivenFingerprint {
     pub rerolls: Option<i64>,
     pub pol: String,
     pub buffs: Vec<Buff>,
+    #[serde(default)]
+    pub curses: Vec<Buff>,
 
     #[serde(flatten)]
     pub other: Option<Value>,
@@ -210,6 +212,7 @@ mod tests {
             assert_eq!(r.lvl, Some(8));
             assert_eq!(r.rerolls, Some(5));
             assert_eq!(r.buffs.len(), 3);
+            assert_eq!(r.curses.len(), 1);
         } else {
             panic!("expected Riven variant");
         }
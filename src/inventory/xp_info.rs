@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+/// Affinity earned on an item, kept even after the item is sold.
+/// This is what mastery is computed from.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct XpInfo {
+    #[serde(rename = "ItemType")]
+    pub item_type: String,
+
+    #[serde(rename = "XP")]
+    pub xp: i64,
+}
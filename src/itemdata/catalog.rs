@@ -0,0 +1,916 @@
+use std::collections::HashMap;
+use std::env;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::itemdata::{
+    ProductCategory, arcane, arch_gun, arch_melee, archwing, gear, melee, misc, mods, pet, primary,
+    quest, relics, resource, secondary, sentinel, sentinel_weapon, warframe,
+};
+
+/// Category of a catalog entry, one per warframe-items JSON file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum ItemCategory {
+    Warframe,
+    Primary,
+    Secondary,
+    Melee,
+    Archwing,
+    ArchGun,
+    ArchMelee,
+    Pet,
+    Sentinel,
+    SentinelWeapon,
+    Arcane,
+    Mod,
+    Gear,
+    Relic,
+    Resource,
+    Quest,
+    Misc,
+}
+
+impl ItemCategory {
+    /// All categories, in the order they are loaded.
+    /// When the same `unique_name` shows up in several files the first one wins,
+    /// so the catch-all `Misc` file goes last.
+    pub const ALL: [ItemCategory; 17] = [
+        ItemCategory::Warframe,
+        ItemCategory::Primary,
+        ItemCategory::Secondary,
+        ItemCategory::Melee,
+        ItemCategory::Archwing,
+        ItemCategory::ArchGun,
+        ItemCategory::ArchMelee,
+        ItemCategory::Pet,
+        ItemCategory::Sentinel,
+        ItemCategory::SentinelWeapon,
+        ItemCategory::Arcane,
+        ItemCategory::Mod,
+        ItemCategory::Gear,
+        ItemCategory::Relic,
+        ItemCategory::Resource,
+        ItemCategory::Quest,
+        ItemCategory::Misc,
+    ];
+
+    /// Name of the file holding this category in the warframe-items `json` directory
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            ItemCategory::Warframe => "Warframes.json",
+            ItemCategory::Primary => "Primary.json",
+            ItemCategory::Secondary => "Secondary.json",
+            ItemCategory::Melee => "Melee.json",
+            ItemCategory::Archwing => "Archwing.json",
+            ItemCategory::ArchGun => "Arch-Gun.json",
+            ItemCategory::ArchMelee => "Arch-Melee.json",
+            ItemCategory::Pet => "Pets.json",
+            ItemCategory::Sentinel => "Sentinels.json",
+            ItemCategory::SentinelWeapon => "SentinelWeapons.json",
+            ItemCategory::Arcane => "Arcanes.json",
+            ItemCategory::Mod => "Mods.json",
+            ItemCategory::Gear => "Gear.json",
+            ItemCategory::Relic => "Relics.json",
+            ItemCategory::Resource => "Resources.json",
+            ItemCategory::Quest => "Quests.json",
+            ItemCategory::Misc => "Misc.json",
+        }
+    }
+}
+
+/// A single catalog entry, wrapping the per-category itemdata struct.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CatalogItem {
+    Warframe(warframe::Warframe),
+    Primary(primary::Primary),
+    Secondary(secondary::Secondary),
+    Melee(melee::Melee),
+    Archwing(archwing::Archwing),
+    ArchGun(arch_gun::ArchGun),
+    ArchMelee(arch_melee::ArchMelee),
+    Pet(pet::Pet),
+    Sentinel(sentinel::Sentinel),
+    SentinelWeapon(sentinel_weapon::SentinelWeapon),
+    Arcane(arcane::Arcane),
+    Mod(mods::Mod),
+    Gear(gear::Gear),
+    Relic(relics::Relic),
+    Resource(resource::Resource),
+    Quest(quest::Quest),
+    Misc(Box<misc::Misc>),
+}
+
+/// Evaluates `$body` with `$item` bound to the inner struct, whatever the variant.
+/// Only usable for fields every itemdata struct shares.
+macro_rules! with_item {
+    ($value:expr, $item:ident => $body:expr) => {
+        match $value {
+            CatalogItem::Warframe($item) => $body,
+            CatalogItem::Primary($item) => $body,
+            CatalogItem::Secondary($item) => $body,
+            CatalogItem::Melee($item) => $body,
+            CatalogItem::Archwing($item) => $body,
+            CatalogItem::ArchGun($item) => $body,
+            CatalogItem::ArchMelee($item) => $body,
+            CatalogItem::Pet($item) => $body,
+            CatalogItem::Sentinel($item) => $body,
+            CatalogItem::SentinelWeapon($item) => $body,
+            CatalogItem::Arcane($item) => $body,
+            CatalogItem::Mod($item) => $body,
+            CatalogItem::Gear($item) => $body,
+            CatalogItem::Relic($item) => $body,
+            CatalogItem::Resource($item) => $body,
+            CatalogItem::Quest($item) => $body,
+            CatalogItem::Misc($item) => $body,
+        }
+    };
+}
+
+/// Builds `DropInfo`s out of any itemdata `Drop`/`Drop2` iterator.
+macro_rules! drop_infos {
+    ($iter:expr) => {
+        $iter
+            .map(|d| DropInfo {
+                name: d.type_field.clone(),
+                location: d.location.clone(),
+                rarity: d.rarity.clone(),
+                chance: d.chance.as_chance(),
+            })
+            .collect()
+    };
+}
+
+/// Builds `ComponentInfo`s out of any itemdata `Component` iterator.
+macro_rules! component_infos {
+    ($iter:expr, $c:ident => $ducats:expr) => {
+        $iter
+            .map(|$c| ComponentInfo {
+                unique_name: $c.unique_name.clone(),
+                name: $c.name.clone(),
+                item_count: $c.item_count,
+                tradable: $c.tradable,
+                ducats: $ducats,
+                drops: drop_infos!($c.drops.iter()),
+            })
+            .collect()
+    };
+}
+
+/// Reads the optional foundry fields most itemdata structs share.
+macro_rules! build_info {
+    ($item:expr) => {
+        match ($item.build_price, $item.build_time) {
+            (None, None) => None,
+            (price, time) => Some(BuildInfo {
+                price: price.unwrap_or(0),
+                quantity: $item.build_quantity.unwrap_or(1).max(1),
+                time: time.unwrap_or(0),
+                consume_on_build: $item.consume_on_build.unwrap_or(true),
+            }),
+        }
+    };
+}
+
+impl CatalogItem {
+    pub fn category(&self) -> ItemCategory {
+        match self {
+            CatalogItem::Warframe(_) => ItemCategory::Warframe,
+            CatalogItem::Primary(_) => ItemCategory::Primary,
+            CatalogItem::Secondary(_) => ItemCategory::Secondary,
+            CatalogItem::Melee(_) => ItemCategory::Melee,
+            CatalogItem::Archwing(_) => ItemCategory::Archwing,
+            CatalogItem::ArchGun(_) => ItemCategory::ArchGun,
+            CatalogItem::ArchMelee(_) => ItemCategory::ArchMelee,
+            CatalogItem::Pet(_) => ItemCategory::Pet,
+            CatalogItem::Sentinel(_) => ItemCategory::Sentinel,
+            CatalogItem::SentinelWeapon(_) => ItemCategory::SentinelWeapon,
+            CatalogItem::Arcane(_) => ItemCategory::Arcane,
+            CatalogItem::Mod(_) => ItemCategory::Mod,
+            CatalogItem::Gear(_) => ItemCategory::Gear,
+            CatalogItem::Relic(_) => ItemCategory::Relic,
+            CatalogItem::Resource(_) => ItemCategory::Resource,
+            CatalogItem::Quest(_) => ItemCategory::Quest,
+            CatalogItem::Misc(_) => ItemCategory::Misc,
+        }
+    }
+
+    pub fn unique_name(&self) -> &str {
+        with_item!(self, item => &item.unique_name)
+    }
+
+    pub fn name(&self) -> &str {
+        with_item!(self, item => &item.name)
+    }
+
+    pub fn masterable(&self) -> bool {
+        with_item!(self, item => item.masterable)
+    }
+
+    pub fn tradable(&self) -> bool {
+        with_item!(self, item => item.tradable)
+    }
+
+    /// Mastery rank required to use the item, if the category has one
+    pub fn mastery_req(&self) -> Option<i64> {
+        match self {
+            CatalogItem::Warframe(item) => item.mastery_req,
+            CatalogItem::Primary(item) => Some(item.mastery_req),
+            CatalogItem::Secondary(item) => Some(item.mastery_req),
+            CatalogItem::Melee(item) => Some(item.mastery_req),
+            CatalogItem::Archwing(item) => Some(item.mastery_req),
+            CatalogItem::ArchGun(item) => Some(item.mastery_req),
+            CatalogItem::ArchMelee(item) => Some(item.mastery_req),
+            CatalogItem::Pet(item) => Some(item.mastery_req),
+            CatalogItem::Sentinel(item) => item.mastery_req,
+            CatalogItem::SentinelWeapon(item) => Some(item.mastery_req),
+            CatalogItem::Misc(item) => item.mastery_req,
+            CatalogItem::Arcane(_)
+            | CatalogItem::Mod(_)
+            | CatalogItem::Gear(_)
+            | CatalogItem::Relic(_)
+            | CatalogItem::Resource(_)
+            | CatalogItem::Quest(_) => None,
+        }
+    }
+
+    pub fn is_prime(&self) -> bool {
+        match self {
+            CatalogItem::Warframe(item) => item.is_prime,
+            CatalogItem::Primary(item) => item.is_prime,
+            CatalogItem::Secondary(item) => item.is_prime,
+            CatalogItem::Melee(item) => item.is_prime,
+            CatalogItem::Archwing(item) => item.is_prime,
+            CatalogItem::ArchGun(item) => item.is_prime,
+            CatalogItem::ArchMelee(item) => item.is_prime,
+            CatalogItem::Sentinel(item) => item.is_prime,
+            CatalogItem::SentinelWeapon(item) => item.is_prime,
+            CatalogItem::Mod(item) => item.is_prime,
+            CatalogItem::Pet(_)
+            | CatalogItem::Arcane(_)
+            | CatalogItem::Gear(_)
+            | CatalogItem::Relic(_)
+            | CatalogItem::Resource(_)
+            | CatalogItem::Quest(_)
+            | CatalogItem::Misc(_) => false,
+        }
+    }
+
+    /// Highest rank the item can reach, 40 for Kuva/Tenet weapons and Necramechs
+    pub fn max_rank(&self) -> i64 {
+        let cap = match self {
+            CatalogItem::Primary(item) => item.max_level_cap,
+            CatalogItem::Secondary(item) => item.max_level_cap,
+            CatalogItem::Melee(item) => item.max_level_cap,
+            CatalogItem::ArchGun(item) => item.max_level_cap,
+            CatalogItem::Warframe(item)
+                if item.product_category.as_deref() == Some("MechSuits") =>
+            {
+                Some(40)
+            }
+            _ => None,
+        };
+        cap.unwrap_or(30)
+    }
+
+    /// Riven disposition multiplier (`omegaAttenuation`), only weapons have one
+    pub fn disposition(&self) -> Option<f64> {
+        match self {
+            CatalogItem::Primary(item) => Some(item.omega_attenuation),
+            CatalogItem::Secondary(item) => Some(item.omega_attenuation),
+            CatalogItem::Melee(item) => Some(item.omega_attenuation),
+            CatalogItem::ArchGun(item) => Some(item.omega_attenuation),
+            CatalogItem::ArchMelee(item) => Some(item.omega_attenuation),
+            CatalogItem::SentinelWeapon(item) => Some(item.omega_attenuation),
+            CatalogItem::Misc(item) => item.omega_attenuation,
+            _ => None,
+        }
+    }
+
+    /// Foundry cost of the main blueprint, `None` for items that are not built
+    pub fn build_info(&self) -> Option<BuildInfo> {
+        match self {
+            CatalogItem::Warframe(item) => build_info!(item),
+            CatalogItem::Primary(item) => build_info!(item),
+            CatalogItem::Secondary(item) => build_info!(item),
+            CatalogItem::Melee(item) => build_info!(item),
+            CatalogItem::Archwing(item) => Some(BuildInfo {
+                price: item.build_price,
+                quantity: item.build_quantity.max(1),
+                time: item.build_time,
+                consume_on_build: item.consume_on_build,
+            }),
+            CatalogItem::ArchGun(item) => build_info!(item),
+            CatalogItem::ArchMelee(item) => build_info!(item),
+            CatalogItem::Pet(item) => build_info!(item),
+            CatalogItem::Sentinel(item) => build_info!(item),
+            CatalogItem::SentinelWeapon(item) => build_info!(item),
+            CatalogItem::Arcane(item) => build_info!(item),
+            CatalogItem::Gear(item) => build_info!(item),
+            CatalogItem::Resource(item) => build_info!(item),
+            CatalogItem::Quest(item) => build_info!(item),
+            CatalogItem::Misc(item) => build_info!(item),
+            CatalogItem::Mod(_) | CatalogItem::Relic(_) => None,
+        }
+    }
+
+    /// Crafting components, normalized across categories
+    pub fn components(&self) -> Vec<ComponentInfo> {
+        match self {
+            CatalogItem::Warframe(item) => component_infos!(item.components.iter(), c => c.ducats),
+            CatalogItem::Primary(item) => component_infos!(item.components.iter(), c => c.ducats),
+            CatalogItem::Secondary(item) => {
+                component_infos!(item.components.iter(), c => c.ducats)
+            }
+            CatalogItem::Melee(item) => component_infos!(item.components.iter(), c => c.ducats),
+            CatalogItem::Archwing(item) => component_infos!(item.components.iter(), c => c.ducats),
+            CatalogItem::ArchGun(item) => component_infos!(item.components.iter(), c => c.ducats),
+            CatalogItem::ArchMelee(item) => component_infos!(item.components.iter(), _c => None),
+            CatalogItem::Pet(item) => {
+                component_infos!(item.components.iter().flatten(), _c => None)
+            }
+            CatalogItem::Sentinel(item) => component_infos!(item.components.iter(), c => c.ducats),
+            CatalogItem::SentinelWeapon(item) => {
+                component_infos!(item.components.iter().flatten(), _c => None)
+            }
+            CatalogItem::Arcane(item) => {
+                component_infos!(item.components.iter().flatten(), _c => None)
+            }
+            CatalogItem::Gear(item) => component_infos!(item.components.iter(), _c => None),
+            CatalogItem::Resource(item) => component_infos!(item.components.iter(), _c => None),
+            CatalogItem::Quest(item) => component_infos!(item.components.iter(), _c => None),
+            CatalogItem::Misc(item) => component_infos!(item.components.iter(), _c => None),
+            CatalogItem::Mod(_) | CatalogItem::Relic(_) => vec![],
+        }
+    }
+
+    /// Where the item itself drops, empty for categories without drop data
+    pub fn drops(&self) -> Vec<DropInfo> {
+        match self {
+            CatalogItem::Warframe(item) => drop_infos!(item.drops.iter().flatten()),
+            CatalogItem::Primary(item) => drop_infos!(item.drops.iter()),
+            CatalogItem::Secondary(item) => drop_infos!(item.drops.iter()),
+            CatalogItem::Melee(item) => drop_infos!(item.drops.iter()),
+            CatalogItem::Pet(item) => drop_infos!(item.drops.iter().flatten()),
+            CatalogItem::Arcane(item) => drop_infos!(item.drops.iter()),
+            CatalogItem::Mod(item) => drop_infos!(item.drops.iter()),
+            CatalogItem::Gear(item) => drop_infos!(item.drops.iter()),
+            CatalogItem::Relic(item) => drop_infos!(item.drops.iter()),
+            CatalogItem::Resource(item) => drop_infos!(item.drops.iter()),
+            CatalogItem::Misc(item) => drop_infos!(item.drops.iter()),
+            CatalogItem::Archwing(_)
+            | CatalogItem::ArchGun(_)
+            | CatalogItem::ArchMelee(_)
+            | CatalogItem::Sentinel(_)
+            | CatalogItem::SentinelWeapon(_)
+            | CatalogItem::Quest(_) => vec![],
+        }
+    }
+}
+
+/// Crafting component of a catalog item.
+/// Each itemdata module has its own `Component` struct, this keeps the fields they share.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ComponentInfo {
+    pub unique_name: String,
+    /// Short name such as "Chassis" or "Blueprint", or the resource name
+    pub name: String,
+    pub item_count: i64,
+    pub tradable: bool,
+    pub ducats: Option<i64>,
+    #[serde(default)]
+    pub drops: Vec<DropInfo>,
+}
+
+/// Foundry cost of building an item once.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BuildInfo {
+    /// Credits
+    pub price: i64,
+    /// Items produced per build
+    pub quantity: i64,
+    /// Seconds
+    pub time: i64,
+    /// Whether the blueprint is used up by the build
+    pub consume_on_build: bool,
+}
+
+/// Where an item or component drops, e.g. a relic at a given refinement.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DropInfo {
+    /// Full name of the dropped item, e.g. "Harrow Prime Blueprint"
+    pub name: String,
+    /// Drop source, e.g. "Axi K6 Relic (Exceptional)"
+    pub location: String,
+    pub rarity: String,
+    /// Probability between 0 and 1
+    pub chance: f64,
+}
+
+/// Drop chances are floats in most itemdata files but got inferred as integers in a few.
+trait AsChance {
+    fn as_chance(&self) -> f64;
+}
+
+impl AsChance for f64 {
+    fn as_chance(&self) -> f64 {
+        *self
+    }
+}
+
+impl AsChance for i64 {
+    fn as_chance(&self) -> f64 {
+        *self as f64
+    }
+}
+
+impl AsChance for Option<f64> {
+    fn as_chance(&self) -> f64 {
+        self.unwrap_or(0.0)
+    }
+}
+
+/// Component together with the catalog item it is used to build.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComponentEntry {
+    pub parent: String,
+    pub info: ComponentInfo,
+}
+
+/// What an inventory `ItemType` path resolved to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Resolved<'a> {
+    /// The path is a catalog item
+    Item(&'a CatalogItem),
+    /// The path is a component of a catalog item, or the blueprint of such a component
+    Component {
+        parent: &'a CatalogItem,
+        component: &'a ComponentInfo,
+        blueprint: bool,
+    },
+}
+
+impl<'a> Resolved<'a> {
+    /// Display name, e.g. "Harrow Prime Chassis Blueprint"
+    pub fn name(&self) -> String {
+        match self {
+            Resolved::Item(item) => item.name().to_string(),
+            Resolved::Component {
+                parent,
+                component,
+                blueprint,
+            } => {
+                // Resources used as components already carry their full name
+                if !component.unique_name.contains("/Recipes/") {
+                    return component.name.clone();
+                }
+                let mut name = format!("{} {}", parent.name(), component.name);
+                if *blueprint && component.name != "Blueprint" {
+                    name.push_str(" Blueprint");
+                }
+                name
+            }
+        }
+    }
+
+    /// The catalog item itself, or the item the component builds
+    pub fn item(&self) -> &'a CatalogItem {
+        match self {
+            Resolved::Item(item) => item,
+            Resolved::Component { parent, .. } => parent,
+        }
+    }
+}
+
+impl ProductCategory for CatalogItem {
+    fn get_product_categories(&self) -> Vec<String> {
+        with_item!(self, item => item.get_product_categories())
+    }
+}
+
+/// Every itemdata category indexed by `unique_name`.
+#[derive(Default, Debug, Clone)]
+pub struct Catalog {
+    items: HashMap<String, CatalogItem>,
+    components: HashMap<String, ComponentEntry>,
+}
+
+impl Catalog {
+    /// Loads all warframe-items JSON files found in `dir` (the `json` folder of the dataset).
+    /// Missing files are skipped, malformed files are an error.
+    pub fn load_from_dir(dir: &Path) -> anyhow::Result<Catalog> {
+        let mut catalog = Catalog::default();
+
+        for category in ItemCategory::ALL {
+            let path = dir.join(category.file_name());
+            if !path.exists() {
+                log::warn!("Catalog file {} not found, skipping", path.display());
+                continue;
+            }
+
+            let items = match category {
+                ItemCategory::Warframe => load_file(&path, CatalogItem::Warframe),
+                ItemCategory::Primary => load_file(&path, CatalogItem::Primary),
+                ItemCategory::Secondary => load_file(&path, CatalogItem::Secondary),
+                ItemCategory::Melee => load_file(&path, CatalogItem::Melee),
+                ItemCategory::Archwing => load_file(&path, CatalogItem::Archwing),
+                ItemCategory::ArchGun => load_file(&path, CatalogItem::ArchGun),
+                ItemCategory::ArchMelee => load_file(&path, CatalogItem::ArchMelee),
+                ItemCategory::Pet => load_file(&path, CatalogItem::Pet),
+                ItemCategory::Sentinel => load_file(&path, CatalogItem::Sentinel),
+                ItemCategory::SentinelWeapon => load_file(&path, CatalogItem::SentinelWeapon),
+                ItemCategory::Arcane => load_file(&path, CatalogItem::Arcane),
+                ItemCategory::Mod => load_file(&path, CatalogItem::Mod),
+                ItemCategory::Gear => load_file(&path, CatalogItem::Gear),
+                ItemCategory::Relic => load_file(&path, CatalogItem::Relic),
+                ItemCategory::Resource => load_file(&path, CatalogItem::Resource),
+                ItemCategory::Quest => load_file(&path, CatalogItem::Quest),
+                ItemCategory::Misc => load_file(&path, |item| CatalogItem::Misc(Box::new(item))),
+            }?;
+
+            for item in items {
+                catalog.insert_if_absent(item);
+            }
+        }
+
+        log::info!(
+            "Loaded {} catalog items from {}",
+            catalog.len(),
+            dir.display()
+        );
+        Ok(catalog)
+    }
+
+    /// Adds an item, replacing and returning any entry with the same `unique_name`.
+    /// Components indexed under the replaced entry are indexed again, under the new entry
+    /// or under another item that uses them.
+    pub fn insert(&mut self, item: CatalogItem) -> Option<CatalogItem> {
+        let unique_name = item.unique_name().to_string();
+        let replaced = self.items.insert(unique_name.clone(), item);
+        let mut orphaned = Vec::new();
+        if let Some(old) = &replaced {
+            for info in old.components() {
+                if self
+                    .components
+                    .get(&info.unique_name)
+                    .is_some_and(|entry| entry.parent == unique_name)
+                {
+                    self.components.remove(&info.unique_name);
+                    orphaned.push(info.unique_name);
+                }
+            }
+        }
+
+        self.index_components(&unique_name);
+        orphaned.retain(|component| !self.components.contains_key(component));
+        if !orphaned.is_empty() {
+            let mut others: Vec<&str> = self
+                .items
+                .values()
+                .filter(|other| {
+                    other
+                        .components()
+                        .iter()
+                        .any(|info| orphaned.contains(&info.unique_name))
+                })
+                .map(CatalogItem::unique_name)
+                .collect();
+            others.sort();
+            let others: Vec<String> = others.into_iter().map(str::to_string).collect();
+            for other in others {
+                self.index_components(&other);
+            }
+        }
+        replaced
+    }
+
+    /// Indexes the components of an item already in `items`, keeping existing entries
+    fn index_components(&mut self, unique_name: &str) {
+        let Some(item) = self.items.get(unique_name) else {
+            return;
+        };
+        for info in item.components() {
+            self.components
+                .entry(info.unique_name.clone())
+                .or_insert_with(|| ComponentEntry {
+                    parent: unique_name.to_string(),
+                    info,
+                });
+        }
+    }
+
+    fn insert_if_absent(&mut self, item: CatalogItem) {
+        if let Some(existing) = self.items.get(item.unique_name()) {
+            log::debug!(
+                "Duplicate catalog entry {} in {:?}, keeping {:?}",
+                item.unique_name(),
+                item.category(),
+                existing.category()
+            );
+            return;
+        }
+        self.insert(item);
+    }
+
+    pub fn get(&self, unique_name: &str) -> Option<&CatalogItem> {
+        self.items.get(unique_name)
+    }
+
+    pub fn contains(&self, unique_name: &str) -> bool {
+        self.items.contains_key(unique_name)
+    }
+
+    /// Component with the given `unique_name`, with the first item found to use it
+    pub fn component(&self, unique_name: &str) -> Option<&ComponentEntry> {
+        self.components.get(unique_name)
+    }
+
+    /// Resolves an inventory `ItemType` against items first, then components.
+    /// Component blueprints (`...ChassisBlueprint`) resolve to the component they build.
+    pub fn resolve(&self, item_type: &str) -> Option<Resolved<'_>> {
+        if let Some(item) = self.get(item_type) {
+            return Some(Resolved::Item(item));
+        }
+
+        let as_component = |unique_name: &str, blueprint: bool| {
+            self.component(unique_name).and_then(|entry| {
+                self.get(&entry.parent).map(|parent| Resolved::Component {
+                    parent,
+                    component: &entry.info,
+                    blueprint,
+                })
+            })
+        };
+
+        if let Some(resolved) = as_component(item_type, false) {
+            return Some(resolved);
+        }
+
+        let stem = item_type.strip_suffix("Blueprint")?;
+        as_component(stem, true).or_else(|| as_component(&format!("{}Component", stem), true))
+    }
+
+    /// Drop sources of an item, a component or a component blueprint
+    pub fn drops(&self, item_type: &str) -> Vec<DropInfo> {
+        match self.resolve(item_type) {
+            Some(Resolved::Item(item)) => item.drops(),
+            Some(Resolved::Component { component, .. }) => component.drops.clone(),
+            None => Vec::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &CatalogItem> {
+        self.items.values()
+    }
+
+    pub fn by_category(&self, category: ItemCategory) -> impl Iterator<Item = &CatalogItem> {
+        self.iter().filter(move |item| item.category() == category)
+    }
+}
+
+impl FromIterator<CatalogItem> for Catalog {
+    fn from_iter<I: IntoIterator<Item = CatalogItem>>(iter: I) -> Self {
+        let mut catalog = Catalog::default();
+        for item in iter {
+            catalog.insert(item);
+        }
+        catalog
+    }
+}
+
+fn load_file<T: DeserializeOwned>(
+    path: &Path,
+    wrap: fn(T) -> CatalogItem,
+) -> anyhow::Result<Vec<CatalogItem>> {
+    let raw = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    let items: Vec<T> = serde_json::from_str(&raw)
+        .with_context(|| format!("Failed to parse {}", path.display()))?;
+    Ok(items.into_iter().map(wrap).collect())
+}
+
+/// Locates the warframe-items `json` directory.
+/// Checks `WF_ITEMS_DIR` first, then the app cache directory, then the working directory.
+pub fn find_items_dir() -> Option<PathBuf> {
+    if let Ok(custom_path) = env::var("WF_ITEMS_DIR") {
+        let path = PathBuf::from(custom_path);
+        if path.exists() {
+            return Some(path);
+        }
+    }
+
+    if let Some(cache_dir) = dirs::cache_dir() {
+        let path = cache_dir.join("wf-info-2/warframe-items-data/json");
+        if path.exists() {
+            return Some(path);
+        }
+    }
+
+    let path = PathBuf::from("warframe-items-data/json");
+    if path.exists() {
+        return Some(path);
+    }
+
+    None
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    /// Forma, built from one Forma Blueprint
+    pub fn forma() -> CatalogItem {
+        CatalogItem::Misc(Box::new(misc::Misc {
+            name: "Forma".to_string(),
+            unique_name: "/Lotus/Types/Items/MiscItems/Forma".to_string(),
+            build_price: Some(0),
+            build_time: Some(86_400),
+            components: vec![misc::Component {
+                name: "Blueprint".to_string(),
+                unique_name: "/Lotus/Types/Recipes/Components/FormaBlueprint".to_string(),
+                item_count: 1,
+                ..Default::default()
+            }],
+            ..Default::default()
+        }))
+    }
+
+    /// Neural Sensors, dropping on Io and Themisto
+    pub fn neural_sensors() -> CatalogItem {
+        CatalogItem::Resource(resource::Resource {
+            name: "Neural Sensors".to_string(),
+            unique_name: "/Lotus/Types/Items/MiscItems/NeuralSensor".to_string(),
+            drops: vec![
+                resource::Drop2 {
+                    chance: 0.1,
+                    location: "Jupiter/Io (Defense)".to_string(),
+                    rarity: "Uncommon".to_string(),
+                    type_field: "Neural Sensors".to_string(),
+                },
+                resource::Drop2 {
+                    chance: 0.25,
+                    location: "Jupiter/Themisto (Assassination)".to_string(),
+                    rarity: "Uncommon".to_string(),
+                    type_field: "Neural Sensors".to_string(),
+                },
+            ],
+            ..Default::default()
+        })
+    }
+
+    /// Rifle built from its blueprint and 200 Neural Sensors. Like in warframe-items, the
+    /// Neural Sensors component repeats one of the resource drops.
+    pub fn sensor_gun(name: &str, unique_name: &str) -> CatalogItem {
+        CatalogItem::Primary(primary::Primary {
+            name: name.to_string(),
+            unique_name: unique_name.to_string(),
+            build_price: Some(15_000),
+            build_time: Some(43_200),
+            components: vec![
+                primary::Component {
+                    name: "Blueprint".to_string(),
+                    unique_name: format!(
+                        "/Lotus/Types/Recipes/Weapons/{}Blueprint",
+                        unique_name.rsplit('/').next().unwrap()
+                    ),
+                    item_count: 1,
+                    ..Default::default()
+                },
+                primary::Component {
+                    name: "Neural Sensors".to_string(),
+                    unique_name: "/Lotus/Types/Items/MiscItems/NeuralSensor".to_string(),
+                    item_count: 200,
+                    drops: vec![primary::Drop {
+                        chance: 0.1,
+                        location: "Jupiter/Io (Defense)".to_string(),
+                        rarity: "Uncommon".to_string(),
+                        type_field: "Neural Sensors".to_string(),
+                    }],
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        })
+    }
+
+    fn trinity_prime() -> warframe::Warframe {
+        warframe::Warframe {
+            name: "Trinity Prime".to_string(),
+            unique_name: "/Lotus/Powersuits/Trinity/TrinityPrime".to_string(),
+            is_prime: true,
+            masterable: true,
+            mastery_req: Some(0),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_catalog_lookup() {
+        let catalog: Catalog = [
+            CatalogItem::Warframe(trinity_prime()),
+            CatalogItem::Resource(resource::Resource {
+                name: "Rubedo".to_string(),
+                unique_name: "/Lotus/Types/Items/MiscItems/Rubedo".to_string(),
+                ..Default::default()
+            }),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(catalog.len(), 2);
+
+        let item = catalog
+            .get("/Lotus/Powersuits/Trinity/TrinityPrime")
+            .unwrap();
+        assert_eq!(item.name(), "Trinity Prime");
+        assert_eq!(item.category(), ItemCategory::Warframe);
+        assert!(item.is_prime());
+        assert_eq!(catalog.by_category(ItemCategory::Resource).count(), 1);
+        assert!(catalog.get("/Lotus/Powersuits/Trinity/Trinity").is_none());
+    }
+
+    #[test]
+    fn test_catalog_load_from_dir() {
+        let dir = env::temp_dir().join(format!("wf-info-2-catalog-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let warframes = serde_json::to_string(&vec![trinity_prime()]).unwrap();
+        std::fs::write(dir.join("Warframes.json"), warframes).unwrap();
+
+        // Same item again in the catch-all file, the Warframes entry must win
+        let misc = serde_json::to_string(&vec![misc::Misc {
+            name: "Trinity Prime".to_string(),
+            unique_name: "/Lotus/Powersuits/Trinity/TrinityPrime".to_string(),
+            ..Default::default()
+        }])
+        .unwrap();
+        std::fs::write(dir.join("Misc.json"), misc).unwrap();
+
+        let catalog = Catalog::load_from_dir(&dir).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(catalog.len(), 1);
+        assert_eq!(
+            catalog
+                .get("/Lotus/Powersuits/Trinity/TrinityPrime")
+                .unwrap()
+                .category(),
+            ItemCategory::Warframe
+        );
+    }
+
+    #[test]
+    fn test_catalog_insert_replaces_components() {
+        let component = |unique_name: &str| warframe::Component {
+            unique_name: unique_name.to_string(),
+            item_count: 1,
+            ..Default::default()
+        };
+        let chassis = "/Lotus/Types/Recipes/WarframeRecipes/TrinityPrimeChassisComponent";
+        let systems = "/Lotus/Types/Recipes/WarframeRecipes/TrinityPrimeSystemsComponent";
+        let cell = "/Lotus/Types/Items/MiscItems/OrokinCell";
+
+        let mut catalog = Catalog::default();
+        catalog.insert(CatalogItem::Warframe(warframe::Warframe {
+            components: vec![component(chassis), component(cell)],
+            ..trinity_prime()
+        }));
+        catalog.insert(CatalogItem::Warframe(warframe::Warframe {
+            name: "Trinity".to_string(),
+            unique_name: "/Lotus/Powersuits/Trinity/Trinity".to_string(),
+            components: vec![component(cell)],
+            ..Default::default()
+        }));
+        assert_eq!(
+            catalog.component(cell).unwrap().parent,
+            "/Lotus/Powersuits/Trinity/TrinityPrime"
+        );
+
+        let replaced = catalog.insert(CatalogItem::Warframe(warframe::Warframe {
+            components: vec![component(systems)],
+            ..trinity_prime()
+        }));
+        assert!(replaced.is_some());
+        assert_eq!(catalog.len(), 2);
+        assert!(catalog.component(chassis).is_none());
+        assert_eq!(
+            catalog.component(systems).unwrap().parent,
+            "/Lotus/Powersuits/Trinity/TrinityPrime"
+        );
+        // Still used by Trinity
+        assert_eq!(
+            catalog.component(cell).unwrap().parent,
+            "/Lotus/Powersuits/Trinity/Trinity"
+        );
+    }
+}
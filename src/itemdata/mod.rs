@@ -16,9 +16,12 @@ pub mod mods;
 
 pub mod gear;
 pub mod misc;
+pub mod quest;
 pub mod relics;
 pub mod resource;
 
+pub mod catalog;
+
 pub trait ProductCategory {
     fn get_product_categories(&self) -> Vec<String>;
 }
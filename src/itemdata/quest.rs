@@ -0,0 +1,107 @@
+use serde::{Deserialize, Serialize};
+
+use crate::itemdata::ProductCategory;
+
+pub type Root = Vec<Quest>;
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Quest {
+    #[serde(default)]
+    pub category: String,
+    pub description: Option<String>,
+    pub image_name: Option<String>,
+    #[serde(default)]
+    pub masterable: bool,
+    pub name: String,
+    #[serde(default)]
+    pub tradable: bool,
+    #[serde(rename = "type", default)]
+    pub type_field: String,
+    /// Key chain path, e.g. `/Lotus/Types/Keys/VorsPrize/VorsPrizeQuestKeyChain`
+    pub unique_name: String,
+    pub build_price: Option<i64>,
+    pub build_quantity: Option<i64>,
+    pub build_time: Option<i64>,
+    #[serde(default)]
+    pub components: Vec<Component>,
+    pub consume_on_build: Option<bool>,
+    pub skip_build_time_price: Option<i64>,
+    #[serde(default)]
+    pub patchlogs: Vec<Patchlog>,
+}
+
+impl ProductCategory for Quest {
+    fn get_product_categories(&self) -> Vec<String> {
+        vec!["LevelKeys".to_string()]
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Component {
+    pub unique_name: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub item_count: i64,
+    pub image_name: Option<String>,
+    pub tradable: bool,
+    #[serde(default)]
+    pub masterable: bool,
+    #[serde(default)]
+    pub drops: Vec<Drop>,
+    #[serde(rename = "type")]
+    pub type_field: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Drop {
+    pub chance: f64,
+    pub location: String,
+    pub rarity: String,
+    #[serde(rename = "type")]
+    pub type_field: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Patchlog {
+    pub name: String,
+    pub date: String,
+    pub url: String,
+    pub additions: String,
+    pub changes: String,
+    pub fixes: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::from_str;
+
+    #[test]
+    fn test_deserialize_quest() {
+        let json_data = r#"
+{
+  "category": "Quests",
+  "description": "Uncover the secrets of the Archwing and take to the skies.",
+  "imageName": "the-archwing.png",
+  "masterable": false,
+  "name": "The Archwing",
+  "tradable": false,
+  "type": "Quest",
+  "uniqueName": "/Lotus/Types/Keys/ArchwingQuest/ArchwingQuestKeyChain"
+}
+"#;
+
+        let rec: Quest = from_str(json_data).unwrap();
+
+        assert_eq!(rec.name, "The Archwing");
+        assert_eq!(
+            rec.unique_name,
+            "/Lotus/Types/Keys/ArchwingQuest/ArchwingQuestKeyChain"
+        );
+        assert!(rec.components.is_empty());
+    }
+}
@@ -12,10 +12,14 @@ pub struct Relic {
     pub category: String,
     pub description: String,
     pub image_name: String,
-    pub locations: Vec<Value>, // observed to be empty array
+    /// Observed to be empty, the drop sources are in `drops`
+    #[serde(default)]
+    pub locations: Vec<Location>,
     pub masterable: bool,
     pub name: String,
-    pub rewards: Vec<Value>, // observed to be empty array
+    /// Observed to be empty, the reward tables are in the prime components `drops`
+    #[serde(default)]
+    pub rewards: Vec<Reward>,
     pub tradable: bool,
     #[serde(rename = "type")]
     pub type_field: String,
@@ -43,6 +47,34 @@ pub struct Drop {
     pub type_field: String,
 }
 
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Location {
+    pub location: String,
+    pub rarity: Option<String>,
+    pub chance: Option<f64>,
+    #[serde(flatten)]
+    pub other: Option<Value>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Reward {
+    pub rarity: String,
+    /// Percentage, e.g. `25.33` for a common Intact reward
+    pub chance: f64,
+    pub item: RewardItem,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RewardItem {
+    pub name: String,
+    pub unique_name: String,
+    #[serde(flatten)]
+    pub other: Option<Value>,
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Patchlog {
@@ -583,4 +615,39 @@ mod tests {
             "/Lotus/Types/Game/Projections/T4VoidProjectionLavosPrimeASilver"
         );
     }
+
+    #[test]
+    fn test_deserialize_relic_rewards() {
+        let json_data = r#"
+{
+  "category": "Relics",
+  "description": "",
+  "imageName": "lith-intact.png",
+  "masterable": false,
+  "name": "Lith H1 Intact",
+  "rewards": [
+    {
+      "rarity": "Rare",
+      "chance": 2,
+      "item": {
+        "name": "Hikou Prime Blueprint",
+        "uniqueName": "/Lotus/StoreItems/Weapons/Tenno/ThrowingWeapons/HikouPrimeBlueprint",
+        "warframeMarket": { "urlName": "hikou_prime_blueprint" }
+      }
+    }
+  ],
+  "tradable": true,
+  "type": "Relic",
+  "uniqueName": "/Lotus/Types/Game/Projections/T1VoidProjectionHikouPrimeABronze"
+}
+"#;
+
+        let rec: Relic = from_str(json_data).unwrap();
+
+        assert!(rec.locations.is_empty());
+        assert_eq!(rec.rewards.len(), 1);
+        assert_eq!(rec.rewards[0].rarity, "Rare");
+        assert_eq!(rec.rewards[0].chance, 2.0);
+        assert_eq!(rec.rewards[0].item.name, "Hikou Prime Blueprint");
+    }
 }
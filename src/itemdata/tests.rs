@@ -1,5 +1,3 @@
-use std::collections::HashMap;
-
 use crate::{inventory, itemdata};
 
 #[test]
@@ -227,46 +225,35 @@ fn test_deserialize_all_resources() {
 }
 
 #[test]
-fn test_map_warframe_inventory() {
-    use crate::inventory::tests::load_test_inventory;
+#[cfg(feature = "test_with_wf_items")]
+fn test_deserialize_all_quests() {
     let f = concat!(
         env!("CARGO_MANIFEST_DIR"),
-        "/warframe-items-data/json/Warframes.json"
+        "/warframe-items-data/json/Quests.json"
     );
 
     let raw = std::fs::read_to_string(f).unwrap();
 
-    let arr: itemdata::warframe::Root = serde_json::from_str(&raw).unwrap();
+    let arr: itemdata::quest::Root = serde_json::from_str(&raw).unwrap();
+
+    assert!(!arr.is_empty());
+}
 
-    let info_idx: HashMap<String, itemdata::warframe::Warframe> = arr
-        .into_iter()
-        .map(|item| (item.unique_name.clone(), item))
-        .collect();
+#[test]
+fn test_map_warframe_inventory() {
+    use crate::inventory::tests::load_test_inventory;
+    let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/warframe-items-data/json");
+
+    let catalog = itemdata::catalog::Catalog::load_from_dir(std::path::Path::new(dir)).unwrap();
 
     let inventory: inventory::Inventory = load_test_inventory();
-    let inv_index = inventory
+    let trinity = inventory
         .suits
         .iter()
-        .map(|frame| (frame.item_type.clone(), frame.clone()))
-        .collect::<HashMap<_, _>>();
-
-    #[allow(dead_code)]
-    #[derive(Debug)]
-    struct Data {
-        info: itemdata::warframe::Warframe,
-        inventory: Option<inventory::suit::Suit>,
-    };
+        .find(|frame| frame.item_type == "/Lotus/Powersuits/Trinity/TrinityPrime")
+        .unwrap();
 
-    let data: Vec<Data> = info_idx
-        .iter()
-        .map(|(key, info)| {
-            let inv_data = inv_index.get(key).cloned();
-            Data {
-                info: info.clone(),
-                inventory: inv_data,
-            }
-        })
-        .collect();
-
-    print!("Data: {:?}", data);
+    let item = catalog.get(&trinity.item_type).unwrap();
+    assert_eq!(item.name(), "Trinity Prime");
+    assert_eq!(item.category(), itemdata::catalog::ItemCategory::Warframe);
 }
@@ -1,10 +1,33 @@
 pub mod account;
 pub mod api;
+pub mod cli;
+pub mod crafting;
+pub mod diff;
+pub mod dps;
+pub mod drops;
+pub mod enriched;
+pub mod foundry;
+pub mod helminth;
 pub mod inventory;
 pub mod itemdata;
+pub mod loadout;
 pub mod logs;
+pub mod mastery;
+pub mod metrics;
+pub mod primes;
 pub mod process;
 pub mod profile;
+pub mod quests;
+pub mod relics;
+pub mod riven;
+pub mod session;
+pub mod shards;
+pub mod snapshots;
+pub mod sqlite;
+pub mod starchart;
 pub mod storage;
+pub mod survivability;
+pub mod syndicates;
 pub mod utils;
 pub mod watcher;
+pub mod wishlist;
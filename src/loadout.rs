@@ -0,0 +1,447 @@
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::enriched::InventorySection;
+use crate::inventory::config::{Config, ConfigSlot};
+use crate::inventory::polarity::{self, CapacityReport, ModSlot};
+use crate::inventory::upgrades::{Upgrade, UpgradeFingerprint};
+use crate::inventory::{FEATURE_DOUBLE_CAPACITY, Inventory, Polarity, PolarityKind};
+use crate::itemdata::catalog::{Catalog, CatalogItem};
+use crate::mastery::ItemMastery;
+
+/// Role of a position in `Config::upgrades`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SlotKind {
+    Mod,
+    Aura,
+    Exilus,
+    Stance,
+    Arcane,
+}
+
+impl SlotKind {
+    /// Slot layout as observed in inventory data:
+    /// warframes use 0-7 for mods, 8 aura, 9 exilus and 10+ arcanes,
+    /// melee 8 stance, 9 exilus and 10+ arcanes, guns 8 exilus and 9+ arcanes.
+    /// Stances and arcanes are recognized by path first since their index moved over time.
+    pub fn for_slot(section: InventorySection, index: usize, item_type: Option<&str>) -> SlotKind {
+        if let Some(item_type) = item_type {
+            if item_type.contains("/CosmeticEnhancers/") {
+                return SlotKind::Arcane;
+            }
+            if item_type.contains("/MeleeTrees/") {
+                return SlotKind::Stance;
+            }
+        }
+        match (section, index) {
+            (_, 0..=7) => SlotKind::Mod,
+            (InventorySection::Suits, 8) => SlotKind::Aura,
+            (InventorySection::Suits, 9) => SlotKind::Exilus,
+            (InventorySection::Suits, _) => SlotKind::Arcane,
+            (InventorySection::Melee, 8) => SlotKind::Stance,
+            (InventorySection::Melee, 9) => SlotKind::Exilus,
+            (InventorySection::Melee, _) => SlotKind::Arcane,
+            (InventorySection::LongGuns | InventorySection::Pistols, 8) => SlotKind::Exilus,
+            (InventorySection::LongGuns | InventorySection::Pistols, _) => SlotKind::Arcane,
+            _ => SlotKind::Mod,
+        }
+    }
+}
+
+/// Where the mod in a slot is stored in the inventory.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SlotSource {
+    /// Ranked copy in `Upgrades`
+    Upgrade { item_id: String },
+    /// Unranked copy from `RawUpgrades`
+    RawUpgrade,
+}
+
+/// A filled slot of a loadout.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResolvedSlot {
+    /// Position in `Config::upgrades`
+    pub index: usize,
+    pub kind: SlotKind,
+    pub item_type: String,
+    /// Catalog name, "Riven Mod" for rivens
+    pub name: Option<String>,
+    pub rank: i64,
+    pub max_rank: Option<i64>,
+    /// Drain at rank 0 from the catalog, negative for auras and stances
+    pub base_drain: Option<i64>,
+    pub polarity: Option<PolarityKind>,
+    pub source: SlotSource,
+}
+
+impl ResolvedSlot {
+    /// e.g. "Primed Continuity (10/10)"
+    pub fn describe(&self) -> String {
+        let name = self.name.as_deref().unwrap_or(&self.item_type);
+        match self.max_rank {
+            Some(max_rank) => format!("{} ({}/{})", name, self.rank, max_rank),
+            None => format!("{} ({})", name, self.rank),
+        }
+    }
+}
+
+/// One config of one equipment item with its slots resolved.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Build {
+    pub section: InventorySection,
+    pub item_id: String,
+    pub item_type: String,
+    pub item_name: Option<String>,
+    /// Index in the item's `Configs`
+    pub config_index: usize,
+    pub config_name: Option<String>,
+    pub slots: Vec<ResolvedSlot>,
+    /// Upgrade ids referenced by the config but missing from `Upgrades`, and mod paths
+    /// missing from `RawUpgrades`
+    pub missing: Vec<String>,
+    /// Equipment state the capacity depends on
+    pub xp: i64,
+    pub features: i64,
+    pub polarities: Vec<Polarity>,
+}
+
+impl Build {
+    pub fn slots_of(&self, kind: SlotKind) -> impl Iterator<Item = &ResolvedSlot> {
+        self.slots.iter().filter(move |slot| slot.kind == kind)
+    }
+
+    /// Mod capacity of the build, arcanes and mods missing from the catalog are left out
+    pub fn capacity(&self, catalog: &Catalog) -> CapacityReport {
+        let rank = ItemMastery::new(&self.item_type, self.xp, catalog).rank;
+        let mods: Vec<ModSlot> = self
+            .slots
+            .iter()
+            .filter(|slot| slot.kind != SlotKind::Arcane)
+            .filter_map(|slot| {
+                Some(ModSlot {
+                    index: slot.index,
+                    name: slot.name.clone().unwrap_or_else(|| slot.item_type.clone()),
+                    base_drain: slot.base_drain?,
+                    rank: slot.rank,
+                    polarity: slot.polarity,
+                    bonus: matches!(slot.kind, SlotKind::Aura | SlotKind::Stance),
+                })
+            })
+            .collect();
+
+        polarity::calculate_capacity(
+            &mods,
+            &self.polarities,
+            rank,
+            self.features & FEATURE_DOUBLE_CAPACITY != 0,
+        )
+    }
+
+    /// e.g. "Trinity Prime / Avenging Angel: aura Growing Power (5/5), mods A (10/10), B (5/5)"
+    pub fn summary(&self) -> String {
+        let item = self.item_name.as_deref().unwrap_or(&self.item_type);
+        let mut summary = match &self.config_name {
+            Some(name) => format!("{} / {}", item, name),
+            None => format!("{} / Config {}", item, self.config_index + 1),
+        };
+
+        let groups = [
+            (SlotKind::Aura, "aura"),
+            (SlotKind::Stance, "stance"),
+            (SlotKind::Exilus, "exilus"),
+            (SlotKind::Mod, "mods"),
+            (SlotKind::Arcane, "arcanes"),
+        ];
+        let parts: Vec<String> = groups
+            .iter()
+            .filter_map(|(kind, label)| {
+                let slots: Vec<String> = self.slots_of(*kind).map(ResolvedSlot::describe).collect();
+                (!slots.is_empty()).then(|| format!("{} {}", label, slots.join(", ")))
+            })
+            .collect();
+        if !parts.is_empty() {
+            summary.push_str(": ");
+            summary.push_str(&parts.join(", "));
+        }
+        summary
+    }
+}
+
+/// Resolves config slots against `Upgrades`, `RawUpgrades` and the catalog.
+pub struct LoadoutResolver<'a> {
+    upgrades: HashMap<&'a str, &'a Upgrade>,
+    /// Unranked mods owned at least once, one copy can be equipped in any number of configs
+    raw_upgrades: HashSet<&'a str>,
+    catalog: &'a Catalog,
+}
+
+impl<'a> LoadoutResolver<'a> {
+    pub fn new(inventory: &'a Inventory, catalog: &'a Catalog) -> LoadoutResolver<'a> {
+        LoadoutResolver {
+            upgrades: inventory
+                .upgrades
+                .iter()
+                .map(|upgrade| (upgrade.item_id.oid.as_str(), upgrade))
+                .collect(),
+            raw_upgrades: inventory
+                .raw_upgrades
+                .iter()
+                .filter(|upgrade| upgrade.item_count > 0)
+                .map(|upgrade| upgrade.item_type.as_str())
+                .collect(),
+            catalog,
+        }
+    }
+
+    /// Builds for every config of every equipment item, skipping configs with no mods
+    pub fn builds(&self, inventory: &Inventory) -> Vec<Build> {
+        let mut builds = Vec::new();
+
+        macro_rules! equipment {
+            ($list:expr, $section:expr) => {
+                for item in &$list {
+                    for (index, wrapper) in item.configs.iter().enumerate() {
+                        let Some(config) = wrapper.config() else {
+                            continue;
+                        };
+                        let mut build = self.resolve(
+                            config,
+                            $section,
+                            &item.item_type,
+                            &item.item_id.oid,
+                            index,
+                        );
+                        build.xp = item.xp.unwrap_or(0);
+                        build.features = item.features.unwrap_or(0);
+                        build.polarities = item.polarity.clone().unwrap_or_default();
+                        if !build.slots.is_empty() || !build.missing.is_empty() {
+                            builds.push(build);
+                        }
+                    }
+                }
+            };
+        }
+
+        equipment!(inventory.suits, InventorySection::Suits);
+        equipment!(inventory.long_guns, InventorySection::LongGuns);
+        equipment!(inventory.pistols, InventorySection::Pistols);
+        equipment!(inventory.melee, InventorySection::Melee);
+        equipment!(inventory.space_suits, InventorySection::SpaceSuits);
+        equipment!(inventory.space_guns, InventorySection::SpaceGuns);
+        equipment!(inventory.space_melee, InventorySection::SpaceMelee);
+
+        builds
+    }
+
+    pub fn resolve(
+        &self,
+        config: &Config,
+        section: InventorySection,
+        item_type: &str,
+        item_id: &str,
+        config_index: usize,
+    ) -> Build {
+        let mut build = Build {
+            section,
+            item_id: item_id.to_string(),
+            item_type: item_type.to_string(),
+            item_name: self
+                .catalog
+                .get(item_type)
+                .map(|item| item.name().to_string()),
+            config_index,
+            config_name: config.name.clone(),
+            slots: Vec::new(),
+            missing: Vec::new(),
+            xp: 0,
+            features: 0,
+            polarities: Vec::new(),
+        };
+
+        for (index, slot) in config.slots().enumerate() {
+            let (item_type, rank, source) = match slot {
+                ConfigSlot::Empty => continue,
+                ConfigSlot::Path(path) => {
+                    if !self.raw_upgrades.contains(path) {
+                        build.missing.push(path.to_string());
+                        continue;
+                    }
+                    (path, 0, SlotSource::RawUpgrade)
+                }
+                ConfigSlot::ItemId(id) => {
+                    let Some(upgrade) = self.upgrades.get(id) else {
+                        build.missing.push(id.to_string());
+                        continue;
+                    };
+                    let rank = match &upgrade.upgrade_fingerprint {
+                        UpgradeFingerprint::ClassicObj(fingerprint) => fingerprint.lvl,
+                        UpgradeFingerprint::RivenMod(fingerprint) => fingerprint.lvl.unwrap_or(0),
+                        _ => 0,
+                    };
+                    let source = SlotSource::Upgrade {
+                        item_id: id.to_string(),
+                    };
+                    (upgrade.item_type.as_str(), rank, source)
+                }
+            };
+
+            let item = self.catalog.get(item_type);
+            let name = match item {
+                Some(item) => Some(item.name().to_string()),
+                None if item_type.contains("/Randomized/") => Some("Riven Mod".to_string()),
+                None => None,
+            };
+            let max_rank = match item {
+                Some(CatalogItem::Mod(item)) => item.fusion_limit,
+                Some(CatalogItem::Arcane(item)) => item
+                    .level_stats
+                    .len()
+                    .checked_sub(1)
+                    .map(|rank| rank as i64),
+                _ => None,
+            };
+
+            build.slots.push(ResolvedSlot {
+                index,
+                kind: SlotKind::for_slot(section, index, Some(item_type)),
+                item_type: item_type.to_string(),
+                name,
+                rank,
+                max_rank,
+                base_drain: match item {
+                    Some(CatalogItem::Mod(item)) => item.base_drain,
+                    _ => None,
+                },
+                polarity: match item {
+                    Some(CatalogItem::Mod(item)) => {
+                        item.polarity.as_deref().and_then(PolarityKind::from_name)
+                    }
+                    _ => None,
+                },
+                source,
+            });
+        }
+
+        build
+    }
+}
+
+/// Every modded loadout in the inventory
+pub fn resolve_builds(inventory: &Inventory, catalog: &Catalog) -> Vec<Build> {
+    LoadoutResolver::new(inventory, catalog).builds(inventory)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::tests::load_test_inventory;
+    use crate::itemdata::{mods, warframe};
+
+    #[test]
+    fn test_resolve_builds() {
+        let catalog: Catalog = [
+            CatalogItem::Warframe(warframe::Warframe {
+                name: "Trinity Prime".to_string(),
+                unique_name: "/Lotus/Powersuits/Trinity/TrinityPrime".to_string(),
+                ..Default::default()
+            }),
+            CatalogItem::Mod(mods::Mod {
+                name: "Physique".to_string(),
+                unique_name: "/Lotus/Upgrades/Mods/Aura/PlayerHealthAuraMod".to_string(),
+                fusion_limit: Some(5),
+                base_drain: Some(-2),
+                polarity: Some("madurai".to_string()),
+                ..Default::default()
+            }),
+            CatalogItem::Mod(mods::Mod {
+                name: "Nira's Anguish".to_string(),
+                unique_name: "/Lotus/Upgrades/Mods/Sets/Nira/NiraExilusMod".to_string(),
+                fusion_limit: Some(3),
+                base_drain: Some(4),
+                polarity: Some("naramon".to_string()),
+                ..Default::default()
+            }),
+        ]
+        .into_iter()
+        .collect();
+
+        let inventory = load_test_inventory();
+        let builds = resolve_builds(&inventory, &catalog);
+
+        let build = builds
+            .iter()
+            .find(|build| build.config_name.as_deref() == Some("spread out"))
+            .unwrap();
+        assert_eq!(build.item_name.as_deref(), Some("Trinity Prime"));
+        assert_eq!(build.slots.len(), 12);
+        assert_eq!(build.slots_of(SlotKind::Mod).count(), 8);
+        assert_eq!(build.slots_of(SlotKind::Arcane).count(), 2);
+
+        let aura = build.slots_of(SlotKind::Aura).next().unwrap();
+        assert_eq!(aura.name.as_deref(), Some("Physique"));
+        assert!(matches!(aura.source, SlotSource::Upgrade { .. }));
+
+        let exilus = build.slots_of(SlotKind::Exilus).next().unwrap();
+        assert_eq!(exilus.source, SlotSource::RawUpgrade);
+        assert_eq!(exilus.describe(), "Nira's Anguish (0/3)");
+
+        assert!(
+            build
+                .summary()
+                .starts_with("Trinity Prime / spread out: aura Physique")
+        );
+
+        // Trinity Prime is rank 30 with a reactor and a single Madurai slot
+        let capacity = build.capacity(&catalog);
+        assert_eq!(capacity.base_capacity, 60);
+        assert_eq!(capacity.slots.len(), 2);
+        assert_eq!(capacity.used, 4);
+        assert_eq!(capacity.bonus, 7);
+        assert_eq!(capacity.remaining, 63);
+
+        let melee = builds
+            .iter()
+            .find(|build| build.section == InventorySection::Melee)
+            .unwrap();
+        assert!(melee.slots_of(SlotKind::Stance).count() <= 1);
+    }
+
+    #[test]
+    fn test_missing_raw_upgrade() {
+        let nira = "/Lotus/Upgrades/Mods/Sets/Nira/NiraExilusMod";
+        let mut inventory = load_test_inventory();
+        inventory
+            .raw_upgrades
+            .retain(|upgrade| upgrade.item_type != nira);
+        let builds = resolve_builds(&inventory, &Catalog::default());
+
+        let build = builds
+            .iter()
+            .find(|build| build.config_name.as_deref() == Some("spread out"))
+            .unwrap();
+        assert_eq!(build.missing, vec![nira.to_string()]);
+        assert_eq!(build.slots.len(), 11);
+        assert_eq!(build.slots_of(SlotKind::Exilus).count(), 0);
+    }
+
+    #[test]
+    fn test_slot_kind() {
+        assert_eq!(
+            SlotKind::for_slot(InventorySection::Suits, 8, None),
+            SlotKind::Aura
+        );
+        assert_eq!(
+            SlotKind::for_slot(InventorySection::LongGuns, 8, None),
+            SlotKind::Exilus
+        );
+        assert_eq!(
+            SlotKind::for_slot(
+                InventorySection::Melee,
+                8,
+                Some("/Lotus/Weapons/Tenno/Melee/MeleeTrees/CrimsonDervishMeleeTree")
+            ),
+            SlotKind::Stance
+        );
+    }
+}
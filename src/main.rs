@@ -1,4 +1,5 @@
 use std::env;
+use std::sync::Arc;
 use tokio::process::Command;
 use tokio::signal;
 
@@ -15,6 +16,30 @@ async fn main() {
     // Format: wf-info-2 -- /path/to/warframe args...
     let warframe_cmd = if args.len() > 2 && args[1] == "--" {
         Some(&args[2..])
+    } else if args.len() > 1 && args[1] == "report" {
+        if let Err(e) = cli::run_report(&args[2..]) {
+            eprintln!("Error: {:#}", e);
+            std::process::exit(1);
+        }
+        std::process::exit(0);
+    } else if args.len() > 1 && args[1] == "wishlist" {
+        if let Err(e) = cli::run_wishlist(&args[2..]) {
+            eprintln!("Error: {:#}", e);
+            std::process::exit(1);
+        }
+        std::process::exit(0);
+    } else if args.len() > 1 && args[1] == "offline" {
+        if let Err(e) = cli::run_offline(&args[2..]) {
+            eprintln!("Error: {:#}", e);
+            std::process::exit(1);
+        }
+        std::process::exit(0);
+    } else if args.len() > 1 && args[1] == "export" {
+        if let Err(e) = cli::run_export(&args[2..]) {
+            eprintln!("Error: {:#}", e);
+            std::process::exit(1);
+        }
+        std::process::exit(0);
     } else if args.len() > 1 && args[1] == "--help" {
         println!("Usage:");
         println!(
@@ -25,6 +50,26 @@ async fn main() {
             "  {}                                   Monitor existing Warframe process",
             args[0]
         );
+        println!(
+            "  {} report <name> [--json]            Print a report from the last saved inventory",
+            args[0]
+        );
+        println!(
+            "                                        Reports: {}",
+            cli::REPORTS.join(", ")
+        );
+        println!(
+            "  {} wishlist add|remove|list [item]   Edit the items tracked by the wishlist report",
+            args[0]
+        );
+        println!(
+            "  {} offline <file> [name|all]         Run reports on a saved inventory.json or lastData.dat",
+            args[0]
+        );
+        println!(
+            "  {} export <file.db>                  Write the inventory and catalog to SQLite",
+            args[0]
+        );
         println!();
         println!("Example:");
         println!(
@@ -85,13 +130,28 @@ async fn main() {
         None
     };
 
+    // Loaded once for the watcher and the foundry scheduler,
+    // names fall back to item paths without it
+    let catalog = Arc::new(
+        itemdata::catalog::find_items_dir()
+            .and_then(|dir| itemdata::catalog::Catalog::load_from_dir(&dir).ok())
+            .unwrap_or_default(),
+    );
+
     // Start watching the log file
+    let watcher_catalog = catalog.clone();
     let log_watcher = tokio::spawn(async move {
-        if let Err(e) = watcher::observe_warframe_activity(wf_config).await {
+        if let Err(e) = watcher::observe_warframe_activity(wf_config, watcher_catalog).await {
             log::error!("Error watching file: {}", e);
         }
     });
 
+    // Notify when foundry builds finish
+    tokio::spawn(foundry::run_scheduler(
+        catalog,
+        foundry::Notifier::from_env(),
+    ));
+
     // Wait for Ctrl+C
     tokio::select! {
         _ = signal::ctrl_c() => {
@@ -0,0 +1,272 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Serialize};
+
+use crate::inventory::Inventory;
+use crate::itemdata::catalog::{Catalog, CatalogItem, ItemCategory};
+
+/// Rank a freshly built item caps at, without Kuva/Tenet style extra ranks
+pub const DEFAULT_MAX_RANK: i64 = 30;
+
+/// Affinity curve an item levels on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MasteryClass {
+    /// Warframes, archwings, necramechs and companions
+    Frame,
+    /// Everything else that levels: primaries, secondaries, melee, arch-guns, ...
+    Weapon,
+}
+
+impl MasteryClass {
+    pub fn from_category(category: ItemCategory) -> MasteryClass {
+        match category {
+            ItemCategory::Warframe
+            | ItemCategory::Archwing
+            | ItemCategory::Pet
+            | ItemCategory::Sentinel => MasteryClass::Frame,
+            _ => MasteryClass::Weapon,
+        }
+    }
+
+    /// Best guess for items missing from the catalog, based on their path
+    pub fn from_item_type(item_type: &str) -> MasteryClass {
+        let frame_paths = [
+            "/Powersuits/",
+            "/Types/Sentinels/SentinelPowersuits/",
+            "/Types/Game/KubrowPet/",
+            "/Types/Friendly/Pets/",
+        ];
+        if frame_paths.iter().any(|p| item_type.contains(p)) {
+            MasteryClass::Frame
+        } else {
+            MasteryClass::Weapon
+        }
+    }
+
+    /// Affinity needed for rank `r` is `xp_factor * r^2`
+    pub fn xp_factor(&self) -> i64 {
+        match self {
+            MasteryClass::Frame => 1000,
+            MasteryClass::Weapon => 500,
+        }
+    }
+
+    pub fn points_per_rank(&self) -> i64 {
+        match self {
+            MasteryClass::Frame => 200,
+            MasteryClass::Weapon => 100,
+        }
+    }
+}
+
+/// Total affinity needed to reach `rank`
+pub fn xp_for_rank(rank: i64, class: MasteryClass) -> i64 {
+    class.xp_factor() * rank * rank
+}
+
+/// Rank reached with `xp` affinity, capped at `max_rank`
+pub fn rank_for_xp(xp: i64, class: MasteryClass, max_rank: i64) -> i64 {
+    let mut rank = ((xp.max(0) as f64 / class.xp_factor() as f64).sqrt()) as i64;
+    // Guard against float rounding right at a rank boundary
+    while xp_for_rank(rank + 1, class) <= xp {
+        rank += 1;
+    }
+    while rank > 0 && xp_for_rank(rank, class) > xp {
+        rank -= 1;
+    }
+    rank.min(max_rank)
+}
+
+/// Mastery contribution of one levelled item.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ItemMastery {
+    pub item_type: String,
+    /// Catalog name, `None` when the item is not in the catalog
+    pub name: Option<String>,
+    pub category: Option<ItemCategory>,
+    pub class: MasteryClass,
+    pub xp: i64,
+    pub rank: i64,
+    pub max_rank: i64,
+    pub mastery_points: i64,
+    pub max_mastery_points: i64,
+}
+
+impl ItemMastery {
+    pub fn new(item_type: &str, xp: i64, catalog: &Catalog) -> ItemMastery {
+        let item = catalog.get(item_type);
+        let class = item
+            .map(|item| MasteryClass::from_category(item.category()))
+            .unwrap_or_else(|| MasteryClass::from_item_type(item_type));
+        let max_rank = item.map(CatalogItem::max_rank).unwrap_or(DEFAULT_MAX_RANK);
+        let rank = rank_for_xp(xp, class, max_rank);
+
+        ItemMastery {
+            item_type: item_type.to_string(),
+            name: item.map(|item| item.name().to_string()),
+            category: item.map(CatalogItem::category),
+            class,
+            xp,
+            rank,
+            max_rank,
+            mastery_points: rank * class.points_per_rank(),
+            max_mastery_points: max_rank * class.points_per_rank(),
+        }
+    }
+
+    pub fn is_maxed(&self) -> bool {
+        self.rank >= self.max_rank
+    }
+}
+
+/// Masterable catalog item that has never been levelled.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UnmasteredItem {
+    pub unique_name: String,
+    pub name: String,
+    pub mastery_req: Option<i64>,
+    /// Mastery points the item would give at max rank
+    pub mastery_points: i64,
+}
+
+/// Account mastery computed from `XPInfo`.
+/// Only item mastery is counted, star chart and intrinsics are not part of the inventory.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MasteryReport {
+    pub items: Vec<ItemMastery>,
+    pub mastery_points: i64,
+    /// Levelled items still below max rank, by category
+    pub unmaxed: BTreeMap<ItemCategory, Vec<ItemMastery>>,
+    /// Masterable items never levelled, by category
+    pub never_levelled: BTreeMap<ItemCategory, Vec<UnmasteredItem>>,
+}
+
+impl MasteryReport {
+    pub fn build(inventory: &Inventory, catalog: &Catalog) -> MasteryReport {
+        let mut report = MasteryReport::default();
+
+        // XPInfo may list an item more than once after account merges, keep the best
+        let mut best_xp: HashMap<&str, i64> = HashMap::new();
+        for info in &inventory.xp_info {
+            let xp = best_xp.entry(info.item_type.as_str()).or_default();
+            *xp = (*xp).max(info.xp);
+        }
+
+        for (item_type, xp) in &best_xp {
+            let mastery = ItemMastery::new(item_type, *xp, catalog);
+            report.mastery_points += mastery.mastery_points;
+            if let Some(category) = mastery.category
+                && !mastery.is_maxed()
+            {
+                report
+                    .unmaxed
+                    .entry(category)
+                    .or_default()
+                    .push(mastery.clone());
+            }
+            report.items.push(mastery);
+        }
+
+        for item in catalog.iter().filter(|item| item.masterable()) {
+            if best_xp.contains_key(item.unique_name()) {
+                continue;
+            }
+            let class = MasteryClass::from_category(item.category());
+            report
+                .never_levelled
+                .entry(item.category())
+                .or_default()
+                .push(UnmasteredItem {
+                    unique_name: item.unique_name().to_string(),
+                    name: item.name().to_string(),
+                    mastery_req: item.mastery_req(),
+                    mastery_points: item.max_rank() * class.points_per_rank(),
+                });
+        }
+
+        report.items.sort_by(|a, b| a.item_type.cmp(&b.item_type));
+        for items in report.unmaxed.values_mut() {
+            items.sort_by(|a, b| a.name.cmp(&b.name));
+        }
+        for items in report.never_levelled.values_mut() {
+            items.sort_by(|a, b| a.name.cmp(&b.name));
+        }
+
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::tests::load_test_inventory;
+    use crate::itemdata::{primary, warframe};
+
+    #[test]
+    fn test_rank_for_xp() {
+        assert_eq!(rank_for_xp(0, MasteryClass::Frame, 30), 0);
+        assert_eq!(rank_for_xp(999, MasteryClass::Frame, 30), 0);
+        assert_eq!(rank_for_xp(1000, MasteryClass::Frame, 30), 1);
+        assert_eq!(rank_for_xp(899_999, MasteryClass::Frame, 30), 29);
+        assert_eq!(rank_for_xp(900_000, MasteryClass::Frame, 30), 30);
+        assert_eq!(rank_for_xp(3_106_125, MasteryClass::Frame, 30), 30);
+        assert_eq!(rank_for_xp(450_000, MasteryClass::Weapon, 30), 30);
+        assert_eq!(rank_for_xp(578_000, MasteryClass::Weapon, 40), 34);
+        assert_eq!(rank_for_xp(800_000, MasteryClass::Weapon, 40), 40);
+        assert_eq!(xp_for_rank(40, MasteryClass::Weapon), 800_000);
+    }
+
+    #[test]
+    fn test_mastery_report() {
+        let catalog: Catalog = [
+            CatalogItem::Warframe(warframe::Warframe {
+                name: "Mag".to_string(),
+                unique_name: "/Lotus/Powersuits/Mag/Mag".to_string(),
+                masterable: true,
+                ..Default::default()
+            }),
+            CatalogItem::Primary(primary::Primary {
+                name: "Kuva Zarr".to_string(),
+                unique_name: "/Lotus/Weapons/Grineer/KuvaLich/LongGuns/Zarr/KuvaZarr".to_string(),
+                masterable: true,
+                max_level_cap: Some(40),
+                ..Default::default()
+            }),
+            CatalogItem::Primary(primary::Primary {
+                name: "Never Used".to_string(),
+                unique_name: "/Lotus/Weapons/Test/NeverUsed".to_string(),
+                masterable: true,
+                mastery_req: 5,
+                ..Default::default()
+            }),
+        ]
+        .into_iter()
+        .collect();
+
+        let report = MasteryReport::build(&load_test_inventory(), &catalog);
+
+        let mag = report
+            .items
+            .iter()
+            .find(|item| item.item_type == "/Lotus/Powersuits/Mag/Mag")
+            .unwrap();
+        assert_eq!(mag.rank, 30);
+        assert_eq!(mag.mastery_points, 6000);
+
+        // 450k affinity is rank 30 of 40 on a Kuva weapon
+        let zarr = &report.unmaxed[&ItemCategory::Primary][0];
+        assert_eq!(zarr.name.as_deref(), Some("Kuva Zarr"));
+        assert_eq!(zarr.rank, 30);
+        assert_eq!(zarr.max_mastery_points, 4000);
+
+        let never = &report.never_levelled[&ItemCategory::Primary];
+        assert_eq!(never.len(), 1);
+        assert_eq!(never[0].name, "Never Used");
+        assert_eq!(never[0].mastery_points, 3000);
+
+        assert_eq!(
+            report.mastery_points,
+            report.items.iter().map(|i| i.mastery_points).sum::<i64>()
+        );
+    }
+}
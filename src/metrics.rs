@@ -0,0 +1,381 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::inventory::Inventory;
+use crate::itemdata::catalog::Catalog;
+use crate::mastery::MasteryReport;
+use crate::profile::ProfileData;
+use crate::storage;
+
+const FORMA: &str = "/Lotus/Types/Items/MiscItems/Forma";
+
+/// Account metrics recorded at one point in time, by metric name.
+/// Inventory and profile samples are recorded separately and carry different metrics.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MetricsSample {
+    pub at: DateTime<Utc>,
+    pub values: BTreeMap<String, f64>,
+}
+
+impl MetricsSample {
+    /// credits, platinum, mastery_rank, mastery_points, forma, forma_used, trades_remaining
+    /// and `standing.<tag>` for every syndicate
+    pub fn from_inventory(
+        inventory: &Inventory,
+        catalog: &Catalog,
+        at: DateTime<Utc>,
+    ) -> MetricsSample {
+        let mut values = BTreeMap::new();
+        values.insert("credits".to_string(), inventory.regular_credits as f64);
+        values.insert("platinum".to_string(), inventory.premium_credits as f64);
+        if let Some(rank) = inventory.player_level {
+            values.insert("mastery_rank".to_string(), rank as f64);
+        }
+        values.insert(
+            "mastery_points".to_string(),
+            MasteryReport::build(inventory, catalog).mastery_points as f64,
+        );
+        values.insert("forma".to_string(), inventory.misc_item_count(FORMA) as f64);
+        values.insert("forma_used".to_string(), forma_used(inventory) as f64);
+        if let Some(trades) = inventory.trades_remaining {
+            values.insert("trades_remaining".to_string(), trades as f64);
+        }
+        for affiliation in &inventory.affiliations {
+            values.insert(
+                format!("standing.{}", affiliation.tag.tag()),
+                affiliation.standing as f64,
+            );
+        }
+        MetricsSample { at, values }
+    }
+
+    /// mastery_rank, missions_completed and time_played_sec from the public profile
+    pub fn from_profile(profile: &ProfileData, at: DateTime<Utc>) -> MetricsSample {
+        let mut values = BTreeMap::new();
+        if let Some(level) = profile
+            .results
+            .first()
+            .and_then(|result| result.player_level)
+        {
+            values.insert("mastery_rank".to_string(), level);
+        }
+        if let Some(stats) = &profile.stats {
+            if let Some(missions) = stats.missions_completed {
+                values.insert("missions_completed".to_string(), missions);
+            }
+            if let Some(seconds) = stats.time_played_sec {
+                values.insert("time_played_sec".to_string(), seconds);
+            }
+        }
+        MetricsSample { at, values }
+    }
+}
+
+/// Forma applied to every warframe, weapon and archwing
+fn forma_used(inventory: &Inventory) -> i64 {
+    let polarized = inventory
+        .suits
+        .iter()
+        .map(|item| item.polarized)
+        .chain(inventory.long_guns.iter().map(|item| item.polarized))
+        .chain(inventory.pistols.iter().map(|item| item.polarized))
+        .chain(inventory.melee.iter().map(|item| item.polarized))
+        .chain(inventory.space_suits.iter().map(|item| item.polarized))
+        .chain(inventory.space_guns.iter().map(|item| item.polarized))
+        .chain(inventory.space_melee.iter().map(|item| item.polarized));
+    polarized.flatten().sum()
+}
+
+/// One value of one metric.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Point {
+    pub at: DateTime<Utc>,
+    pub value: f64,
+}
+
+/// How the points of one downsampling bucket are combined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Aggregation {
+    Last,
+    Mean,
+    Min,
+    Max,
+}
+
+/// One point per `bucket`, stamped with the start of the bucket.
+/// `points` must be sorted by time.
+pub fn downsample(
+    points: &[Point],
+    bucket: chrono::Duration,
+    aggregation: Aggregation,
+) -> Vec<Point> {
+    let width = bucket.num_milliseconds().max(1);
+    let mut buckets: BTreeMap<i64, Vec<f64>> = BTreeMap::new();
+    for point in points {
+        let start = point.at.timestamp_millis().div_euclid(width) * width;
+        buckets.entry(start).or_default().push(point.value);
+    }
+
+    buckets
+        .into_iter()
+        .filter_map(|(start, values)| {
+            let value = match aggregation {
+                Aggregation::Last => *values.last()?,
+                Aggregation::Mean => values.iter().sum::<f64>() / values.len() as f64,
+                Aggregation::Min => values.iter().copied().fold(f64::INFINITY, f64::min),
+                Aggregation::Max => values.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+            };
+            Some(Point {
+                at: DateTime::from_timestamp_millis(start)?,
+                value,
+            })
+        })
+        .collect()
+}
+
+/// One row per sample, one column per metric, empty cells where a sample lacks a metric
+pub fn to_csv(samples: &[MetricsSample]) -> String {
+    let metrics: BTreeSet<&str> = samples
+        .iter()
+        .flat_map(|sample| sample.values.keys().map(String::as_str))
+        .collect();
+
+    let mut out = String::from("timestamp");
+    for metric in &metrics {
+        out.push(',');
+        out.push_str(metric);
+    }
+    out.push('\n');
+
+    for sample in samples {
+        out.push_str(&sample.at.to_rfc3339());
+        for metric in &metrics {
+            out.push(',');
+            if let Some(value) = sample.values.get(*metric) {
+                out.push_str(&value.to_string());
+            }
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Append-only JSON lines file of `MetricsSample`s.
+#[derive(Debug, Clone)]
+pub struct MetricsStore {
+    path: PathBuf,
+}
+
+impl MetricsStore {
+    pub fn open(path: impl Into<PathBuf>) -> MetricsStore {
+        MetricsStore { path: path.into() }
+    }
+
+    /// `metrics.jsonl` in the app cache directory
+    pub fn open_default() -> anyhow::Result<MetricsStore> {
+        Ok(MetricsStore::open(
+            storage::app_cache_dir()?.join("metrics.jsonl"),
+        ))
+    }
+
+    pub fn append(&self, sample: &MetricsSample) -> anyhow::Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).context("Failed to create cache directory")?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .with_context(|| format!("Failed to open {}", self.path.display()))?;
+        let line = serde_json::to_string(sample).context("Failed to serialize metrics")?;
+        writeln!(file, "{}", line)
+            .with_context(|| format!("Failed to write {}", self.path.display()))
+    }
+
+    /// Samples between `from` and `to` inclusive, oldest first.
+    /// Lines that do not parse, e.g. one cut short by a crash, are skipped.
+    pub fn samples(
+        &self,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> anyhow::Result<Vec<MetricsSample>> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+        let raw = fs::read_to_string(&self.path)
+            .with_context(|| format!("Failed to read {}", self.path.display()))?;
+        let mut samples: Vec<MetricsSample> = raw
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .filter_map(|line| match serde_json::from_str(line) {
+                Ok(sample) => Some(sample),
+                Err(e) => {
+                    log::warn!("Skipping malformed metrics line: {}", e);
+                    None
+                }
+            })
+            .filter(|sample: &MetricsSample| {
+                from.is_none_or(|from| sample.at >= from) && to.is_none_or(|to| sample.at <= to)
+            })
+            .collect();
+        samples.sort_by_key(|sample| sample.at);
+        Ok(samples)
+    }
+
+    /// Values of one metric between `from` and `to`, oldest first
+    pub fn series(
+        &self,
+        metric: &str,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> anyhow::Result<Vec<Point>> {
+        Ok(self
+            .samples(from, to)?
+            .into_iter()
+            .filter_map(|sample| {
+                sample.values.get(metric).map(|value| Point {
+                    at: sample.at,
+                    value: *value,
+                })
+            })
+            .collect())
+    }
+}
+
+/// Records a freshly fetched inventory, logging instead of failing
+pub fn record_inventory(inventory: &Inventory, catalog: &Catalog) {
+    let sample = MetricsSample::from_inventory(inventory, catalog, Utc::now());
+    if let Err(e) = MetricsStore::open_default().and_then(|store| store.append(&sample)) {
+        log::warn!("Failed to record inventory metrics: {:#}", e);
+    }
+}
+
+/// Records a freshly fetched profile, logging instead of failing
+pub fn record_profile(profile: &ProfileData) {
+    let sample = MetricsSample::from_profile(profile, Utc::now());
+    if let Err(e) = MetricsStore::open_default().and_then(|store| store.append(&sample)) {
+        log::warn!("Failed to record profile metrics: {:#}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::tests::load_test_inventory;
+    use chrono::TimeZone;
+
+    fn day(day: u32, hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 1, day, hour, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_inventory_sample() {
+        let inventory = load_test_inventory();
+        let sample = MetricsSample::from_inventory(&inventory, &Catalog::default(), day(1, 0));
+        assert_eq!(sample.values["credits"], 8_419_328.0);
+        assert_eq!(sample.values["platinum"], 749.0);
+        assert_eq!(sample.values["mastery_rank"], 27.0);
+        assert_eq!(sample.values["forma"], 44.0);
+        assert_eq!(sample.values["trades_remaining"], 23.0);
+        assert!(sample.values["mastery_points"] > 0.0);
+        assert!(sample.values["forma_used"] > 0.0);
+        assert!(sample.values.keys().any(|key| key.starts_with("standing.")));
+    }
+
+    #[test]
+    fn test_store_and_queries() {
+        let path =
+            std::env::temp_dir().join(format!("wf-info-2-metrics-{}.jsonl", std::process::id()));
+        let store = MetricsStore::open(&path);
+        assert!(store.samples(None, None).unwrap().is_empty());
+
+        // Two logins a day over three days, credits growing by 1000 per login
+        let mut inventory = load_test_inventory();
+        inventory.regular_credits = 0;
+        for day_of_month in 1..=3 {
+            for hour in [10, 20] {
+                inventory.regular_credits += 1_000;
+                let sample = MetricsSample::from_inventory(
+                    &inventory,
+                    &Catalog::default(),
+                    day(day_of_month, hour),
+                );
+                store.append(&sample).unwrap();
+            }
+        }
+
+        let all = store.series("credits", None, None).unwrap();
+        let range = store
+            .series("credits", Some(day(2, 0)), Some(day(3, 10)))
+            .unwrap();
+        let samples = store.samples(None, None).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(all.len(), 6);
+        let values: Vec<f64> = range.iter().map(|point| point.value).collect();
+        assert_eq!(values, vec![3_000.0, 4_000.0, 5_000.0]);
+
+        let daily = downsample(&all, chrono::Duration::days(1), Aggregation::Last);
+        assert_eq!(
+            daily,
+            vec![
+                Point {
+                    at: day(1, 0),
+                    value: 2_000.0
+                },
+                Point {
+                    at: day(2, 0),
+                    value: 4_000.0
+                },
+                Point {
+                    at: day(3, 0),
+                    value: 6_000.0
+                },
+            ]
+        );
+        let mean = downsample(&all, chrono::Duration::days(1), Aggregation::Mean);
+        assert_eq!(mean[0].value, 1_500.0);
+        let max = downsample(&all, chrono::Duration::days(3), Aggregation::Max);
+        assert_eq!(max.len(), 1);
+        assert_eq!(max[0].value, 6_000.0);
+
+        let csv = to_csv(&samples);
+        let mut lines = csv.lines();
+        let header: Vec<&str> = lines.next().unwrap().split(',').collect();
+        assert_eq!(header[0], "timestamp");
+        let credits = header
+            .iter()
+            .position(|column| *column == "credits")
+            .unwrap();
+        let first: Vec<&str> = lines.next().unwrap().split(',').collect();
+        assert_eq!(first[0], "2026-01-01T10:00:00+00:00");
+        assert_eq!(first[credits], "1000");
+        assert_eq!(csv.lines().count(), 7);
+    }
+
+    #[test]
+    fn test_csv_missing_metrics() {
+        let samples = vec![
+            MetricsSample {
+                at: day(1, 0),
+                values: BTreeMap::from([("credits".to_string(), 10.0)]),
+            },
+            MetricsSample {
+                at: day(1, 1),
+                values: BTreeMap::from([("mastery_rank".to_string(), 27.0)]),
+            },
+        ];
+        assert_eq!(
+            to_csv(&samples),
+            "timestamp,credits,mastery_rank\n\
+             2026-01-01T00:00:00+00:00,10,\n\
+             2026-01-01T01:00:00+00:00,,27\n"
+        );
+    }
+}
@@ -0,0 +1,263 @@
+use serde::{Deserialize, Serialize};
+
+use crate::inventory::Inventory;
+use crate::itemdata::catalog::{Catalog, CatalogItem, ComponentInfo, ItemCategory};
+
+/// One tradable part of a prime set and how many of it the account holds.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PrimePart {
+    pub unique_name: String,
+    /// Full name, e.g. "Braton Prime Receiver"
+    pub name: String,
+    /// Copies needed to build the item once
+    pub required: i64,
+    /// Unbuilt blueprints in `Recipes`
+    pub blueprints: i64,
+    /// Built parts in `MiscItems`
+    pub built: i64,
+    /// Ducats Baro pays for one copy
+    pub ducats: Option<i64>,
+}
+
+impl PrimePart {
+    pub fn owned(&self) -> i64 {
+        self.blueprints + self.built
+    }
+
+    /// Copies still needed for one set
+    pub fn missing(&self) -> i64 {
+        (self.required - self.owned()).max(0)
+    }
+
+    /// Copies beyond what one set needs
+    pub fn duplicates(&self) -> i64 {
+        (self.owned() - self.required).max(0)
+    }
+
+    pub fn duplicate_ducats(&self) -> i64 {
+        self.duplicates() * self.ducats.unwrap_or(0)
+    }
+}
+
+/// Ownership of the parts of one prime item.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PrimeSet {
+    pub unique_name: String,
+    pub name: String,
+    pub category: ItemCategory,
+    /// The item itself is already in the arsenal
+    pub owned_item: bool,
+    pub parts: Vec<PrimePart>,
+    /// Sets that can be assembled from the owned parts
+    pub complete_sets: i64,
+}
+
+impl PrimeSet {
+    pub fn new(item: &CatalogItem, inventory: &Inventory) -> PrimeSet {
+        let parts: Vec<PrimePart> = item
+            .components()
+            .into_iter()
+            .filter(|component| component.tradable)
+            .map(|component| prime_part(item, component, inventory))
+            .collect();
+
+        let complete_sets = parts
+            .iter()
+            .map(|part| part.owned() / part.required.max(1))
+            .min()
+            .unwrap_or(0);
+
+        PrimeSet {
+            unique_name: item.unique_name().to_string(),
+            name: item.name().to_string(),
+            category: item.category(),
+            owned_item: inventory.owns_equipment(item.unique_name()),
+            parts,
+            complete_sets,
+        }
+    }
+
+    pub fn owned_parts(&self) -> usize {
+        self.parts.iter().filter(|part| part.missing() == 0).count()
+    }
+
+    pub fn missing_parts(&self) -> impl Iterator<Item = &PrimePart> {
+        self.parts.iter().filter(|part| part.missing() > 0)
+    }
+
+    pub fn duplicate_ducats(&self) -> i64 {
+        self.parts.iter().map(PrimePart::duplicate_ducats).sum()
+    }
+}
+
+fn prime_part(item: &CatalogItem, component: ComponentInfo, inventory: &Inventory) -> PrimePart {
+    let path = &component.unique_name;
+
+    // Warframe parts are `XChassisComponent` built from `XChassisBlueprint`,
+    // weapon parts are `XReceiver` built from `XReceiverBlueprint`.
+    // The main blueprint is only ever a blueprint.
+    let (blueprints, built) = if path.ends_with("Blueprint") {
+        (inventory.recipe_count(path), 0)
+    } else {
+        let blueprint = match path.strip_suffix("Component") {
+            Some(stem) => format!("{stem}Blueprint"),
+            None => format!("{path}Blueprint"),
+        };
+        (
+            inventory.recipe_count(&blueprint),
+            inventory.misc_item_count(path),
+        )
+    };
+
+    PrimePart {
+        name: format!("{} {}", item.name(), component.name),
+        unique_name: component.unique_name,
+        required: component.item_count,
+        blueprints,
+        built,
+        ducats: component.ducats,
+    }
+}
+
+/// Prime set completion and Baro ducat value across the whole catalog.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PrimeReport {
+    /// Every prime item with tradable parts, sorted by name
+    pub sets: Vec<PrimeSet>,
+    /// Ducat value of all parts beyond one set each
+    pub duplicate_ducats: i64,
+}
+
+impl PrimeReport {
+    pub fn build(inventory: &Inventory, catalog: &Catalog) -> PrimeReport {
+        let mut sets: Vec<PrimeSet> = catalog
+            .iter()
+            .filter(|item| item.is_prime())
+            .map(|item| PrimeSet::new(item, inventory))
+            .filter(|set| !set.parts.is_empty())
+            .collect();
+        sets.sort_by(|a, b| a.name.cmp(&b.name));
+
+        PrimeReport {
+            duplicate_ducats: sets.iter().map(PrimeSet::duplicate_ducats).sum(),
+            sets,
+        }
+    }
+
+    /// Sets with at least one part owned, the rest of the catalog is usually noise
+    pub fn started(&self) -> impl Iterator<Item = &PrimeSet> {
+        self.sets
+            .iter()
+            .filter(|set| set.parts.iter().any(|part| part.owned() > 0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::tests::load_test_inventory;
+    use crate::itemdata::{primary, warframe};
+
+    fn component(unique_name: &str, name: &str, ducats: i64) -> primary::Component {
+        primary::Component {
+            unique_name: unique_name.to_string(),
+            name: name.to_string(),
+            item_count: 1,
+            tradable: true,
+            ducats: Some(ducats),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_prime_report() {
+        let catalog: Catalog = [
+            CatalogItem::Primary(primary::Primary {
+                name: "Braton Prime".to_string(),
+                unique_name: "/Lotus/Weapons/Tenno/Rifle/BratonPrime".to_string(),
+                is_prime: true,
+                components: vec![
+                    component(
+                        "/Lotus/Types/Recipes/Weapons/BratonPrimeBlueprint",
+                        "Blueprint",
+                        15,
+                    ),
+                    component(
+                        "/Lotus/Types/Recipes/Weapons/WeaponParts/BratonPrimeBarrel",
+                        "Barrel",
+                        45,
+                    ),
+                    component(
+                        "/Lotus/Types/Recipes/Weapons/WeaponParts/BratonPrimeReceiver",
+                        "Receiver",
+                        15,
+                    ),
+                    component(
+                        "/Lotus/Types/Recipes/Weapons/WeaponParts/BratonPrimeStock",
+                        "Stock",
+                        15,
+                    ),
+                    primary::Component {
+                        unique_name: "/Lotus/Types/Items/MiscItems/OrokinCell".to_string(),
+                        name: "Orokin Cell".to_string(),
+                        item_count: 10,
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            }),
+            CatalogItem::Warframe(warframe::Warframe {
+                name: "Zephyr Prime".to_string(),
+                unique_name: "/Lotus/Powersuits/Tengu/ZephyrPrime".to_string(),
+                is_prime: true,
+                components: vec![
+                    warframe::Component {
+                        unique_name: "/Lotus/Types/Recipes/WarframeRecipes/ZephyrPrimeBlueprint"
+                            .to_string(),
+                        name: "Blueprint".to_string(),
+                        item_count: 1,
+                        tradable: true,
+                        ducats: Some(45),
+                        ..Default::default()
+                    },
+                    warframe::Component {
+                        unique_name:
+                            "/Lotus/Types/Recipes/WarframeRecipes/ZephyrPrimeChassisComponent"
+                                .to_string(),
+                        name: "Chassis".to_string(),
+                        item_count: 1,
+                        tradable: true,
+                        ducats: Some(65),
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            }),
+        ]
+        .into_iter()
+        .collect();
+
+        let report = PrimeReport::build(&load_test_inventory(), &catalog);
+        assert_eq!(report.sets.len(), 2);
+
+        let braton = &report.sets[0];
+        assert_eq!(braton.name, "Braton Prime");
+        assert_eq!(braton.parts.len(), 4);
+        assert_eq!(braton.complete_sets, 1);
+        assert_eq!(braton.owned_parts(), 4);
+        assert_eq!(braton.parts[0].blueprints, 2);
+        assert_eq!(braton.parts[1].built, 1);
+        // The second blueprint is the only duplicate
+        assert_eq!(braton.duplicate_ducats(), 15);
+
+        let zephyr = &report.sets[1];
+        assert!(zephyr.owned_item);
+        assert_eq!(zephyr.complete_sets, 0);
+        let missing: Vec<&str> = zephyr.missing_parts().map(|p| p.name.as_str()).collect();
+        assert_eq!(missing, vec!["Zephyr Prime Blueprint"]);
+        assert_eq!(zephyr.parts[1].blueprints, 1);
+
+        assert_eq!(report.duplicate_ducats, 15);
+        assert_eq!(report.started().count(), 2);
+    }
+}
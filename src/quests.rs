@@ -0,0 +1,301 @@
+use std::collections::HashSet;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::inventory::Inventory;
+use crate::inventory::quest::{QuestKey, QuestStage};
+use crate::itemdata::catalog::{Catalog, ItemCategory};
+
+/// Readable name from the key path when the catalog does not know it,
+/// e.g. "Vors Prize" for `.../VorsPrizeQuestKeyChain`
+pub fn quest_name_from_path(item_type: &str) -> String {
+    let key = item_type.rsplit('/').next().unwrap_or(item_type);
+    let key = key.strip_suffix("Item").unwrap_or(key);
+    let key = key.strip_suffix("KeyChain").unwrap_or(key);
+    let key = key.replace("Quest", "");
+
+    let mut name = String::new();
+    let mut previous: Option<char> = None;
+    for c in key.chars() {
+        if let Some(prev) = previous
+            && ((c.is_uppercase() && !prev.is_uppercase())
+                || (c.is_ascii_digit() != prev.is_ascii_digit()))
+        {
+            name.push(' ');
+        }
+        name.push(c);
+        previous = Some(c);
+    }
+    name
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum QuestStatus {
+    Completed,
+    InProgress,
+    NotStarted,
+}
+
+/// Where the player is on one quest stage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StageState {
+    Done,
+    /// Item received or mission played, objective not completed yet
+    Started,
+    /// Unlocked but nothing done on it yet
+    Unlocked,
+}
+
+impl StageState {
+    pub fn label(&self) -> &'static str {
+        match self {
+            StageState::Done => "done",
+            StageState::Started => "started",
+            StageState::Unlocked => "unlocked",
+        }
+    }
+}
+
+/// One quest joined with the catalog.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuestProgress {
+    pub item_type: String,
+    pub name: String,
+    pub status: QuestStatus,
+    /// Stages reached so far
+    pub stages: Vec<QuestStage>,
+    pub completion_date: Option<DateTime<Utc>>,
+}
+
+impl QuestProgress {
+    fn new(key: &QuestKey, catalog: &Catalog) -> QuestProgress {
+        let status = if key.completed {
+            QuestStatus::Completed
+        } else if key.is_started() {
+            QuestStatus::InProgress
+        } else {
+            QuestStatus::NotStarted
+        };
+        QuestProgress {
+            item_type: key.item_type.clone(),
+            name: quest_name(&key.item_type, catalog),
+            status,
+            stages: key.progress.clone(),
+            completion_date: key.completion_date(),
+        }
+    }
+
+    /// State of every recorded stage, from the stage flags and whether a later stage follows
+    pub fn stage_states(&self) -> Vec<StageState> {
+        let last = self.stages.len().saturating_sub(1);
+        self.stages
+            .iter()
+            .enumerate()
+            .map(|(index, stage)| {
+                if self.status == QuestStatus::Completed || index < last || stage.is_completed() {
+                    StageState::Done
+                } else if stage.is_started() {
+                    StageState::Started
+                } else {
+                    StageState::Unlocked
+                }
+            })
+            .collect()
+    }
+
+    /// 1-based stage the player is on, `None` unless in progress.
+    /// One past the recorded stages when the last one is done and the next is not unlocked yet.
+    pub fn current_stage(&self) -> Option<usize> {
+        if self.status != QuestStatus::InProgress {
+            return None;
+        }
+        let states = self.stage_states();
+        let done = states
+            .iter()
+            .position(|state| *state != StageState::Done)
+            .unwrap_or(states.len());
+        Some(done + 1)
+    }
+}
+
+fn quest_name(item_type: &str, catalog: &Catalog) -> String {
+    catalog
+        .get(item_type)
+        .map(|item| item.name().to_string())
+        .unwrap_or_else(|| quest_name_from_path(item_type))
+}
+
+/// Quests grouped by status, each group sorted by name.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuestReport {
+    pub completed: Vec<QuestProgress>,
+    pub in_progress: Vec<QuestProgress>,
+    /// Quest keys held but not started, plus quests from `Quests.json` never received
+    pub not_started: Vec<QuestProgress>,
+}
+
+impl QuestReport {
+    pub fn build(inventory: &Inventory, catalog: &Catalog) -> QuestReport {
+        let mut report = QuestReport::default();
+
+        for key in &inventory.quest_keys {
+            let quest = QuestProgress::new(key, catalog);
+            match quest.status {
+                QuestStatus::Completed => report.completed.push(quest),
+                QuestStatus::InProgress => report.in_progress.push(quest),
+                QuestStatus::NotStarted => report.not_started.push(quest),
+            }
+        }
+
+        let received: HashSet<&str> = inventory
+            .quest_keys
+            .iter()
+            .map(|key| key.item_type.as_str())
+            .collect();
+        report.not_started.extend(
+            catalog
+                .by_category(ItemCategory::Quest)
+                .map(|item| item.unique_name())
+                .filter(|unique_name| !received.contains(unique_name))
+                .map(|unique_name| QuestProgress {
+                    item_type: unique_name.to_string(),
+                    name: quest_name(unique_name, catalog),
+                    status: QuestStatus::NotStarted,
+                    stages: Vec::new(),
+                    completion_date: None,
+                }),
+        );
+
+        for quests in [
+            &mut report.completed,
+            &mut report.in_progress,
+            &mut report.not_started,
+        ] {
+            quests.sort_by(|a, b| a.name.cmp(&b.name));
+        }
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::tests::load_test_inventory;
+    use crate::itemdata::catalog::CatalogItem;
+    use crate::itemdata::quest::Quest;
+
+    fn quest(name: &str, unique_name: &str) -> CatalogItem {
+        CatalogItem::Quest(Quest {
+            name: name.to_string(),
+            unique_name: unique_name.to_string(),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_quest_name_from_path() {
+        assert_eq!(
+            quest_name_from_path("/Lotus/Types/Keys/VorsPrize/VorsPrizeQuestKeyChain"),
+            "Vors Prize"
+        );
+        assert_eq!(
+            quest_name_from_path("/Lotus/Types/Keys/1999Quest/1999QuestGoodEndKeyChain"),
+            "1999 Good End"
+        );
+        assert_eq!(
+            quest_name_from_path("/Lotus/Types/Keys/GolemQuest/GolemQuestKeyChainItem"),
+            "Golem"
+        );
+    }
+
+    #[test]
+    fn test_quest_report() {
+        let catalog: Catalog = [
+            quest(
+                "Vor's Prize",
+                "/Lotus/Types/Keys/VorsPrize/VorsPrizeQuestKeyChain",
+            ),
+            quest(
+                "The Second Dream",
+                "/Lotus/Types/Keys/SentientQuest/SentientQuestKeyChain",
+            ),
+            quest(
+                "Heart of Deimos",
+                "/Lotus/Types/Keys/DeimosQuest/DeimosQuestKeyChain",
+            ),
+        ]
+        .into_iter()
+        .collect();
+
+        let mut inventory = load_test_inventory();
+        // Drop the last stage of The Second Dream to get one quest in progress
+        let second_dream = inventory
+            .quest_keys
+            .iter_mut()
+            .find(|key| key.item_type.ends_with("SentientQuestKeyChain"))
+            .unwrap();
+        second_dream.completed = false;
+        second_dream.progress.pop();
+
+        let report = QuestReport::build(&inventory, &catalog);
+        assert_eq!(report.completed.len(), 40);
+        assert!(
+            report
+                .completed
+                .iter()
+                .any(|quest| quest.name == "Vor's Prize")
+        );
+
+        let dated = report
+            .completed
+            .iter()
+            .filter(|quest| quest.completion_date.is_some())
+            .count();
+        assert_eq!(dated, 10);
+
+        assert_eq!(report.in_progress.len(), 1);
+        assert_eq!(report.in_progress[0].name, "The Second Dream");
+        assert_eq!(report.in_progress[0].current_stage(), Some(7));
+        // Its mission flag is set
+        assert_eq!(
+            report.in_progress[0].stage_states().last(),
+            Some(&StageState::Started)
+        );
+
+        let not_started: Vec<&str> = report
+            .not_started
+            .iter()
+            .map(|quest| quest.name.as_str())
+            .collect();
+        assert_eq!(not_started, vec!["1999 Good End", "Heart of Deimos"]);
+    }
+
+    #[test]
+    fn test_stage_states() {
+        let stage = |c: i64, i: bool, m: bool| QuestStage { c, i, m, b: None };
+        let mut quest = QuestProgress {
+            item_type: "/Lotus/Types/Keys/VorsPrize/VorsPrizeQuestKeyChain".to_string(),
+            name: "Vor's Prize".to_string(),
+            status: QuestStatus::InProgress,
+            stages: vec![stage(0, false, false), stage(0, false, true)],
+            completion_date: None,
+        };
+        assert_eq!(
+            quest.stage_states(),
+            vec![StageState::Done, StageState::Started]
+        );
+        assert_eq!(quest.current_stage(), Some(2));
+
+        quest.stages[1] = stage(0, false, false);
+        assert_eq!(quest.stage_states()[1], StageState::Unlocked);
+
+        // Last stage finished, the next one is not unlocked yet
+        quest.stages[1] = stage(1, true, true);
+        assert_eq!(quest.stage_states()[1], StageState::Done);
+        assert_eq!(quest.current_stage(), Some(3));
+
+        quest.status = QuestStatus::Completed;
+        assert_eq!(quest.current_stage(), None);
+    }
+}
@@ -0,0 +1,403 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::inventory::Inventory;
+use crate::itemdata::catalog::{Catalog, CatalogItem};
+
+const RELIC_PATH_PREFIX: &str = "/Lotus/Types/Game/Projections/T";
+
+/// Relic tier, encoded as `T1`..`T5` in the item path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum RelicEra {
+    Lith,
+    Meso,
+    Neo,
+    Axi,
+    Requiem,
+}
+
+impl RelicEra {
+    pub fn from_tier(tier: char) -> Option<RelicEra> {
+        match tier {
+            '1' => Some(RelicEra::Lith),
+            '2' => Some(RelicEra::Meso),
+            '3' => Some(RelicEra::Neo),
+            '4' => Some(RelicEra::Axi),
+            '5' => Some(RelicEra::Requiem),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for RelicEra {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+/// Refinement level, encoded as a metal suffix in the item path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Refinement {
+    Intact,
+    Exceptional,
+    Flawless,
+    Radiant,
+}
+
+impl Refinement {
+    pub const ALL: [Refinement; 4] = [
+        Refinement::Intact,
+        Refinement::Exceptional,
+        Refinement::Flawless,
+        Refinement::Radiant,
+    ];
+
+    /// Suffix used in the item path, e.g. `Platinum` for Radiant
+    pub fn path_suffix(&self) -> &'static str {
+        match self {
+            Refinement::Intact => "Bronze",
+            Refinement::Exceptional => "Silver",
+            Refinement::Flawless => "Gold",
+            Refinement::Radiant => "Platinum",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Refinement> {
+        Refinement::ALL
+            .into_iter()
+            .find(|refinement| refinement.to_string() == name)
+    }
+}
+
+impl fmt::Display for Refinement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+/// Era, code and refinement parsed from a relic `ItemType`,
+/// e.g. `/Lotus/Types/Game/Projections/T4VoidProjectionLavosPrimeASilver`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RelicPath {
+    pub era: RelicEra,
+    /// Internal relic code, e.g. `LavosPrimeA`. Not the in-game name, that one comes from the catalog.
+    pub code: String,
+    pub refinement: Refinement,
+}
+
+impl RelicPath {
+    pub fn parse(item_type: &str) -> Option<RelicPath> {
+        let rest = item_type.strip_prefix(RELIC_PATH_PREFIX)?;
+        let mut chars = rest.chars();
+        let era = RelicEra::from_tier(chars.next()?)?;
+        let rest = chars.as_str().strip_prefix("VoidProjection")?;
+
+        Refinement::ALL.into_iter().find_map(|refinement| {
+            let code = rest.strip_suffix(refinement.path_suffix())?;
+            (!code.is_empty()).then(|| RelicPath {
+                era,
+                code: code.to_string(),
+                refinement,
+            })
+        })
+    }
+
+    /// Item path of the same relic at another refinement
+    pub fn item_type(&self, refinement: Refinement) -> String {
+        format!(
+            "{}{}VoidProjection{}{}",
+            RELIC_PATH_PREFIX,
+            self.era as u8 + 1,
+            self.code,
+            refinement.path_suffix()
+        )
+    }
+}
+
+/// Possible reward of a relic, with its chance at each refinement.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RelicReward {
+    /// e.g. "Harrow Prime Blueprint"
+    pub name: String,
+    /// Component path when known
+    pub unique_name: Option<String>,
+    /// Common, Uncommon or Rare
+    pub rarity: String,
+    /// Probability between 0 and 1 per refinement
+    pub chances: BTreeMap<Refinement, f64>,
+}
+
+/// Reward tables keyed by relic name, e.g. "Axi P8".
+pub type RewardTables = HashMap<String, Vec<RelicReward>>;
+
+/// Builds the reward table of every relic in the catalog.
+/// Current warframe-items data leaves `Relic::rewards` empty and lists the relics
+/// as drop locations of the prime components instead, so both are read.
+pub fn reward_tables(catalog: &Catalog) -> RewardTables {
+    let mut tables: HashMap<String, BTreeMap<String, RelicReward>> = HashMap::new();
+
+    let mut add = |relic: String,
+                   refinement: Refinement,
+                   name: &str,
+                   unique_name: Option<&str>,
+                   rarity: &str,
+                   chance: f64| {
+        let reward = tables
+            .entry(relic)
+            .or_default()
+            .entry(name.to_string())
+            .or_insert_with(|| RelicReward {
+                name: name.to_string(),
+                unique_name: None,
+                rarity: rarity.to_string(),
+                chances: BTreeMap::new(),
+            });
+        if reward.unique_name.is_none() {
+            reward.unique_name = unique_name.map(str::to_string);
+        }
+        reward.chances.insert(refinement, chance);
+    };
+
+    for item in catalog.iter() {
+        if let CatalogItem::Relic(relic) = item {
+            let Some((name, refinement)) = split_refinement(&relic.name) else {
+                continue;
+            };
+            for reward in &relic.rewards {
+                add(
+                    name.to_string(),
+                    refinement,
+                    &reward.item.name,
+                    Some(&reward.item.unique_name),
+                    &reward.rarity,
+                    reward.chance / 100.0,
+                );
+            }
+            continue;
+        }
+
+        for component in item.components() {
+            for drop in &component.drops {
+                let Some((relic, refinement)) = parse_drop_location(&drop.location) else {
+                    continue;
+                };
+                add(
+                    relic.to_string(),
+                    refinement,
+                    &drop.name,
+                    Some(&component.unique_name),
+                    &drop.rarity,
+                    drop.chance,
+                );
+            }
+        }
+    }
+
+    tables
+        .into_iter()
+        .map(|(relic, rewards)| {
+            let mut rewards: Vec<RelicReward> = rewards.into_values().collect();
+            rewards.sort_by(|a, b| {
+                rarity_order(&a.rarity)
+                    .cmp(&rarity_order(&b.rarity))
+                    .then_with(|| a.name.cmp(&b.name))
+            });
+            (relic, rewards)
+        })
+        .collect()
+}
+
+/// "Axi P8 Exceptional" -> ("Axi P8", Exceptional)
+fn split_refinement(catalog_name: &str) -> Option<(&str, Refinement)> {
+    let (name, refinement) = catalog_name.rsplit_once(' ')?;
+    Some((name, Refinement::from_name(refinement)?))
+}
+
+/// "Axi K6 Relic" -> ("Axi K6", Intact), "Axi K6 Relic (Radiant)" -> ("Axi K6", Radiant)
+fn parse_drop_location(location: &str) -> Option<(&str, Refinement)> {
+    if let Some(relic) = location.strip_suffix(" Relic") {
+        return Some((relic, Refinement::Intact));
+    }
+    let (relic, refinement) = location.strip_suffix(')')?.split_once(" Relic (")?;
+    Some((relic, Refinement::from_name(refinement)?))
+}
+
+fn rarity_order(rarity: &str) -> u8 {
+    match rarity {
+        "Common" => 0,
+        "Uncommon" => 1,
+        "Rare" => 2,
+        _ => 3,
+    }
+}
+
+/// Owned copies of one relic across all refinements.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OwnedRelic {
+    /// In-game name such as "Axi P8", or `<era> <code>` when the relic is not in the catalog
+    pub name: String,
+    pub era: RelicEra,
+    pub code: String,
+    pub counts: BTreeMap<Refinement, i64>,
+    pub rewards: Vec<RelicReward>,
+}
+
+impl OwnedRelic {
+    pub fn total(&self) -> i64 {
+        self.counts.values().sum()
+    }
+
+    pub fn count(&self, refinement: Refinement) -> i64 {
+        self.counts.get(&refinement).copied().unwrap_or(0)
+    }
+}
+
+/// Relics found in `MiscItems`, grouped per relic with their reward tables.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RelicReport {
+    /// Sorted by era, then name
+    pub relics: Vec<OwnedRelic>,
+}
+
+impl RelicReport {
+    pub fn build(inventory: &Inventory, catalog: &Catalog) -> RelicReport {
+        let tables = reward_tables(catalog);
+        let mut owned: HashMap<(RelicEra, String), OwnedRelic> = HashMap::new();
+
+        for item in &inventory.misc_items {
+            let Some(path) = RelicPath::parse(&item.item_type) else {
+                continue;
+            };
+            let relic = owned
+                .entry((path.era, path.code.clone()))
+                .or_insert_with(|| {
+                    let name = catalog
+                        .get(&item.item_type)
+                        .and_then(|relic| split_refinement(relic.name()))
+                        .map(|(name, _)| name.to_string())
+                        .unwrap_or_else(|| format!("{} {}", path.era, path.code));
+                    OwnedRelic {
+                        rewards: tables.get(&name).cloned().unwrap_or_default(),
+                        name,
+                        era: path.era,
+                        code: path.code.clone(),
+                        counts: BTreeMap::new(),
+                    }
+                });
+            *relic.counts.entry(path.refinement).or_default() += item.item_count;
+        }
+
+        let mut relics: Vec<OwnedRelic> = owned.into_values().collect();
+        relics.sort_by(|a, b| a.era.cmp(&b.era).then_with(|| a.name.cmp(&b.name)));
+        RelicReport { relics }
+    }
+
+    pub fn total(&self) -> i64 {
+        self.relics.iter().map(OwnedRelic::total).sum()
+    }
+
+    pub fn by_era(&self, era: RelicEra) -> impl Iterator<Item = &OwnedRelic> {
+        self.relics.iter().filter(move |relic| relic.era == era)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::tests::load_test_inventory;
+    use crate::itemdata::{relics, warframe};
+
+    #[test]
+    fn test_parse_relic_path() {
+        let path =
+            RelicPath::parse("/Lotus/Types/Game/Projections/T4VoidProjectionLavosPrimeASilver")
+                .unwrap();
+        assert_eq!(path.era, RelicEra::Axi);
+        assert_eq!(path.code, "LavosPrimeA");
+        assert_eq!(path.refinement, Refinement::Exceptional);
+        assert_eq!(
+            path.item_type(Refinement::Radiant),
+            "/Lotus/Types/Game/Projections/T4VoidProjectionLavosPrimeAPlatinum"
+        );
+
+        let path =
+            RelicPath::parse("/Lotus/Types/Game/Projections/T5VoidProjectionImmortalABronze")
+                .unwrap();
+        assert_eq!(path.era, RelicEra::Requiem);
+        assert_eq!(path.refinement, Refinement::Intact);
+
+        assert!(RelicPath::parse("/Lotus/Types/Items/MiscItems/Rubedo").is_none());
+        assert!(
+            RelicPath::parse("/Lotus/Types/Game/Projections/T9VoidProjectionXBronze").is_none()
+        );
+    }
+
+    #[test]
+    fn test_relic_report() {
+        let drop = |location: &str, rarity: &str, chance: f64| warframe::Drop {
+            chance,
+            location: location.to_string(),
+            rarity: rarity.to_string(),
+            type_field: "Lavos Prime Blueprint".to_string(),
+        };
+        let catalog: Catalog = [
+            CatalogItem::Relic(relics::Relic {
+                name: "Lith L1 Intact".to_string(),
+                unique_name: "/Lotus/Types/Game/Projections/T1VoidProjectionLavosPrimeABronze"
+                    .to_string(),
+                ..Default::default()
+            }),
+            CatalogItem::Warframe(warframe::Warframe {
+                name: "Lavos Prime".to_string(),
+                unique_name: "/Lotus/Powersuits/Alchemist/AlchemistPrime".to_string(),
+                components: vec![warframe::Component {
+                    unique_name: "/Lotus/Types/Recipes/WarframeRecipes/LavosPrimeBlueprint"
+                        .to_string(),
+                    name: "Blueprint".to_string(),
+                    drops: vec![
+                        drop("Lith L1 Relic", "Rare", 0.02),
+                        drop("Lith L1 Relic (Radiant)", "Rare", 0.1),
+                        drop("Void/Hepit (Capture)", "Rare", 0.5),
+                    ],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }),
+        ]
+        .into_iter()
+        .collect();
+
+        let inventory = load_test_inventory();
+        let report = RelicReport::build(&inventory, &catalog);
+
+        let lith = report
+            .by_era(RelicEra::Lith)
+            .find(|relic| relic.code == "LavosPrimeA")
+            .unwrap();
+        assert_eq!(lith.name, "Lith L1");
+        assert_eq!(lith.count(Refinement::Intact), 4);
+        assert_eq!(lith.rewards.len(), 1);
+        assert_eq!(lith.rewards[0].name, "Lavos Prime Blueprint");
+        assert_eq!(lith.rewards[0].chances[&Refinement::Intact], 0.02);
+        assert_eq!(lith.rewards[0].chances[&Refinement::Radiant], 0.1);
+
+        // Relics missing from the catalog still show up, without rewards
+        let meso = report
+            .by_era(RelicEra::Meso)
+            .find(|relic| relic.code == "GaussPrimeA")
+            .unwrap();
+        assert_eq!(meso.name, "Meso GaussPrimeA");
+        assert_eq!(meso.count(Refinement::Radiant), 2);
+        assert!(meso.rewards.is_empty());
+
+        let owned: i64 = inventory
+            .misc_items
+            .iter()
+            .filter(|item| RelicPath::parse(&item.item_type).is_some())
+            .map(|item| item.item_count)
+            .sum();
+        assert_eq!(report.total(), owned);
+    }
+}
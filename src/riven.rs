@@ -0,0 +1,417 @@
+use serde::{Deserialize, Serialize};
+
+use crate::inventory::upgrades::{
+    Buff, RivenChallenge, RivenFingerprint, Upgrade, UpgradeFingerprint,
+};
+use crate::inventory::{Inventory, PolarityKind};
+use crate::itemdata::catalog::Catalog;
+
+/// Buff and curse values are rolls in `0..=RIVEN_ROLL_MAX`
+pub const RIVEN_ROLL_MAX: f64 = 0x3FFF_FFFF as f64;
+
+/// Rank a riven maxes out at
+pub const RIVEN_MAX_RANK: i64 = 8;
+
+/// Weapon class a riven is for, from the riven `ItemType`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RivenWeapon {
+    Rifle,
+    Shotgun,
+    Pistol,
+    Melee,
+    ArchGun,
+}
+
+impl RivenWeapon {
+    /// e.g. `/Lotus/Upgrades/Mods/Randomized/LotusShotgunRandomModRare`
+    pub fn from_item_type(item_type: &str) -> Option<RivenWeapon> {
+        let name = item_type.strip_prefix("/Lotus/Upgrades/Mods/Randomized/")?;
+        if name.contains("Archgun") {
+            Some(RivenWeapon::ArchGun)
+        } else if name.contains("Shotgun") {
+            Some(RivenWeapon::Shotgun)
+        } else if name.contains("Pistol") {
+            Some(RivenWeapon::Pistol)
+        } else if name.contains("Rifle") {
+            Some(RivenWeapon::Rifle)
+        } else if name.contains("Melee") {
+            Some(RivenWeapon::Melee)
+        } else {
+            None
+        }
+    }
+
+    fn index(&self) -> usize {
+        *self as usize
+    }
+}
+
+/// How a stat is shown in game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StatUnit {
+    /// "+123.4% Critical Chance"
+    Percent,
+    /// "x1.45 Damage to Grineer"
+    Multiplier,
+    /// "+2.7 Punch Through"
+    Flat,
+}
+
+struct StatInfo {
+    tag: &'static str,
+    name: &'static str,
+    unit: StatUnit,
+    /// Base value per `RivenWeapon`, before disposition and roll
+    base: [Option<f64>; 5],
+}
+
+const fn stat(
+    tag: &'static str,
+    name: &'static str,
+    unit: StatUnit,
+    base: [Option<f64>; 5],
+) -> StatInfo {
+    StatInfo {
+        tag,
+        name,
+        unit,
+        base,
+    }
+}
+
+/// Community datamined base values, in `RivenWeapon` order: rifle, shotgun, pistol, melee, arch-gun
+#[rustfmt::skip]
+const STATS: &[StatInfo] = &[
+    stat("WeaponDamageAmountMod", "Damage", StatUnit::Percent, [Some(165.0), Some(164.7), Some(219.6), Some(164.7), Some(99.9)]),
+    stat("WeaponFireIterationsMod", "Multishot", StatUnit::Percent, [Some(90.0), Some(119.7), Some(119.7), None, Some(60.3)]),
+    stat("WeaponCritChanceMod", "Critical Chance", StatUnit::Percent, [Some(149.99), Some(90.0), Some(149.99), Some(180.0), Some(99.9)]),
+    stat("SlideAttackCritChanceMod", "Critical Chance for Slide Attack", StatUnit::Percent, [None, None, None, Some(120.0), None]),
+    stat("WeaponCritDamageMod", "Critical Damage", StatUnit::Percent, [Some(120.0), Some(90.0), Some(90.0), Some(90.0), Some(80.1)]),
+    stat("WeaponFireRateMod", "Fire Rate / Attack Speed", StatUnit::Percent, [Some(60.03), Some(89.1), Some(74.7), Some(54.9), Some(60.03)]),
+    stat("WeaponStunChanceMod", "Status Chance", StatUnit::Percent, [Some(90.0), Some(90.0), Some(90.0), Some(90.0), Some(60.3)]),
+    stat("WeaponProcTimeMod", "Status Duration", StatUnit::Percent, [Some(99.9), Some(99.0), Some(99.9), Some(99.0), Some(99.9)]),
+    stat("WeaponFireDamageMod", "Heat", StatUnit::Percent, [Some(90.0), Some(90.0), Some(90.0), Some(90.0), Some(119.7)]),
+    stat("WeaponFreezeDamageMod", "Cold", StatUnit::Percent, [Some(90.0), Some(90.0), Some(90.0), Some(90.0), Some(119.7)]),
+    stat("WeaponToxinDamageMod", "Toxin", StatUnit::Percent, [Some(90.0), Some(90.0), Some(90.0), Some(90.0), Some(119.7)]),
+    stat("WeaponElectricityDamageMod", "Electricity", StatUnit::Percent, [Some(90.0), Some(90.0), Some(90.0), Some(90.0), Some(119.7)]),
+    stat("WeaponImpactDamageMod", "Impact", StatUnit::Percent, [Some(119.97), Some(119.97), Some(119.97), Some(119.7), Some(90.0)]),
+    stat("WeaponArmorPiercingDamageMod", "Puncture", StatUnit::Percent, [Some(119.97), Some(119.97), Some(119.97), Some(119.7), Some(90.0)]),
+    stat("WeaponSlashDamageMod", "Slash", StatUnit::Percent, [Some(119.97), Some(119.97), Some(119.97), Some(119.7), Some(90.0)]),
+    stat("WeaponFactionDamageGrineer", "Damage to Grineer", StatUnit::Multiplier, [Some(45.0), Some(45.0), Some(45.0), None, Some(45.0)]),
+    stat("WeaponFactionDamageCorpus", "Damage to Corpus", StatUnit::Multiplier, [Some(45.0), Some(45.0), Some(45.0), None, Some(45.0)]),
+    stat("WeaponFactionDamageInfested", "Damage to Infested", StatUnit::Multiplier, [Some(45.0), Some(45.0), Some(45.0), None, Some(45.0)]),
+    stat("WeaponMeleeFactionDamageGrineer", "Damage to Grineer", StatUnit::Multiplier, [None, None, None, Some(45.0), None]),
+    stat("WeaponMeleeFactionDamageCorpus", "Damage to Corpus", StatUnit::Multiplier, [None, None, None, Some(45.0), None]),
+    stat("WeaponMeleeFactionDamageInfested", "Damage to Infested", StatUnit::Multiplier, [None, None, None, Some(45.0), None]),
+    stat("WeaponClipMaxMod", "Magazine Capacity", StatUnit::Percent, [Some(50.0), Some(50.0), Some(50.0), None, Some(60.3)]),
+    stat("WeaponAmmoMaxMod", "Ammo Maximum", StatUnit::Percent, [Some(49.95), Some(90.0), Some(90.0), None, Some(99.9)]),
+    stat("WeaponPunctureDepthMod", "Punch Through", StatUnit::Flat, [Some(2.7), Some(2.7), Some(2.7), None, None]),
+    stat("WeaponProjectileSpeedMod", "Projectile Speed", StatUnit::Percent, [Some(90.0), Some(89.1), Some(79.2), None, None]),
+    stat("WeaponRecoilReductionMod", "Weapon Recoil", StatUnit::Percent, [Some(-90.0), Some(-90.0), Some(-90.0), None, Some(-90.0)]),
+    stat("WeaponReloadSpeedMod", "Reload Speed", StatUnit::Percent, [Some(50.0), Some(49.45), Some(50.0), None, Some(99.9)]),
+    stat("WeaponZoomFovMod", "Zoom", StatUnit::Percent, [Some(59.99), None, Some(80.1), None, Some(59.99)]),
+    stat("WeaponMeleeRangeIncMod", "Range", StatUnit::Flat, [None, None, None, Some(1.94), None]),
+    stat("WeaponMeleeComboDurationMod", "Combo Duration", StatUnit::Flat, [None, None, None, Some(8.1), None]),
+    stat("WeaponMeleeFinisherDamageMod", "Finisher Damage", StatUnit::Percent, [None, None, None, Some(119.7), None]),
+    stat("WeaponMeleeComboInitialBonusMod", "Initial Combo", StatUnit::Flat, [None, None, None, Some(24.5), None]),
+    stat("WeaponMeleeComboEfficiencyMod", "Heavy Attack Efficiency", StatUnit::Percent, [None, None, None, Some(73.44), None]),
+    stat("WeaponMeleeComboBonusOnHitMod", "Additional Combo Count Chance", StatUnit::Percent, [None, None, None, Some(58.77), None]),
+    stat("WeaponMeleeComboPointsOnHitMod", "Chance to Gain Combo Count", StatUnit::Percent, [None, None, None, Some(104.85), None]),
+];
+
+/// One decoded buff or curse.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RivenStat {
+    pub tag: String,
+    /// In-game stat name, `None` for tags missing from the stat table
+    pub name: Option<String>,
+    pub unit: StatUnit,
+    pub curse: bool,
+    /// Roll between 0 (worst) and 1 (best)
+    pub roll: f64,
+    /// Value at the riven's current rank, `None` when the tag or disposition is unknown
+    pub value: Option<f64>,
+    /// Value once the riven is maxed
+    pub max_value: Option<f64>,
+}
+
+impl RivenStat {
+    /// e.g. "+132.4% Critical Chance", "x0.71 Damage to Corpus"
+    pub fn describe(&self) -> String {
+        let name = self.name.as_deref().unwrap_or(&self.tag);
+        match (self.value, self.unit) {
+            (None, _) => name.to_string(),
+            (Some(value), StatUnit::Percent) => format!("{:+.1}% {}", value, name),
+            (Some(value), StatUnit::Flat) => format!("{:+.1} {}", value, name),
+            (Some(value), StatUnit::Multiplier) => format!("x{:.2} {}", 1.0 + value / 100.0, name),
+        }
+    }
+}
+
+/// Unveiled riven with its stats worked out.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DecodedRiven {
+    pub item_id: String,
+    pub item_type: String,
+    pub weapon_class: Option<RivenWeapon>,
+    /// Weapon path the riven is locked to
+    pub compat: String,
+    /// Weapon name from the catalog
+    pub weapon_name: Option<String>,
+    /// Weapon disposition multiplier, between 0.5 and 1.55
+    pub disposition: Option<f64>,
+    pub polarity: Option<PolarityKind>,
+    /// Mastery rank needed to equip it
+    pub mastery_req: Option<i64>,
+    pub rank: i64,
+    pub rerolls: i64,
+    pub stats: Vec<RivenStat>,
+}
+
+/// Veiled riven still waiting on its challenge.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VeiledRiven {
+    pub item_id: String,
+    pub item_type: String,
+    pub weapon_class: Option<RivenWeapon>,
+    pub challenge: String,
+    pub description: String,
+    pub progress: i64,
+    pub required: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Riven {
+    Unveiled(DecodedRiven),
+    Veiled(VeiledRiven),
+}
+
+impl Riven {
+    /// `None` when the upgrade is not a riven
+    pub fn decode(upgrade: &Upgrade, catalog: &Catalog) -> Option<Riven> {
+        match &upgrade.upgrade_fingerprint {
+            UpgradeFingerprint::RivenMod(fingerprint) => {
+                Some(Riven::Unveiled(decode_riven(upgrade, fingerprint, catalog)))
+            }
+            UpgradeFingerprint::RivenChallenge(challenge) => {
+                Some(Riven::Veiled(veiled_riven(upgrade, challenge)))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Every riven in `Upgrades`, veiled or not
+pub fn decode_rivens(inventory: &Inventory, catalog: &Catalog) -> Vec<Riven> {
+    inventory
+        .upgrades
+        .iter()
+        .filter_map(|upgrade| Riven::decode(upgrade, catalog))
+        .collect()
+}
+
+fn decode_riven(
+    upgrade: &Upgrade,
+    fingerprint: &RivenFingerprint,
+    catalog: &Catalog,
+) -> DecodedRiven {
+    let weapon_class = RivenWeapon::from_item_type(&upgrade.item_type);
+    let weapon = catalog.get(&fingerprint.compat);
+    let disposition = weapon.and_then(|weapon| weapon.disposition());
+    let rank = fingerprint.lvl.unwrap_or(0).clamp(0, RIVEN_MAX_RANK);
+
+    let (buff_multiplier, curse_multiplier) =
+        attribute_multipliers(fingerprint.buffs.len(), fingerprint.curses.len());
+    let scale = |buff: &Buff, multiplier: f64, curse: bool| {
+        let info = STATS.iter().find(|info| info.tag == buff.tag);
+        let roll = (buff.value as f64 / RIVEN_ROLL_MAX).clamp(0.0, 1.0);
+        let max_value = info
+            .zip(weapon_class)
+            .and_then(|(info, class)| info.base[class.index()])
+            .zip(disposition)
+            .map(|(base, disposition)| base * disposition * multiplier * (0.9 + 0.2 * roll));
+        RivenStat {
+            tag: buff.tag.clone(),
+            name: info.map(|info| info.name.to_string()),
+            unit: info.map(|info| info.unit).unwrap_or(StatUnit::Percent),
+            curse,
+            roll,
+            value: max_value.map(|value| value * (rank + 1) as f64 / (RIVEN_MAX_RANK + 1) as f64),
+            max_value,
+        }
+    };
+
+    let stats = fingerprint
+        .buffs
+        .iter()
+        .map(|buff| scale(buff, buff_multiplier, false))
+        .chain(
+            fingerprint
+                .curses
+                .iter()
+                .map(|curse| scale(curse, curse_multiplier, true)),
+        )
+        .collect();
+
+    DecodedRiven {
+        item_id: upgrade.item_id.oid.clone(),
+        item_type: upgrade.item_type.clone(),
+        weapon_class,
+        compat: fingerprint.compat.clone(),
+        weapon_name: weapon.map(|weapon| weapon.name().to_string()),
+        disposition,
+        polarity: PolarityKind::from_code(&fingerprint.pol),
+        mastery_req: fingerprint.lvl_req,
+        rank,
+        rerolls: fingerprint.rerolls.unwrap_or(0),
+        stats,
+    }
+}
+
+/// Multipliers applied to every buff and curse, based on how many of each the riven has
+fn attribute_multipliers(buffs: usize, curses: usize) -> (f64, f64) {
+    match (buffs, curses > 0) {
+        (2, false) => (0.99, 0.0),
+        (2, true) => (1.2375, -0.495),
+        (3, false) => (0.75, 0.0),
+        (3, true) => (0.9375, -0.75),
+        _ => (1.0, -1.0),
+    }
+}
+
+fn veiled_riven(upgrade: &Upgrade, challenge: &RivenChallenge) -> VeiledRiven {
+    let detail = &challenge.challenge;
+    VeiledRiven {
+        item_id: upgrade.item_id.oid.clone(),
+        item_type: upgrade.item_type.clone(),
+        weapon_class: RivenWeapon::from_item_type(&upgrade.item_type),
+        challenge: detail.type_path.clone(),
+        description: describe_challenge(&detail.type_path, detail.required),
+        progress: detail.progress,
+        required: detail.required,
+    }
+}
+
+/// Human readable challenge, falling back to the split challenge name for unknown ones
+pub fn describe_challenge(type_path: &str, required: i64) -> String {
+    let name = type_path.rsplit('/').next().unwrap_or(type_path);
+    let name = name.strip_prefix("Randomized").unwrap_or(name);
+    match name {
+        "Kill" => format!("Kill {} enemies", required),
+        "FinisherKill" => format!("Kill {} enemies with finishers", required),
+        "FlyingHeadshotSeries" => {
+            format!("Get {} headshot kills in a row while aim gliding", required)
+        }
+        "HeadshotUnawareBallistas" => {
+            format!("Kill {} unaware Ballistas with headshots", required)
+        }
+        "LongRangeSniper" => format!("Kill {} enemies from long range", required),
+        "SkiffArcher" => format!("Kill {} enemies with a bow while on a Skiff", required),
+        _ => {
+            let mut words = String::new();
+            for c in name.chars() {
+                if c.is_uppercase() && !words.is_empty() {
+                    words.push(' ');
+                }
+                words.push(c);
+            }
+            format!("{} ({})", words, required)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::ObjectId;
+    use crate::inventory::tests::load_test_inventory;
+    use crate::itemdata::catalog::CatalogItem;
+    use crate::itemdata::primary;
+
+    fn riven(item_type: &str, fingerprint: &str) -> Upgrade {
+        Upgrade {
+            item_type: item_type.to_string(),
+            item_id: ObjectId {
+                oid: "60f73b8103cbfb55fc3b1998".to_string(),
+            },
+            upgrade_fingerprint: serde_json::from_value(fingerprint.into()).unwrap(),
+            other: None,
+        }
+    }
+
+    #[test]
+    fn test_decode_riven() {
+        let catalog: Catalog = [CatalogItem::Primary(primary::Primary {
+            name: "Braton".to_string(),
+            unique_name: "/Lotus/Weapons/Tenno/Rifle/Rifle".to_string(),
+            omega_attenuation: 1.0,
+            ..Default::default()
+        })]
+        .into_iter()
+        .collect();
+
+        // Mid rolls on a maxed two buff one curse riven
+        let half = 0x3FFF_FFFF / 2;
+        let upgrade = riven(
+            "/Lotus/Upgrades/Mods/Randomized/LotusRifleRandomModRare",
+            &format!(
+                r#"{{"compat":"/Lotus/Weapons/Tenno/Rifle/Rifle","lim":1,"lvlReq":10,"lvl":8,"rerolls":3,"pol":"AP_ATTACK","buffs":[{{"Tag":"WeaponCritChanceMod","Value":{half}}},{{"Tag":"WeaponFactionDamageGrineer","Value":{half}}}],"curses":[{{"Tag":"WeaponZoomFovMod","Value":{half}}}]}}"#
+            ),
+        );
+
+        let Some(Riven::Unveiled(decoded)) = Riven::decode(&upgrade, &catalog) else {
+            panic!("expected an unveiled riven");
+        };
+        assert_eq!(decoded.weapon_name.as_deref(), Some("Braton"));
+        assert_eq!(decoded.weapon_class, Some(RivenWeapon::Rifle));
+        assert_eq!(decoded.polarity, Some(PolarityKind::Madurai));
+        assert_eq!(decoded.mastery_req, Some(10));
+        assert_eq!(decoded.rerolls, 3);
+
+        let crit = &decoded.stats[0];
+        assert!((crit.value.unwrap() - 149.99 * 1.2375).abs() < 0.01);
+        assert_eq!(crit.describe(), "+185.6% Critical Chance");
+        assert_eq!(decoded.stats[1].describe(), "x1.56 Damage to Grineer");
+        let curse = &decoded.stats[2];
+        assert!(curse.curse);
+        assert_eq!(curse.describe(), "-29.7% Zoom");
+    }
+
+    #[test]
+    fn test_decode_rivens_from_inventory() {
+        let rivens = decode_rivens(&load_test_inventory(), &Catalog::default());
+        assert_eq!(rivens.len(), 19);
+
+        let veiled: Vec<&VeiledRiven> = rivens
+            .iter()
+            .filter_map(|riven| match riven {
+                Riven::Veiled(veiled) => Some(veiled),
+                _ => None,
+            })
+            .collect();
+        assert!(!veiled.is_empty());
+        assert!(veiled.iter().all(|v| v.progress <= v.required));
+
+        // Without the catalog the stats are still listed, only without values
+        let unveiled = rivens.iter().find_map(|riven| match riven {
+            Riven::Unveiled(decoded) => Some(decoded),
+            _ => None,
+        });
+        let unveiled = unveiled.unwrap();
+        assert!(unveiled.weapon_name.is_none());
+        assert!(unveiled.stats.iter().all(|stat| stat.value.is_none()));
+        assert!(unveiled.stats.iter().any(|stat| stat.name.is_some()));
+    }
+
+    #[test]
+    fn test_describe_challenge() {
+        assert_eq!(
+            describe_challenge("/Lotus/Types/Challenges/RandomizedFinisherKill", 5),
+            "Kill 5 enemies with finishers"
+        );
+        assert_eq!(
+            describe_challenge("/Lotus/Types/Challenges/RandomizedEximusKill", 3),
+            "Eximus Kill (3)"
+        );
+    }
+}
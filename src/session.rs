@@ -0,0 +1,287 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::diff::{CountChange, InventoryDiff, ItemChange, StandingChange};
+use crate::inventory::Inventory;
+use crate::itemdata::catalog::Catalog;
+use crate::mastery::MasteryReport;
+use crate::relics::RelicPath;
+use crate::snapshots::{SnapshotInfo, SnapshotStore};
+use crate::storage;
+use crate::utils;
+
+/// Written at login so the session can be reported at logout or on demand.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionStart {
+    pub started_at: DateTime<Utc>,
+    /// Inventory at login
+    pub snapshot: SnapshotInfo,
+}
+
+impl SessionStart {
+    /// `session.json` in the app cache directory
+    pub fn path() -> anyhow::Result<PathBuf> {
+        Ok(storage::app_cache_dir()?.join("session.json"))
+    }
+
+    /// Starts a session from the latest snapshot, call after the login inventory is saved
+    pub fn begin(store: &SnapshotStore, started_at: DateTime<Utc>) -> anyhow::Result<SessionStart> {
+        let snapshot = store
+            .latest()?
+            .ok_or_else(|| anyhow::anyhow!("No inventory snapshot to start the session from"))?;
+        let start = SessionStart {
+            started_at,
+            snapshot,
+        };
+        let path = SessionStart::path()?;
+        let json = serde_json::to_string_pretty(&start)?;
+        fs::write(&path, json).with_context(|| format!("Failed to write {}", path.display()))?;
+        Ok(start)
+    }
+
+    /// The session in progress or the last one that was not reported
+    pub fn load() -> anyhow::Result<Option<SessionStart>> {
+        let path = SessionStart::path()?;
+        if !path.exists() {
+            return Ok(None);
+        }
+        let raw = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let start = serde_json::from_str(&raw)
+            .with_context(|| format!("Failed to parse {}", path.display()))?;
+        Ok(Some(start))
+    }
+
+    pub fn clear() -> anyhow::Result<()> {
+        let path = SessionStart::path()?;
+        if path.exists() {
+            fs::remove_file(&path)
+                .with_context(|| format!("Failed to delete {}", path.display()))?;
+        }
+        Ok(())
+    }
+}
+
+/// What one play session earned.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionReport {
+    pub started_at: DateTime<Utc>,
+    pub ended_at: DateTime<Utc>,
+    pub credits: i64,
+    /// Resources gained, relics are listed separately
+    pub resources: Vec<CountChange>,
+    /// Relics that went down in count, negative deltas
+    pub relics_consumed: Vec<CountChange>,
+    pub items_gained: Vec<ItemChange>,
+    pub mastery_points: i64,
+    /// Affinity earned across all warframes, weapons and archwings
+    pub affinity: i64,
+    pub standing: Vec<StandingChange>,
+}
+
+impl SessionReport {
+    pub fn build(
+        before: &Inventory,
+        after: &Inventory,
+        started_at: DateTime<Utc>,
+        ended_at: DateTime<Utc>,
+        catalog: &Catalog,
+    ) -> SessionReport {
+        let diff = InventoryDiff::between(before, after, catalog);
+        let (relics, resources): (Vec<CountChange>, Vec<CountChange>) = diff
+            .resources
+            .iter()
+            .cloned()
+            .partition(|change| RelicPath::parse(&change.item_type).is_some());
+
+        let mastery_points = MasteryReport::build(after, catalog).mastery_points
+            - MasteryReport::build(before, catalog).mastery_points;
+
+        SessionReport {
+            started_at,
+            ended_at,
+            credits: diff.credits,
+            resources: resources
+                .into_iter()
+                .filter(|change| change.delta() > 0)
+                .collect(),
+            relics_consumed: relics
+                .into_iter()
+                .filter(|change| change.delta() < 0)
+                .collect(),
+            items_gained: diff.items_gained.clone(),
+            mastery_points,
+            affinity: diff.xp_gained(),
+            standing: diff.standing,
+        }
+    }
+
+    pub fn duration(&self) -> chrono::Duration {
+        self.ended_at - self.started_at
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut out = format!(
+            "# Session {}\n\n",
+            self.started_at
+                .with_timezone(&chrono::Local)
+                .format("%Y-%m-%d %H:%M")
+        );
+        out.push_str(&format!(
+            "- Duration: {}\n- Credits: {:+}\n- Mastery points: {:+}\n- Affinity: {:+}\n",
+            utils::format_duration(self.duration()),
+            self.credits,
+            self.mastery_points,
+            self.affinity
+        ));
+
+        if !self.items_gained.is_empty() {
+            out.push_str("\n## New items\n\n");
+            for item in &self.items_gained {
+                out.push_str(&format!("- {}\n", item.name));
+            }
+        }
+        for (title, changes) in [
+            ("Resources", &self.resources),
+            ("Relics consumed", &self.relics_consumed),
+        ] {
+            if changes.is_empty() {
+                continue;
+            }
+            out.push_str(&format!(
+                "\n## {}\n\n| Item | Change |\n| --- | ---: |\n",
+                title
+            ));
+            for change in changes {
+                out.push_str(&format!("| {} | {:+} |\n", change.name, change.delta()));
+            }
+        }
+        if !self.standing.is_empty() {
+            out.push_str("\n## Standing\n\n| Syndicate | Change |\n| --- | ---: |\n");
+            for change in &self.standing {
+                out.push_str(&format!(
+                    "| {} | {:+} |\n",
+                    change.syndicate.name(),
+                    change.delta()
+                ));
+            }
+        }
+        out
+    }
+
+    /// Writes `session-<start>.json` and `.md` into `dir`, returns the Markdown path
+    pub fn write(&self, dir: &Path) -> anyhow::Result<PathBuf> {
+        fs::create_dir_all(dir).context("Failed to create sessions directory")?;
+        let stem = format!("session-{}", self.started_at.format("%Y%m%d-%H%M%S"));
+
+        let json_path = dir.join(format!("{}.json", stem));
+        let json = serde_json::to_string_pretty(self).context("Failed to serialize session")?;
+        fs::write(&json_path, json)
+            .with_context(|| format!("Failed to write {}", json_path.display()))?;
+
+        let md_path = dir.join(format!("{}.md", stem));
+        fs::write(&md_path, self.to_markdown())
+            .with_context(|| format!("Failed to write {}", md_path.display()))?;
+        Ok(md_path)
+    }
+}
+
+/// `sessions` in the app cache directory
+pub fn sessions_dir() -> anyhow::Result<PathBuf> {
+    Ok(storage::app_cache_dir()?.join("sessions"))
+}
+
+/// Report of the session started at login up to the latest snapshot
+pub fn current_report(
+    store: &SnapshotStore,
+    catalog: &Catalog,
+    ended_at: DateTime<Utc>,
+) -> anyhow::Result<SessionReport> {
+    let start = SessionStart::load()?
+        .ok_or_else(|| anyhow::anyhow!("No session started, log in to Warframe first"))?;
+    let latest = store
+        .latest()?
+        .ok_or_else(|| anyhow::anyhow!("No inventory snapshot"))?;
+    let before = store.load(&start.snapshot)?;
+    let after = store.load(&latest)?;
+    Ok(SessionReport::build(
+        &before,
+        &after,
+        start.started_at,
+        ended_at,
+        catalog,
+    ))
+}
+
+/// Writes the report of the session that just ended and forgets its start
+pub fn finish(catalog: &Catalog, ended_at: DateTime<Utc>) -> anyhow::Result<PathBuf> {
+    let store = SnapshotStore::open_default()?;
+    let report = current_report(&store, catalog, ended_at)?;
+    let path = report.write(&sessions_dir()?)?;
+    SessionStart::clear()?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::tests::load_test_inventory;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_session_report() {
+        let before = load_test_inventory();
+        let mut after = before.clone();
+        after.regular_credits += 80_000;
+
+        let relic = after
+            .misc_items
+            .iter_mut()
+            .find(|item| RelicPath::parse(&item.item_type).is_some() && item.item_count > 0)
+            .unwrap();
+        relic.item_count -= 1;
+        let relic_type = relic.item_type.clone();
+        let sensors = after
+            .misc_items
+            .iter_mut()
+            .find(|item| item.item_type == "/Lotus/Types/Items/MiscItems/NeuralSensor")
+            .unwrap();
+        sensors.item_count += 2;
+        // One rank 0 to rank 30 weapon worth of affinity
+        after.xp_info.push(crate::inventory::xp_info::XpInfo {
+            item_type: "/Lotus/Weapons/Test/NewGun".to_string(),
+            xp: 450_000,
+        });
+        after.affiliations[0].standing += 1_000;
+
+        let started_at = Utc.with_ymd_and_hms(2026, 1, 10, 18, 0, 0).unwrap();
+        let ended_at = Utc.with_ymd_and_hms(2026, 1, 10, 20, 30, 0).unwrap();
+        let report =
+            SessionReport::build(&before, &after, started_at, ended_at, &Catalog::default());
+
+        assert_eq!(report.duration(), chrono::Duration::minutes(150));
+        assert_eq!(report.credits, 80_000);
+        assert_eq!(report.mastery_points, 3_000);
+        assert_eq!(report.resources.len(), 1);
+        assert_eq!(report.resources[0].delta(), 2);
+        assert_eq!(report.relics_consumed.len(), 1);
+        assert_eq!(report.relics_consumed[0].item_type, relic_type);
+        assert_eq!(report.standing[0].delta(), 1_000);
+
+        let markdown = report.to_markdown();
+        assert!(markdown.contains("- Duration: 2h 30m\n"));
+        assert!(markdown.contains("| NeuralSensor | +2 |"));
+
+        let dir = std::env::temp_dir().join(format!("wf-info-2-sessions-{}", std::process::id()));
+        let md_path = report.write(&dir).unwrap();
+        let json = fs::read_to_string(dir.join("session-20260110-180000.json")).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        assert!(md_path.ends_with("session-20260110-180000.md"));
+        let parsed: SessionReport = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, report);
+    }
+}
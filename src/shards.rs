@@ -0,0 +1,205 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::inventory::Inventory;
+use crate::inventory::archon::{ArchonShard, SHARD_SOCKETS, ShardColor};
+use crate::itemdata::catalog::Catalog;
+
+/// Shard sockets of one warframe.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FrameShards {
+    pub item_id: String,
+    pub item_type: String,
+    pub name: Option<String>,
+    pub sockets: [Option<ArchonShard>; SHARD_SOCKETS],
+}
+
+impl FrameShards {
+    pub fn installed(&self) -> usize {
+        self.sockets.iter().flatten().count()
+    }
+
+    /// e.g. "Khora Prime: +150 Armor, +50 Energy Max, -, -, -"
+    pub fn summary(&self) -> String {
+        let sockets: Vec<String> = self
+            .sockets
+            .iter()
+            .map(|socket| match socket {
+                Some(shard) => shard.describe(),
+                None => "-".to_string(),
+            })
+            .collect();
+        format!(
+            "{}: {}",
+            self.name.as_deref().unwrap_or(&self.item_type),
+            sockets.join(", ")
+        )
+    }
+}
+
+/// Shards of one color and grade, installed and loose.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShardCount {
+    pub color: ShardColor,
+    pub tauforged: bool,
+    pub installed: i64,
+    /// Unused shards in `MiscItems`
+    pub loose: i64,
+}
+
+impl ShardCount {
+    fn empty(color: ShardColor, tauforged: bool) -> ShardCount {
+        ShardCount {
+            color,
+            tauforged,
+            installed: 0,
+            loose: 0,
+        }
+    }
+
+    pub fn total(&self) -> i64 {
+        self.installed + self.loose
+    }
+}
+
+/// Archon shards across all warframes plus loose ones.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShardReport {
+    /// Warframes with at least one shard, sorted by name
+    pub frames: Vec<FrameShards>,
+    /// One entry per color and grade that is installed or held
+    pub counts: Vec<ShardCount>,
+}
+
+impl ShardReport {
+    pub fn build(inventory: &Inventory, catalog: &Catalog) -> ShardReport {
+        let mut frames: Vec<FrameShards> = inventory
+            .suits
+            .iter()
+            .map(|suit| FrameShards {
+                item_id: suit.item_id.oid.clone(),
+                item_type: suit.item_type.clone(),
+                name: catalog
+                    .get(&suit.item_type)
+                    .map(|item| item.name().to_string()),
+                sockets: suit.shard_sockets(),
+            })
+            .filter(|frame| frame.installed() > 0)
+            .collect();
+        frames.sort_by_key(|frame| {
+            frame
+                .name
+                .clone()
+                .unwrap_or_else(|| frame.item_type.clone())
+        });
+
+        let mut counts: BTreeMap<(ShardColor, bool), ShardCount> = BTreeMap::new();
+        for shard in frames
+            .iter()
+            .flat_map(|frame| frame.sockets.iter().flatten())
+        {
+            counts
+                .entry((shard.color, shard.tauforged))
+                .or_insert_with(|| ShardCount::empty(shard.color, shard.tauforged))
+                .installed += 1;
+        }
+        for item in &inventory.misc_items {
+            if let Some((color, tauforged)) = ShardColor::from_item_type(&item.item_type) {
+                counts
+                    .entry((color, tauforged))
+                    .or_insert_with(|| ShardCount::empty(color, tauforged))
+                    .loose += item.item_count;
+            }
+        }
+
+        ShardReport {
+            frames,
+            counts: counts.into_values().collect(),
+        }
+    }
+
+    /// Where every shard of a color and grade is socketed, as (frame, socket index)
+    pub fn locate(
+        &self,
+        color: ShardColor,
+        tauforged: bool,
+    ) -> impl Iterator<Item = (&FrameShards, usize)> {
+        self.frames.iter().flat_map(move |frame| {
+            frame
+                .sockets
+                .iter()
+                .enumerate()
+                .filter(move |(_, socket)| {
+                    socket
+                        .as_ref()
+                        .is_some_and(|shard| shard.color == color && shard.tauforged == tauforged)
+                })
+                .map(move |(index, _)| (frame, index))
+        })
+    }
+
+    pub fn count(&self, color: ShardColor, tauforged: bool) -> Option<&ShardCount> {
+        self.counts
+            .iter()
+            .find(|count| count.color == color && count.tauforged == tauforged)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::tests::load_test_inventory;
+    use crate::itemdata::catalog::CatalogItem;
+    use crate::itemdata::warframe;
+
+    fn warframe(name: &str, unique_name: &str) -> CatalogItem {
+        CatalogItem::Warframe(warframe::Warframe {
+            name: name.to_string(),
+            unique_name: unique_name.to_string(),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_shard_report() {
+        let catalog: Catalog = [
+            warframe("Saryn Prime", "/Lotus/Powersuits/Saryn/SarynPrime"),
+            warframe("Octavia Prime", "/Lotus/Powersuits/Bard/OctaviaPrime"),
+            warframe("Gara Prime", "/Lotus/Powersuits/Glass/GaraPrime"),
+        ]
+        .into_iter()
+        .collect();
+
+        let report = ShardReport::build(&load_test_inventory(), &catalog);
+        assert_eq!(report.frames.len(), 12);
+
+        // Empty offsets keep later shards in their socket
+        let gara = report
+            .frames
+            .iter()
+            .find(|frame| frame.name.as_deref() == Some("Gara Prime"))
+            .unwrap();
+        assert_eq!(gara.installed(), 2);
+        assert!(gara.sockets[..3].iter().all(Option::is_none));
+        assert_eq!(gara.sockets[3].as_ref().unwrap().color, ShardColor::Crimson);
+        assert_eq!(
+            gara.summary(),
+            "Gara Prime: -, -, -, +37.5% Melee Critical Damage, +37.5% Critical Damage above 500 Energy"
+        );
+
+        let azure = report.count(ShardColor::Azure, true).unwrap();
+        assert_eq!(azure.installed, 2);
+        assert_eq!(azure.loose, 5);
+        assert_eq!(azure.total(), 7);
+
+        let located: Vec<(&str, usize)> = report
+            .locate(ShardColor::Azure, true)
+            .map(|(frame, socket)| (frame.name.as_deref().unwrap(), socket))
+            .collect();
+        assert_eq!(located, vec![("Octavia Prime", 4), ("Saryn Prime", 1)]);
+
+        let topaz = report.count(ShardColor::Topaz, false).unwrap();
+        assert_eq!((topaz.installed, topaz.loose), (0, 1));
+    }
+}
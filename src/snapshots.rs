@@ -0,0 +1,162 @@
+use std::fs::{self, File};
+use std::io::{Read, Write};
+use std::path::PathBuf;
+
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::inventory::Inventory;
+use crate::storage;
+
+/// One saved inventory.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnapshotInfo {
+    pub taken_at: DateTime<Utc>,
+    /// SHA-256 of the inventory JSON, names the compressed file
+    pub hash: String,
+}
+
+/// Timestamped inventory history in a directory: gzipped JSON files named by content hash,
+/// so identical inventories are stored once, plus `index.json` listing when each was taken.
+#[derive(Debug, Clone)]
+pub struct SnapshotStore {
+    dir: PathBuf,
+}
+
+impl SnapshotStore {
+    pub fn open(dir: impl Into<PathBuf>) -> SnapshotStore {
+        SnapshotStore { dir: dir.into() }
+    }
+
+    /// `snapshots` in the app cache directory
+    pub fn open_default() -> anyhow::Result<SnapshotStore> {
+        Ok(SnapshotStore::open(
+            storage::app_cache_dir()?.join("snapshots"),
+        ))
+    }
+
+    fn index_path(&self) -> PathBuf {
+        self.dir.join("index.json")
+    }
+
+    fn snapshot_path(&self, hash: &str) -> PathBuf {
+        self.dir.join(format!("{}.json.gz", hash))
+    }
+
+    /// Every snapshot, oldest first
+    pub fn list(&self) -> anyhow::Result<Vec<SnapshotInfo>> {
+        let path = self.index_path();
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+        let raw = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let mut snapshots: Vec<SnapshotInfo> = serde_json::from_str(&raw)
+            .with_context(|| format!("Failed to parse {}", path.display()))?;
+        snapshots.sort_by_key(|snapshot| snapshot.taken_at);
+        Ok(snapshots)
+    }
+
+    pub fn latest(&self) -> anyhow::Result<Option<SnapshotInfo>> {
+        Ok(self.list()?.pop())
+    }
+
+    /// Most recent snapshot taken at or before `time`
+    pub fn at(&self, time: DateTime<Utc>) -> anyhow::Result<Option<SnapshotInfo>> {
+        Ok(self
+            .list()?
+            .into_iter()
+            .rev()
+            .find(|snapshot| snapshot.taken_at <= time))
+    }
+
+    /// Records the inventory, `None` when it did not change since the latest snapshot
+    pub fn save(
+        &self,
+        inventory: &Inventory,
+        taken_at: DateTime<Utc>,
+    ) -> anyhow::Result<Option<SnapshotInfo>> {
+        let json = serde_json::to_vec(inventory).context("Failed to serialize inventory")?;
+        let hash = hex::encode(Sha256::digest(&json));
+
+        let mut snapshots = self.list()?;
+        if snapshots.last().is_some_and(|latest| latest.hash == hash) {
+            return Ok(None);
+        }
+
+        fs::create_dir_all(&self.dir).context("Failed to create snapshot directory")?;
+        let path = self.snapshot_path(&hash);
+        if !path.exists() {
+            let file = File::create(&path)
+                .with_context(|| format!("Failed to create {}", path.display()))?;
+            let mut encoder = GzEncoder::new(file, Compression::default());
+            encoder.write_all(&json)?;
+            encoder
+                .finish()
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+        }
+
+        let snapshot = SnapshotInfo { taken_at, hash };
+        snapshots.push(snapshot.clone());
+        let index = serde_json::to_string_pretty(&snapshots)?;
+        fs::write(self.index_path(), index).context("Failed to write snapshot index")?;
+
+        log::info!("Saved inventory snapshot {}", path.display());
+        Ok(Some(snapshot))
+    }
+
+    pub fn load(&self, snapshot: &SnapshotInfo) -> anyhow::Result<Inventory> {
+        let path = self.snapshot_path(&snapshot.hash);
+        let file =
+            File::open(&path).with_context(|| format!("Failed to open {}", path.display()))?;
+        let mut json = String::new();
+        GzDecoder::new(file)
+            .read_to_string(&mut json)
+            .with_context(|| format!("Failed to decompress {}", path.display()))?;
+        serde_json::from_str(&json).with_context(|| format!("Failed to parse {}", path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::tests::load_test_inventory;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_snapshot_store() {
+        let dir = std::env::temp_dir().join(format!("wf-info-2-snapshots-{}", std::process::id()));
+        let store = SnapshotStore::open(&dir);
+        assert_eq!(store.latest().unwrap(), None);
+
+        let first = load_test_inventory();
+        let mut second = first.clone();
+        second.regular_credits += 1_000;
+        let at = |ms: i64| Utc.timestamp_millis_opt(ms).unwrap();
+
+        assert!(store.save(&first, at(1_000)).unwrap().is_some());
+        // Unchanged since the latest snapshot
+        assert_eq!(store.save(&first, at(2_000)).unwrap(), None);
+        let changed = store.save(&second, at(3_000)).unwrap().unwrap();
+        // Back to the first inventory, which reuses its file
+        store.save(&first, at(4_000)).unwrap().unwrap();
+
+        let snapshots = store.list().unwrap();
+        let files = fs::read_dir(&dir).unwrap().count();
+        let loaded = store.load(&changed).unwrap();
+        let at_3500 = store.at(at(3_500)).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(snapshots.len(), 3);
+        assert_eq!(snapshots[0].hash, snapshots[2].hash);
+        // Two snapshot files and the index
+        assert_eq!(files, 3);
+        assert_eq!(loaded, second);
+        assert_eq!(at_3500, Some(changed));
+    }
+}
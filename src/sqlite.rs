@@ -0,0 +1,317 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::Context;
+use rusqlite::{Connection, Transaction, params};
+
+use crate::inventory::Inventory;
+use crate::inventory::upgrades::UpgradeFingerprint;
+use crate::itemdata::catalog::Catalog;
+use crate::mastery::ItemMastery;
+
+/// Tables written by `export`. Inventory tables reference the catalog through `item_type`,
+/// which matches `catalog_items.unique_name` for items and `catalog_components.unique_name`
+/// for parts and resources. Items missing from the catalog are still exported, so foreign
+/// keys are declared for joins but not enforced.
+pub const SCHEMA: &str = "
+-- Every warframe-items entry
+CREATE TABLE catalog_items (
+    unique_name TEXT PRIMARY KEY,
+    name TEXT NOT NULL,
+    category TEXT NOT NULL,
+    mastery_req INTEGER,
+    max_rank INTEGER NOT NULL,
+    masterable INTEGER NOT NULL,
+    tradable INTEGER NOT NULL,
+    is_prime INTEGER NOT NULL
+);
+
+-- Crafting components, one row per item that uses them
+CREATE TABLE catalog_components (
+    unique_name TEXT NOT NULL,
+    parent TEXT NOT NULL REFERENCES catalog_items (unique_name),
+    name TEXT NOT NULL,
+    item_count INTEGER NOT NULL,
+    tradable INTEGER NOT NULL,
+    ducats INTEGER,
+    PRIMARY KEY (unique_name, parent)
+);
+
+-- Warframes, weapons and archwings; slot is the inventory list, e.g. LongGuns
+CREATE TABLE equipment (
+    item_id TEXT PRIMARY KEY,
+    slot TEXT NOT NULL,
+    item_type TEXT NOT NULL REFERENCES catalog_items (unique_name),
+    xp INTEGER NOT NULL,
+    rank INTEGER NOT NULL,
+    max_rank INTEGER NOT NULL,
+    forma INTEGER NOT NULL,
+    features INTEGER NOT NULL
+);
+
+-- Ranked mods and rivens, one row per copy; rank is NULL for unreadable fingerprints
+CREATE TABLE upgrades (
+    item_id TEXT PRIMARY KEY,
+    item_type TEXT NOT NULL REFERENCES catalog_items (unique_name),
+    rank INTEGER,
+    is_riven INTEGER NOT NULL
+);
+
+-- Unranked mod stacks
+CREATE TABLE raw_upgrades (
+    item_type TEXT PRIMARY KEY REFERENCES catalog_items (unique_name),
+    item_count INTEGER NOT NULL
+);
+
+-- Unbuilt blueprints
+CREATE TABLE recipes (
+    item_type TEXT PRIMARY KEY,
+    item_count INTEGER NOT NULL
+);
+
+-- Resources, relics and built parts
+CREATE TABLE misc_items (
+    item_type TEXT PRIMARY KEY,
+    item_count INTEGER NOT NULL
+);
+
+-- Affinity of everything ever levelled, including items since sold
+CREATE TABLE xp_info (
+    item_type TEXT PRIMARY KEY REFERENCES catalog_items (unique_name),
+    xp INTEGER NOT NULL
+);
+";
+
+/// Writes the inventory and the catalog to a fresh SQLite database at `path`,
+/// replacing any file already there
+pub fn export(inventory: &Inventory, catalog: &Catalog, path: &Path) -> anyhow::Result<()> {
+    if path.exists() {
+        fs::remove_file(path).with_context(|| format!("Failed to replace {}", path.display()))?;
+    }
+    let mut conn =
+        Connection::open(path).with_context(|| format!("Failed to create {}", path.display()))?;
+    // The bundled SQLite enforces foreign keys by default
+    conn.execute_batch("PRAGMA foreign_keys = OFF;")?;
+    conn.execute_batch(SCHEMA)
+        .context("Failed to create tables")?;
+
+    let tx = conn.transaction()?;
+    insert_catalog(&tx, catalog)?;
+    insert_inventory(&tx, inventory, catalog)?;
+    tx.commit().context("Failed to write database")?;
+    Ok(())
+}
+
+fn insert_catalog(tx: &Transaction, catalog: &Catalog) -> anyhow::Result<()> {
+    let mut items = tx.prepare(
+        "INSERT INTO catalog_items
+         (unique_name, name, category, mastery_req, max_rank, masterable, tradable, is_prime)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+    )?;
+    let mut components = tx.prepare(
+        "INSERT OR IGNORE INTO catalog_components
+         (unique_name, parent, name, item_count, tradable, ducats)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+    )?;
+    for item in catalog.iter() {
+        items.execute(params![
+            item.unique_name(),
+            item.name(),
+            format!("{:?}", item.category()),
+            item.mastery_req(),
+            item.max_rank(),
+            item.masterable(),
+            item.tradable(),
+            item.is_prime(),
+        ])?;
+        for component in item.components() {
+            components.execute(params![
+                component.unique_name,
+                item.unique_name(),
+                component.name,
+                component.item_count,
+                component.tradable,
+                component.ducats,
+            ])?;
+        }
+    }
+    Ok(())
+}
+
+fn insert_inventory(
+    tx: &Transaction,
+    inventory: &Inventory,
+    catalog: &Catalog,
+) -> anyhow::Result<()> {
+    let mut equipment = tx.prepare(
+        "INSERT OR IGNORE INTO equipment
+         (item_id, slot, item_type, xp, rank, max_rank, forma, features)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+    )?;
+    macro_rules! insert_equipment {
+        ($slot:literal, $items:expr) => {
+            for item in &$items {
+                let xp = item.xp.unwrap_or(0);
+                let mastery = ItemMastery::new(&item.item_type, xp, catalog);
+                equipment.execute(params![
+                    item.item_id.oid,
+                    $slot,
+                    item.item_type,
+                    xp,
+                    mastery.rank,
+                    mastery.max_rank,
+                    item.polarized.unwrap_or(0),
+                    item.features.unwrap_or(0),
+                ])?;
+            }
+        };
+    }
+    insert_equipment!("Suits", inventory.suits);
+    insert_equipment!("LongGuns", inventory.long_guns);
+    insert_equipment!("Pistols", inventory.pistols);
+    insert_equipment!("Melee", inventory.melee);
+    insert_equipment!("SpaceSuits", inventory.space_suits);
+    insert_equipment!("SpaceGuns", inventory.space_guns);
+    insert_equipment!("SpaceMelee", inventory.space_melee);
+
+    let mut upgrades = tx.prepare(
+        "INSERT OR IGNORE INTO upgrades (item_id, item_type, rank, is_riven)
+         VALUES (?1, ?2, ?3, ?4)",
+    )?;
+    for upgrade in &inventory.upgrades {
+        let (rank, is_riven) = match &upgrade.upgrade_fingerprint {
+            UpgradeFingerprint::ClassicObj(fingerprint) => (Some(fingerprint.lvl), false),
+            UpgradeFingerprint::RivenMod(fingerprint) => (Some(fingerprint.lvl.unwrap_or(0)), true),
+            UpgradeFingerprint::RivenChallenge(_) => (Some(0), true),
+            UpgradeFingerprint::Unknown(_) => (None, false),
+        };
+        upgrades.execute(params![
+            upgrade.item_id.oid,
+            upgrade.item_type,
+            rank,
+            is_riven
+        ])?;
+    }
+
+    let counts = [
+        (
+            "raw_upgrades",
+            inventory
+                .raw_upgrades
+                .iter()
+                .map(|item| (item.item_type.as_str(), item.item_count))
+                .collect::<Vec<_>>(),
+        ),
+        (
+            "recipes",
+            inventory
+                .recipes
+                .iter()
+                .map(|item| (item.item_type.as_str(), item.item_count))
+                .collect(),
+        ),
+        (
+            "misc_items",
+            inventory
+                .misc_items
+                .iter()
+                .map(|item| (item.item_type.as_str(), item.item_count))
+                .collect(),
+        ),
+    ];
+    for (table, rows) in counts {
+        // Stacks of the same type are merged
+        let mut insert = tx.prepare(&format!(
+            "INSERT INTO {table} (item_type, item_count) VALUES (?1, ?2)
+             ON CONFLICT (item_type) DO UPDATE SET item_count = item_count + excluded.item_count"
+        ))?;
+        for (item_type, count) in rows {
+            insert.execute(params![item_type, count])?;
+        }
+    }
+
+    let mut xp_info = tx.prepare(
+        "INSERT INTO xp_info (item_type, xp) VALUES (?1, ?2)
+         ON CONFLICT (item_type) DO UPDATE SET xp = max(xp, excluded.xp)",
+    )?;
+    for info in &inventory.xp_info {
+        xp_info.execute(params![info.item_type, info.xp])?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::tests::load_test_inventory;
+    use crate::itemdata::catalog::CatalogItem;
+    use crate::itemdata::mods::Mod;
+
+    fn count(conn: &Connection, sql: &str) -> i64 {
+        conn.query_row(sql, [], |row| row.get(0)).unwrap()
+    }
+
+    #[test]
+    fn test_export() {
+        let inventory = load_test_inventory();
+        let unique_name = inventory.upgrades[0].item_type.clone();
+        let catalog = [CatalogItem::Mod(Mod {
+            unique_name: unique_name.clone(),
+            name: "Test Mod".to_string(),
+            ..Default::default()
+        })]
+        .into_iter()
+        .collect::<Catalog>();
+
+        let path = std::env::temp_dir().join(format!("wf-info-2-export-{}.db", std::process::id()));
+        export(&inventory, &catalog, &path).unwrap();
+        // Exporting again replaces the database
+        export(&inventory, &catalog, &path).unwrap();
+        let conn = Connection::open(&path).unwrap();
+
+        let equipment = inventory.suits.len()
+            + inventory.long_guns.len()
+            + inventory.pistols.len()
+            + inventory.melee.len()
+            + inventory.space_suits.len()
+            + inventory.space_guns.len()
+            + inventory.space_melee.len();
+        assert_eq!(
+            count(&conn, "SELECT COUNT(*) FROM equipment"),
+            equipment as i64
+        );
+        assert_eq!(
+            count(&conn, "SELECT COUNT(*) FROM upgrades"),
+            inventory.upgrades.len() as i64
+        );
+        assert_eq!(
+            count(
+                &conn,
+                "SELECT item_count FROM misc_items
+                 WHERE item_type = '/Lotus/Types/Items/MiscItems/NeuralSensor'"
+            ),
+            353
+        );
+        assert_eq!(
+            count(
+                &conn,
+                "SELECT item_count FROM recipes
+                 WHERE item_type LIKE '%PaladinChassisBlueprint'"
+            ),
+            5
+        );
+        let joined: String = conn
+            .query_row(
+                "SELECT catalog_items.name FROM upgrades
+                 JOIN catalog_items ON catalog_items.unique_name = upgrades.item_type
+                 LIMIT 1",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        drop(conn);
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(joined, "Test Mod");
+    }
+}
@@ -0,0 +1,378 @@
+use std::collections::{BTreeMap, HashMap};
+use std::env;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+use crate::inventory::Inventory;
+use crate::inventory::mission::Mission;
+use crate::itemdata::catalog;
+
+/// Every junction on the star chart, by `Missions` tag. Taken from the `Missions` of
+/// `testdata/sample_inventory.json`, an account with all of them completed.
+pub const JUNCTIONS: [&str; 13] = [
+    "EarthToVenusJunction",
+    "VenusToMercuryJunction",
+    "EarthToMarsJunction",
+    "MarsToPhobosJunction",
+    "MarsToCeresJunction",
+    "CeresToJupiterJunction",
+    "JupiterToEuropaJunction",
+    "JupiterToSaturnJunction",
+    "SaturnToUranusJunction",
+    "UranusToNeptuneJunction",
+    "NeptuneToPlutoJunction",
+    "PlutoToErisJunction",
+    "ErisToSednaJunction",
+];
+
+/// One star chart node. Field names follow the warframe-items `Node.json` export.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Node {
+    /// Node id as used by `Missions`, e.g. `SolNode27`
+    pub unique_name: String,
+    /// e.g. "E Prime"
+    pub name: String,
+    /// Planet, e.g. "Earth"
+    #[serde(alias = "planet")]
+    pub system_name: String,
+    #[serde(alias = "type")]
+    pub mission_type: Option<String>,
+    #[serde(alias = "enemy")]
+    pub faction: Option<String>,
+    pub min_enemy_level: Option<i64>,
+    pub max_enemy_level: Option<i64>,
+}
+
+impl Node {
+    /// e.g. "E Prime (Earth)"
+    pub fn display_name(&self) -> String {
+        format!("{} ({})", self.name, self.system_name)
+    }
+
+    /// e.g. "1-3", `None` if the data file has no levels for this node
+    pub fn level_range(&self) -> Option<String> {
+        match (self.min_enemy_level, self.max_enemy_level) {
+            (Some(min), Some(max)) => Some(format!("{}-{}", min, max)),
+            _ => None,
+        }
+    }
+}
+
+/// Star chart nodes by id.
+#[derive(Default, Debug, Clone)]
+pub struct NodeCatalog {
+    nodes: HashMap<String, Node>,
+}
+
+impl NodeCatalog {
+    /// Loads a JSON array of nodes
+    pub fn load(path: &Path) -> anyhow::Result<NodeCatalog> {
+        let raw = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let nodes: Vec<Node> = serde_json::from_str(&raw)
+            .with_context(|| format!("Failed to parse {}", path.display()))?;
+        Ok(nodes.into_iter().collect())
+    }
+
+    pub fn get(&self, unique_name: &str) -> Option<&Node> {
+        self.nodes.get(unique_name)
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Node> {
+        self.nodes.values()
+    }
+}
+
+impl FromIterator<Node> for NodeCatalog {
+    fn from_iter<I: IntoIterator<Item = Node>>(iter: I) -> NodeCatalog {
+        NodeCatalog {
+            nodes: iter
+                .into_iter()
+                .map(|node| (node.unique_name.clone(), node))
+                .collect(),
+        }
+    }
+}
+
+/// Locates the node data file.
+/// Checks `WF_NODES_FILE` first, then `nodes.json` in the app cache directory,
+/// then `Node.json` next to the warframe-items data.
+pub fn find_nodes_file() -> Option<PathBuf> {
+    if let Ok(custom_path) = env::var("WF_NODES_FILE") {
+        let path = PathBuf::from(custom_path);
+        if path.exists() {
+            return Some(path);
+        }
+    }
+
+    if let Some(cache_dir) = dirs::cache_dir() {
+        let path = cache_dir.join("wf-info-2/nodes.json");
+        if path.exists() {
+            return Some(path);
+        }
+    }
+
+    catalog::find_items_dir()
+        .map(|dir| dir.join("Node.json"))
+        .filter(|path| path.exists())
+}
+
+/// Completion of the nodes of one planet.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlanetProgress {
+    pub planet: String,
+    pub nodes: usize,
+    pub completed: usize,
+    pub steel_path: usize,
+    /// Nodes never completed, sorted by name
+    pub missing: Vec<Node>,
+}
+
+impl PlanetProgress {
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JunctionProgress {
+    pub tag: String,
+    pub from: String,
+    pub to: String,
+    pub completed: bool,
+    pub steel_path: bool,
+}
+
+/// Star chart and Steel Path completion computed from `Missions`.
+/// Without node data only the totals and junctions are known: every completed tag is counted,
+/// including dark sectors and Railjack nodes, and `planets` is empty.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StarChartReport {
+    /// Catalog nodes, 0 without node data
+    pub nodes: usize,
+    pub completed: usize,
+    pub steel_path: usize,
+    /// Sorted by planet name
+    pub planets: Vec<PlanetProgress>,
+    /// In star chart order
+    pub junctions: Vec<JunctionProgress>,
+    /// Completed tags the node catalog does not know, e.g. dark sectors and Railjack nodes
+    pub uncatalogued: Vec<String>,
+}
+
+impl StarChartReport {
+    pub fn build(inventory: &Inventory, nodes: &NodeCatalog) -> StarChartReport {
+        let missions: HashMap<&str, &Mission> = inventory
+            .missions
+            .iter()
+            .map(|mission| (mission.tag.as_str(), mission))
+            .collect();
+
+        let mut planets: BTreeMap<&str, PlanetProgress> = BTreeMap::new();
+        for node in nodes.iter() {
+            let progress =
+                planets
+                    .entry(node.system_name.as_str())
+                    .or_insert_with(|| PlanetProgress {
+                        planet: node.system_name.clone(),
+                        nodes: 0,
+                        completed: 0,
+                        steel_path: 0,
+                        missing: Vec::new(),
+                    });
+            progress.nodes += 1;
+            match missions.get(node.unique_name.as_str()) {
+                Some(mission) if mission.completed() => {
+                    progress.completed += 1;
+                    if mission.steel_path() {
+                        progress.steel_path += 1;
+                    }
+                }
+                _ => progress.missing.push(node.clone()),
+            }
+        }
+        for progress in planets.values_mut() {
+            progress.missing.sort_by(|a, b| a.name.cmp(&b.name));
+        }
+        let planets: Vec<PlanetProgress> = planets.into_values().collect();
+
+        let junctions = JUNCTIONS
+            .iter()
+            .map(|tag| {
+                let mission = missions.get(tag);
+                let (from, to) = tag
+                    .strip_suffix("Junction")
+                    .and_then(|tag| tag.split_once("To"))
+                    .unwrap_or_default();
+                JunctionProgress {
+                    tag: tag.to_string(),
+                    from: from.to_string(),
+                    to: to.to_string(),
+                    completed: mission.is_some_and(|mission| mission.completed()),
+                    steel_path: mission.is_some_and(|mission| mission.steel_path()),
+                }
+            })
+            .collect();
+
+        if nodes.is_empty() {
+            let completed: Vec<&Mission> = inventory
+                .missions
+                .iter()
+                .filter(|mission| mission.junction().is_none() && mission.completed())
+                .collect();
+            return StarChartReport {
+                completed: completed.len(),
+                steel_path: completed
+                    .iter()
+                    .filter(|mission| mission.steel_path())
+                    .count(),
+                junctions,
+                ..Default::default()
+            };
+        }
+
+        let mut uncatalogued: Vec<String> = inventory
+            .missions
+            .iter()
+            .filter(|mission| nodes.get(&mission.tag).is_none() && mission.junction().is_none())
+            .map(|mission| mission.tag.clone())
+            .collect();
+        uncatalogued.sort();
+
+        StarChartReport {
+            nodes: nodes.len(),
+            completed: planets.iter().map(|planet| planet.completed).sum(),
+            steel_path: planets.iter().map(|planet| planet.steel_path).sum(),
+            planets,
+            junctions,
+            uncatalogued,
+        }
+    }
+
+    pub fn junctions_completed(&self) -> usize {
+        self.junctions
+            .iter()
+            .filter(|junction| junction.completed)
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::tests::load_test_inventory;
+
+    fn node(unique_name: &str, name: &str, planet: &str) -> Node {
+        Node {
+            unique_name: unique_name.to_string(),
+            name: name.to_string(),
+            system_name: planet.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_load_nodes() {
+        let path = env::temp_dir().join(format!("wf-info-2-nodes-{}.json", std::process::id()));
+        let js = r#"[
+  {
+    "uniqueName": "SolNode27",
+    "name": "E Prime",
+    "systemName": "Earth",
+    "missionType": "Exterminate",
+    "faction": "Grineer",
+    "minEnemyLevel": 1,
+    "maxEnemyLevel": 3
+  },
+  { "uniqueName": "SolNode22", "name": "Tessera", "planet": "Venus", "type": "Defense" }
+]"#;
+        std::fs::write(&path, js).unwrap();
+        let nodes = NodeCatalog::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(nodes.len(), 2);
+        let e_prime = nodes.get("SolNode27").unwrap();
+        assert_eq!(e_prime.display_name(), "E Prime (Earth)");
+        assert_eq!(e_prime.level_range().as_deref(), Some("1-3"));
+        let tessera = nodes.get("SolNode22").unwrap();
+        assert_eq!(tessera.system_name, "Venus");
+        assert_eq!(tessera.mission_type.as_deref(), Some("Defense"));
+        assert_eq!(tessera.level_range(), None);
+    }
+
+    #[test]
+    fn test_star_chart_report() {
+        let nodes: NodeCatalog = [
+            node("SolNode27", "E Prime", "Earth"),
+            node("SolNode89", "Mariana", "Earth"),
+            node("SolNode22", "Tessera", "Venus"),
+            node("SolNode104", "Fossa", "Venus"),
+            node("SolNode3", "Cordelia", "Uranus"),
+        ]
+        .into_iter()
+        .collect();
+
+        let inventory = load_test_inventory();
+        let report = StarChartReport::build(&inventory, &nodes);
+        assert_eq!(report.nodes, 5);
+        assert_eq!(report.completed, 4);
+        assert_eq!(report.steel_path, 3);
+
+        let planets: Vec<(&str, usize, usize)> = report
+            .planets
+            .iter()
+            .map(|planet| (planet.planet.as_str(), planet.completed, planet.steel_path))
+            .collect();
+        assert_eq!(
+            planets,
+            vec![("Earth", 2, 2), ("Uranus", 0, 0), ("Venus", 2, 1)]
+        );
+        assert!(report.planets[0].is_complete());
+        assert_eq!(report.planets[1].missing[0].name, "Cordelia");
+
+        assert_eq!(report.junctions.len(), 13);
+        assert_eq!(report.junctions_completed(), 13);
+        let mercury = &report.junctions[1];
+        assert_eq!(
+            (mercury.from.as_str(), mercury.to.as_str()),
+            ("Venus", "Mercury")
+        );
+        assert!(mercury.completed && !mercury.steel_path);
+
+        // Every tag is either a catalog node, a junction or uncatalogued
+        assert_eq!(
+            report.uncatalogued.len(),
+            inventory.missions.len() - 4 - JUNCTIONS.len()
+        );
+        assert!(report.uncatalogued.contains(&"ClanNode9".to_string()));
+    }
+
+    #[test]
+    fn test_star_chart_without_nodes() {
+        let inventory = load_test_inventory();
+        let report = StarChartReport::build(&inventory, &NodeCatalog::default());
+        assert_eq!(report.nodes, 0);
+        assert!(report.planets.is_empty());
+        assert!(report.uncatalogued.is_empty());
+        assert_eq!(report.junctions_completed(), 13);
+
+        let completed = inventory
+            .missions
+            .iter()
+            .filter(|mission| mission.junction().is_none() && mission.completed())
+            .count();
+        assert_eq!(report.completed, completed);
+        assert!(report.steel_path > 0 && report.steel_path <= completed);
+    }
+}
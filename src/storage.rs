@@ -9,7 +9,9 @@ use rand::{Rng, rng};
 use sha2::{Digest, Sha256};
 use std::fs::{self, File};
 use std::io::Write;
+use std::path::{Path, PathBuf};
 
+use crate::snapshots::SnapshotStore;
 use crate::{inventory, profile::ProfileData};
 
 // Get key from build-time environment variable
@@ -25,6 +27,13 @@ const INVENTORY_IV: [u8; 16] = [
     49, 50, 70, 71, 66, 51, 54, 45, 76, 69, 51, 45, 113, 61, 57, 0,
 ];
 
+/// `wf-info-2` directory under the platform cache dir, not created if missing
+pub fn app_cache_dir() -> anyhow::Result<PathBuf> {
+    let cache_dir =
+        dirs::cache_dir().ok_or_else(|| anyhow::anyhow!("Could not find cache directory"))?;
+    Ok(cache_dir.join("wf-info-2"))
+}
+
 pub fn save_encrypted_profile(profile: &ProfileData) -> anyhow::Result<()> {
     let json = serde_json::to_vec(profile).context("Failed to serialize profile")?;
 
@@ -86,9 +95,6 @@ pub fn delete_profile() -> anyhow::Result<()> {
 /// 1. inventory.json - Pretty-printed JSON for human readability
 /// 2. lastData.dat - AES-128-CBC encrypted (compatible with C++ reference)
 pub fn save_inventory(inventory: &inventory::Inventory) -> anyhow::Result<()> {
-    use aes::cipher::{BlockEncryptMut, KeyIvInit, block_padding::Pkcs7};
-    type Aes128CbcEnc = cbc::Encryptor<aes::Aes128>;
-
     let cache_dir =
         dirs::cache_dir().ok_or_else(|| anyhow::anyhow!("Could not find cache directory"))?;
     let app_cache_dir = cache_dir.join("wf-info-2");
@@ -105,6 +111,26 @@ pub fn save_inventory(inventory: &inventory::Inventory) -> anyhow::Result<()> {
     log::info!("Saved inventory JSON to {}", json_path.display());
 
     // Save encrypted lastData.dat (AES-128-CBC with PKCS7 padding)
+    let ciphertext = encrypt_inventory(inventory)?;
+
+    let dat_path = app_cache_dir.join("lastData.dat");
+    fs::write(&dat_path, ciphertext).context("Failed to write lastData.dat")?;
+    log::info!("Saved encrypted inventory to {}", dat_path.display());
+
+    // History is best effort, the fresh inventory is already saved
+    let snapshots = SnapshotStore::open(app_cache_dir.join("snapshots"));
+    if let Err(e) = snapshots.save(inventory, chrono::Utc::now()) {
+        log::warn!("Failed to save inventory snapshot: {:#}", e);
+    }
+
+    Ok(())
+}
+
+/// Inventory JSON encrypted the way `lastData.dat` is stored
+pub fn encrypt_inventory(inventory: &inventory::Inventory) -> anyhow::Result<Vec<u8>> {
+    use aes::cipher::{BlockEncryptMut, KeyIvInit, block_padding::Pkcs7};
+    type Aes128CbcEnc = cbc::Encryptor<aes::Aes128>;
+
     let json_bytes = serde_json::to_vec(inventory).context("Failed to serialize inventory")?;
 
     // Calculate padded size (PKCS7 pads to block size boundary)
@@ -117,10 +143,66 @@ pub fn save_inventory(inventory: &inventory::Inventory) -> anyhow::Result<()> {
     let ciphertext = cipher
         .encrypt_padded_mut::<Pkcs7>(&mut buffer, json_bytes.len())
         .map_err(|e| anyhow::anyhow!("Encryption error: {:?}", e))?;
+    Ok(ciphertext.to_vec())
+}
 
-    let dat_path = app_cache_dir.join("lastData.dat");
-    fs::write(&dat_path, ciphertext).context("Failed to write lastData.dat")?;
-    log::info!("Saved encrypted inventory to {}", dat_path.display());
+/// Reverses `encrypt_inventory`
+pub fn decrypt_inventory(data: &[u8]) -> anyhow::Result<inventory::Inventory> {
+    use aes::cipher::{BlockDecryptMut, KeyIvInit, block_padding::Pkcs7};
+    type Aes128CbcDec = cbc::Decryptor<aes::Aes128>;
+
+    let mut buffer = data.to_vec();
+    let cipher = Aes128CbcDec::new(&INVENTORY_KEY.into(), &INVENTORY_IV.into());
+    let json = cipher
+        .decrypt_padded_mut::<Pkcs7>(&mut buffer)
+        .map_err(|e| anyhow::anyhow!("Decryption error: {:?}", e))?;
+    serde_json::from_slice(json).context("Failed to parse decrypted inventory")
+}
 
-    Ok(())
+/// Loads the inventory.json written by the last `save_inventory`
+pub fn load_inventory() -> anyhow::Result<inventory::Inventory> {
+    load_inventory_file(&app_cache_dir()?.join("inventory.json"))
+}
+
+/// Loads an inventory saved anywhere, either plain JSON or encrypted like `lastData.dat`
+pub fn load_inventory_file(path: &Path) -> anyhow::Result<inventory::Inventory> {
+    let data = fs::read(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let is_json = data
+        .iter()
+        .find(|byte| !byte.is_ascii_whitespace())
+        .is_some_and(|byte| *byte == b'{');
+    if is_json {
+        serde_json::from_slice(&data).with_context(|| format!("Failed to parse {}", path.display()))
+    } else {
+        decrypt_inventory(&data).with_context(|| format!("Failed to decrypt {}", path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::tests::load_test_inventory;
+
+    #[test]
+    fn test_load_inventory_file() {
+        let inventory = load_test_inventory();
+        let dir = std::env::temp_dir().join(format!("wf-info-2-storage-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let dat_path = dir.join("lastData.dat");
+        fs::write(&dat_path, encrypt_inventory(&inventory).unwrap()).unwrap();
+        let json_path = dir.join("inventory.json");
+        fs::write(
+            &json_path,
+            serde_json::to_string_pretty(&inventory).unwrap(),
+        )
+        .unwrap();
+
+        let from_dat = load_inventory_file(&dat_path);
+        let from_json = load_inventory_file(&json_path);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(from_dat.unwrap(), inventory);
+        assert_eq!(from_json.unwrap(), inventory);
+        assert!(decrypt_inventory(b"not an inventory").is_err());
+    }
 }
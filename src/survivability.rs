@@ -0,0 +1,412 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::enriched::InventorySection;
+use crate::inventory::Inventory;
+use crate::inventory::archon::ArchonShard;
+use crate::inventory::suit::Suit;
+use crate::itemdata::catalog::{Catalog, CatalogItem};
+use crate::itemdata::warframe::Warframe;
+use crate::loadout::{self, Build, SlotKind};
+use crate::mastery::ItemMastery;
+
+/// Armor value at which damage reduction reaches 50%
+pub const ARMOR_CONSTANT: f64 = 300.0;
+
+/// Armor damage reduction never goes above this
+pub const MAX_DAMAGE_REDUCTION: f64 = 0.9;
+
+/// Warframe stat a mod, arcane or shard can change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum SuitStat {
+    Health,
+    Shield,
+    Armor,
+    Energy,
+    SprintSpeed,
+}
+
+/// `value` is a fraction of the rank 30 stat (`1.0` for "+100%")
+/// or a flat amount added after percentage bonuses.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SuitBonus {
+    pub stat: SuitStat,
+    pub value: f64,
+    pub flat: bool,
+}
+
+impl SuitBonus {
+    pub fn percent(stat: SuitStat, value: f64) -> SuitBonus {
+        SuitBonus {
+            stat,
+            value,
+            flat: false,
+        }
+    }
+
+    pub fn flat(stat: SuitStat, value: f64) -> SuitBonus {
+        SuitBonus {
+            stat,
+            value,
+            flat: true,
+        }
+    }
+}
+
+/// Parses one `level_stats` entry of a mod or arcane, e.g. "+440% Health" or "+150 Armor".
+/// Conditional effects like "On Damaged: ..." are skipped.
+pub fn parse_suit_stats(text: &str) -> Vec<SuitBonus> {
+    text.lines().filter_map(parse_suit_stat_line).collect()
+}
+
+fn parse_suit_stat_line(line: &str) -> Option<SuitBonus> {
+    let (value, name) = line.trim().split_once(' ')?;
+    let value = value.strip_prefix('+')?;
+    let stat = match name.trim() {
+        "Health" => SuitStat::Health,
+        "Shield Capacity" | "Shields" => SuitStat::Shield,
+        "Armor" => SuitStat::Armor,
+        "Energy Max" | "Energy" => SuitStat::Energy,
+        "Sprint Speed" => SuitStat::SprintSpeed,
+        _ => return None,
+    };
+    match value.strip_suffix('%') {
+        Some(percent) => Some(SuitBonus::percent(
+            stat,
+            percent.parse::<f64>().ok()? / 100.0,
+        )),
+        None => Some(SuitBonus::flat(stat, value.parse().ok()?)),
+    }
+}
+
+/// Survivability part of an archon shard, tauforged value included
+pub fn shard_bonus(shard: &ArchonShard) -> Option<SuitBonus> {
+    let stat = match shard.upgrade_name() {
+        "WarframeHealthMax" => SuitStat::Health,
+        "WarframeShieldMax" => SuitStat::Shield,
+        "WarframeArmourMax" => SuitStat::Armor,
+        "WarframeEnergyMax" => SuitStat::Energy,
+        _ => return None,
+    };
+    Some(SuitBonus::flat(stat, shard.value()?))
+}
+
+/// Survivability bonuses of the shards socketed in a suit
+pub fn suit_shard_bonuses(suit: &Suit) -> Vec<SuitBonus> {
+    suit.archon_shards()
+        .filter_map(|shard| shard_bonus(&shard))
+        .collect()
+}
+
+/// Bonuses of the mods, aura and arcanes equipped in a build
+pub fn build_suit_bonuses(build: &Build, catalog: &Catalog) -> Vec<SuitBonus> {
+    build
+        .slots
+        .iter()
+        .filter(|slot| slot.kind != SlotKind::Stance)
+        .flat_map(|slot| {
+            let level_stats: Vec<&Vec<String>> = match catalog.get(&slot.item_type) {
+                Some(CatalogItem::Mod(item)) => {
+                    item.level_stats.iter().map(|level| &level.stats).collect()
+                }
+                Some(CatalogItem::Arcane(item)) => {
+                    item.level_stats.iter().map(|level| &level.stats).collect()
+                }
+                _ => Vec::new(),
+            };
+            let rank = (slot.rank.max(0) as usize).min(level_stats.len().saturating_sub(1));
+            level_stats
+                .get(rank)
+                .map(|stats| {
+                    stats
+                        .iter()
+                        .flat_map(|text| parse_suit_stats(text))
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+/// Base stats of a warframe at the given rank.
+/// Health and shields gain up to +200% and energy up to +50% by rank 30,
+/// in ten steps of three ranks. Armor and sprint speed do not scale.
+pub fn ranked_stats(warframe: &Warframe, rank: i64) -> BTreeMap<SuitStat, f64> {
+    let steps = (rank.clamp(0, 30) / 3) as f64 / 10.0;
+    BTreeMap::from([
+        (
+            SuitStat::Health,
+            warframe.health as f64 * (1.0 + 2.0 * steps),
+        ),
+        (
+            SuitStat::Shield,
+            warframe.shield as f64 * (1.0 + 2.0 * steps),
+        ),
+        (
+            SuitStat::Energy,
+            warframe.power as f64 * (1.0 + 0.5 * steps),
+        ),
+        (SuitStat::Armor, warframe.armor as f64),
+        (
+            SuitStat::SprintSpeed,
+            warframe.sprint_speed.or(warframe.sprint).unwrap_or(1.0),
+        ),
+    ])
+}
+
+/// Share of armor damage reduction, capped at `MAX_DAMAGE_REDUCTION`
+pub fn damage_reduction(armor: f64) -> f64 {
+    (armor.max(0.0) / (armor.max(0.0) + ARMOR_CONSTANT)).min(MAX_DAMAGE_REDUCTION)
+}
+
+/// Enemy faction the effective HP is computed against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Faction {
+    Grineer,
+    Corpus,
+    Infested,
+    Corrupted,
+    Murmur,
+}
+
+impl Faction {
+    pub const ALL: [Faction; 5] = [
+        Faction::Grineer,
+        Faction::Corpus,
+        Faction::Infested,
+        Faction::Corrupted,
+        Faction::Murmur,
+    ];
+
+    /// Assumed share of the faction's damage that is toxin and skips shields.
+    /// This is an estimate, not game data: damage types vary per enemy, so effective HP
+    /// against a faction with a bypass is only an approximation.
+    pub fn shield_bypass(&self) -> f64 {
+        match self {
+            Faction::Grineer | Faction::Corpus | Faction::Murmur => 0.0,
+            Faction::Infested => 0.35,
+            Faction::Corrupted => 0.1,
+        }
+    }
+}
+
+/// Raw damage taken before dying, when `bypass` of each hit skips shields.
+/// Health can run out before shields do if enough damage bypasses them.
+pub fn effective_hp(health: f64, shield: f64, armor: f64, bypass: f64) -> f64 {
+    let mitigation = 1.0 - damage_reduction(armor);
+    let layered = shield + health / mitigation;
+    if bypass > 0.0 {
+        layered.min(health / (bypass * mitigation))
+    } else {
+        layered
+    }
+}
+
+/// Modded warframe stats and effective HP.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Survivability {
+    pub unique_name: String,
+    pub name: String,
+    pub rank: i64,
+    pub health: f64,
+    pub shield: f64,
+    pub armor: f64,
+    pub energy: f64,
+    pub sprint_speed: f64,
+    pub damage_reduction: f64,
+    /// Approximate for factions with a [`Faction::shield_bypass`]
+    pub effective_hp: BTreeMap<Faction, f64>,
+}
+
+impl Survivability {
+    /// Percentage bonuses scale the ranked stat, flat bonuses are added afterwards
+    pub fn calculate(warframe: &Warframe, rank: i64, bonuses: &[SuitBonus]) -> Survivability {
+        let ranked = ranked_stats(warframe, rank);
+        let stat = |stat: SuitStat| -> f64 {
+            let (percent, flat) = bonuses.iter().filter(|bonus| bonus.stat == stat).fold(
+                (0.0, 0.0),
+                |(percent, flat), bonus| {
+                    if bonus.flat {
+                        (percent, flat + bonus.value)
+                    } else {
+                        (percent + bonus.value, flat)
+                    }
+                },
+            );
+            (ranked[&stat] * (1.0 + percent) + flat).max(0.0)
+        };
+
+        let health = stat(SuitStat::Health);
+        let shield = stat(SuitStat::Shield);
+        let armor = stat(SuitStat::Armor);
+
+        Survivability {
+            unique_name: warframe.unique_name.clone(),
+            name: warframe.name.clone(),
+            rank,
+            health,
+            shield,
+            armor,
+            energy: stat(SuitStat::Energy),
+            sprint_speed: stat(SuitStat::SprintSpeed),
+            damage_reduction: damage_reduction(armor),
+            effective_hp: Faction::ALL
+                .iter()
+                .map(|faction| {
+                    (
+                        *faction,
+                        effective_hp(health, shield, armor, faction.shield_bypass()),
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+/// One row per owned warframe using its first config with mods, plus its archon shards
+pub fn suit_survivability(inventory: &Inventory, catalog: &Catalog) -> Vec<Survivability> {
+    let builds: Vec<Build> = loadout::resolve_builds(inventory, catalog)
+        .into_iter()
+        .filter(|build| build.section == InventorySection::Suits)
+        .collect();
+
+    let mut rows: Vec<Survivability> = inventory
+        .suits
+        .iter()
+        .filter_map(|suit| {
+            let Some(CatalogItem::Warframe(warframe)) = catalog.get(&suit.item_type) else {
+                return None;
+            };
+            let rank = ItemMastery::new(&suit.item_type, suit.xp.unwrap_or(0), catalog).rank;
+
+            let mut bonuses = builds
+                .iter()
+                .find(|build| build.item_id == suit.item_id.oid)
+                .map(|build| build_suit_bonuses(build, catalog))
+                .unwrap_or_default();
+            bonuses.extend(suit_shard_bonuses(suit));
+
+            Some(Survivability::calculate(warframe, rank, &bonuses))
+        })
+        .collect();
+    rows.sort_by(|a, b| a.name.cmp(&b.name));
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::archon::ShardColor;
+    use crate::inventory::tests::load_test_inventory;
+    use crate::itemdata::mods;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-6,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    fn khora_prime() -> Warframe {
+        Warframe {
+            name: "Khora Prime".to_string(),
+            unique_name: "/Lotus/Powersuits/Khora/KhoraPrime".to_string(),
+            health: 100,
+            shield: 100,
+            armor: 300,
+            power: 150,
+            sprint_speed: Some(1.0),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_parse_suit_stats() {
+        assert_eq!(
+            parse_suit_stats("+440% Health"),
+            vec![SuitBonus::percent(SuitStat::Health, 4.4)]
+        );
+        assert_eq!(
+            parse_suit_stats("+150 Armor\r\n+20% Sprint Speed"),
+            vec![
+                SuitBonus::flat(SuitStat::Armor, 150.0),
+                SuitBonus::percent(SuitStat::SprintSpeed, 0.2),
+            ]
+        );
+        assert!(parse_suit_stats("On Damaged: 20% chance for +900 Armor").is_empty());
+    }
+
+    #[test]
+    fn test_shard_bonus() {
+        let shard = |upgrade: &str, tauforged: bool| ArchonShard {
+            color: ShardColor::Azure,
+            tauforged,
+            upgrade_type: format!(
+                "/Lotus/Upgrades/Invigorations/ArchonCrystalUpgrades/ArchonCrystalUpgrade{upgrade}"
+            ),
+        };
+        assert_eq!(
+            shard_bonus(&shard("WarframeArmourMax", false)),
+            Some(SuitBonus::flat(SuitStat::Armor, 150.0))
+        );
+        assert_eq!(
+            shard_bonus(&shard("WarframeShieldMaxMythic", true)),
+            Some(SuitBonus::flat(SuitStat::Shield, 225.0))
+        );
+        assert_eq!(shard_bonus(&shard("WarframeCastingSpeed", false)), None);
+    }
+
+    #[test]
+    fn test_effective_hp() {
+        assert_close(damage_reduction(300.0), 0.5);
+        assert_close(damage_reduction(10_000.0), MAX_DAMAGE_REDUCTION);
+        assert_close(effective_hp(300.0, 300.0, 300.0, 0.0), 900.0);
+        // Half of each hit skips shields: health runs out after 750 raw damage
+        assert_close(effective_hp(300.0, 1000.0, 300.0, 0.5), 1200.0);
+    }
+
+    #[test]
+    fn test_ranked_stats() {
+        let stats = ranked_stats(&khora_prime(), 30);
+        assert_close(stats[&SuitStat::Health], 300.0);
+        assert_close(stats[&SuitStat::Energy], 225.0);
+        assert_close(stats[&SuitStat::Armor], 300.0);
+
+        let stats = ranked_stats(&khora_prime(), 15);
+        assert_close(stats[&SuitStat::Shield], 200.0);
+    }
+
+    #[test]
+    fn test_suit_survivability() {
+        let catalog: Catalog = [
+            CatalogItem::Warframe(khora_prime()),
+            CatalogItem::Mod(mods::Mod {
+                name: "Physique".to_string(),
+                unique_name: "/Lotus/Upgrades/Mods/Aura/PlayerHealthAuraMod".to_string(),
+                fusion_limit: Some(5),
+                level_stats: (0..=5)
+                    .map(|rank| mods::LevelStat {
+                        stats: vec![format!("+{}% Health", 3 * (rank + 1))],
+                    })
+                    .collect(),
+                ..Default::default()
+            }),
+        ]
+        .into_iter()
+        .collect();
+
+        let rows = suit_survivability(&load_test_inventory(), &catalog);
+        assert_eq!(rows.len(), 1);
+
+        // Rank 30, Physique at rank 5 and an armor and an energy shard
+        let khora = &rows[0];
+        assert_eq!(khora.rank, 30);
+        assert_close(khora.health, 354.0);
+        assert_close(khora.shield, 300.0);
+        assert_close(khora.armor, 450.0);
+        assert_close(khora.energy, 275.0);
+        assert_close(khora.damage_reduction, 0.6);
+        assert_close(khora.effective_hp[&Faction::Grineer], 300.0 + 354.0 / 0.4);
+    }
+}
@@ -0,0 +1,188 @@
+use serde::{Deserialize, Serialize};
+
+use crate::inventory::Inventory;
+use crate::inventory::affiliation::{Affiliation, Syndicate};
+
+/// Total standing at which ranks 1 to 5 start. Each rank caps standing at the
+/// next rank's start until the player ranks up.
+pub const RANK_STANDING: [i64; 5] = [5_000, 27_000, 71_000, 141_000, 240_000];
+
+/// Total standing at which relay syndicate enemy ranks -1 and -2 start
+pub const ENEMY_RANK_STANDING: [i64; 2] = [-5_000, -27_000];
+
+/// Nightwave standing per rank
+pub const NIGHTWAVE_RANK_STANDING: i64 = 10_000;
+
+/// How a syndicate's ranks relate to its standing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RankLadder {
+    /// Relay syndicates, ranks -2 to 5
+    Faction,
+    /// Ranks 0 to `max` on the usual standing ladder
+    Standard { max: i64 },
+    /// 10,000 standing per rank with no upper limit
+    Nightwave,
+    /// No ranks tied to standing
+    Unranked,
+}
+
+impl RankLadder {
+    pub fn for_syndicate(syndicate: &Syndicate) -> RankLadder {
+        match syndicate {
+            s if s.is_faction() => RankLadder::Faction,
+            Syndicate::Conclave
+            | Syndicate::Ostron
+            | Syndicate::Quills
+            | Syndicate::SolarisUnited
+            | Syndicate::Ventkids
+            | Syndicate::VoxSolaris
+            | Syndicate::Entrati
+            | Syndicate::Holdfasts
+            | Syndicate::Cavia
+            | Syndicate::Hex => RankLadder::Standard { max: 5 },
+            Syndicate::Necraloid => RankLadder::Standard { max: 3 },
+            Syndicate::Nightwave(_) => RankLadder::Nightwave,
+            _ => RankLadder::Unranked,
+        }
+    }
+
+    /// Total standing at which a rank starts, `None` outside the ladder
+    pub fn rank_start(&self, rank: i64) -> Option<i64> {
+        match self {
+            RankLadder::Faction | RankLadder::Standard { .. } if rank == 0 => Some(0),
+            RankLadder::Faction if rank < 0 => {
+                ENEMY_RANK_STANDING.get((-rank - 1) as usize).copied()
+            }
+            RankLadder::Faction if rank <= 5 => RANK_STANDING.get((rank - 1) as usize).copied(),
+            RankLadder::Standard { max } if rank > 0 && rank <= *max => {
+                RANK_STANDING.get((rank - 1) as usize).copied()
+            }
+            RankLadder::Nightwave if rank >= 0 => Some(rank * NIGHTWAVE_RANK_STANDING),
+            _ => None,
+        }
+    }
+
+    /// Standing still needed before the next rank up, `None` at the top or when unranked
+    pub fn standing_to_next(&self, rank: i64, standing: i64) -> Option<i64> {
+        let next = self.rank_start(rank + 1)?;
+        Some((next - standing).max(0))
+    }
+
+    pub fn max_rank(&self) -> Option<i64> {
+        match self {
+            RankLadder::Faction => Some(5),
+            RankLadder::Standard { max } => Some(*max),
+            RankLadder::Nightwave | RankLadder::Unranked => None,
+        }
+    }
+}
+
+/// Standing and rank progress with one syndicate.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyndicateStanding {
+    pub syndicate: Syndicate,
+    pub name: String,
+    pub standing: i64,
+    pub rank: i64,
+    pub ladder: RankLadder,
+    pub standing_to_next: Option<i64>,
+    /// Standing that can still be earned today
+    pub daily_remaining: Option<i64>,
+    /// Earned rank-up rewards not claimed yet
+    pub unused_favors: Vec<i64>,
+    /// Pledged relay syndicate
+    pub supported: bool,
+}
+
+impl SyndicateStanding {
+    pub fn new(affiliation: &Affiliation, inventory: &Inventory) -> SyndicateStanding {
+        let ladder = RankLadder::for_syndicate(&affiliation.tag);
+        SyndicateStanding {
+            name: affiliation.tag.name().to_string(),
+            standing: affiliation.standing,
+            rank: affiliation.rank(),
+            ladder,
+            standing_to_next: ladder.standing_to_next(affiliation.rank(), affiliation.standing),
+            daily_remaining: inventory.daily_affiliations.remaining(&affiliation.tag),
+            unused_favors: affiliation.unused_favors().collect(),
+            supported: inventory.supported_syndicates.as_ref() == Some(&affiliation.tag),
+            syndicate: affiliation.tag.clone(),
+        }
+    }
+
+    pub fn is_max_rank(&self) -> bool {
+        self.ladder
+            .max_rank()
+            .is_some_and(|max_rank| self.rank >= max_rank)
+    }
+}
+
+/// Every syndicate in `Affiliations`, in inventory order.
+pub fn syndicate_standings(inventory: &Inventory) -> Vec<SyndicateStanding> {
+    inventory
+        .affiliations
+        .iter()
+        .map(|affiliation| SyndicateStanding::new(affiliation, inventory))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::tests::load_test_inventory;
+
+    #[test]
+    fn test_rank_ladder() {
+        let faction = RankLadder::Faction;
+        assert_eq!(faction.rank_start(-2), Some(-27_000));
+        assert_eq!(faction.rank_start(3), Some(71_000));
+        assert_eq!(faction.rank_start(6), None);
+        assert_eq!(faction.standing_to_next(4, 141_037), Some(98_963));
+        assert_eq!(faction.standing_to_next(5, 262_670), None);
+        assert_eq!(faction.standing_to_next(-2, -71_000), Some(66_000));
+
+        let necraloid = RankLadder::Standard { max: 3 };
+        assert_eq!(necraloid.standing_to_next(2, 60_000), Some(11_000));
+        assert_eq!(necraloid.standing_to_next(3, 102_000), None);
+
+        assert_eq!(
+            RankLadder::Nightwave.standing_to_next(33, 334_280),
+            Some(5_720)
+        );
+        assert_eq!(RankLadder::Unranked.standing_to_next(0, 39_905), None);
+    }
+
+    #[test]
+    fn test_syndicate_standings() {
+        let inventory = load_test_inventory();
+        let standings = syndicate_standings(&inventory);
+        assert_eq!(standings.len(), 37);
+        assert!(
+            standings
+                .iter()
+                .all(|standing| !matches!(standing.syndicate, Syndicate::Unknown(_)))
+        );
+
+        let suda = standings
+            .iter()
+            .find(|standing| standing.syndicate == Syndicate::CephalonSuda)
+            .unwrap();
+        assert!(suda.supported);
+        assert!(suda.is_max_rank());
+        assert_eq!(suda.daily_remaining, Some(0));
+
+        let ventkids = standings
+            .iter()
+            .find(|standing| standing.syndicate == Syndicate::Ventkids)
+            .unwrap();
+        assert_eq!(ventkids.rank, 4);
+        assert_eq!(ventkids.standing_to_next, Some(240_000 - 141_037));
+        assert_eq!(ventkids.daily_remaining, Some(29_500));
+
+        let nightwave = standings
+            .iter()
+            .filter(|standing| matches!(standing.syndicate, Syndicate::Nightwave(_)))
+            .count();
+        assert_eq!(nightwave, 17);
+    }
+}
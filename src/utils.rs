@@ -79,3 +79,31 @@ fn ms_to_dt(ms: i64) -> Option<DateTime<Utc>> {
     let nsec = ((ms % 1000).abs() as u32) * 1_000_000;
     Utc.timestamp_opt(secs, nsec).single()
 }
+
+/// e.g. "1d 4h 12m", rounded up to the minute and "0m" for zero or negative durations
+pub fn format_duration(duration: chrono::Duration) -> String {
+    let minutes = (duration.num_seconds().max(0) + 59) / 60;
+    let (days, hours, minutes) = (minutes / 1440, minutes / 60 % 24, minutes % 60);
+    match (days, hours) {
+        (0, 0) => format!("{}m", minutes),
+        (0, _) => format!("{}h {}m", hours, minutes),
+        _ => format!("{}d {}h {}m", days, hours, minutes),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(chrono::Duration::zero()), "0m");
+        assert_eq!(format_duration(chrono::Duration::seconds(-90)), "0m");
+        assert_eq!(format_duration(chrono::Duration::seconds(61)), "2m");
+        assert_eq!(
+            format_duration(chrono::Duration::minutes(12 * 60 + 5)),
+            "12h 5m"
+        );
+        assert_eq!(format_duration(chrono::Duration::hours(76)), "3d 4h 0m");
+    }
+}
@@ -3,18 +3,24 @@ use notify_debouncer_mini::{DebounceEventResult, new_debouncer};
 use std::fs::{File, metadata};
 use std::io::{BufRead, BufReader, Seek, SeekFrom};
 use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use tokio::sync::mpsc;
 use tokio::time::sleep;
 
 use crate::account::AccountInfo;
 use crate::api;
+use crate::itemdata::catalog::Catalog;
 use crate::logs::{self, LogEvent};
-use crate::process;
+use crate::metrics;
+use crate::process::{self, AuthQuery};
+use crate::session::{self, SessionStart};
+use crate::snapshots::SnapshotStore;
 use crate::storage;
 
 pub async fn observe_warframe_activity(
     app_config_path: PathBuf,
+    catalog: Arc<Catalog>,
 ) -> Result<(), Box<dyn std::error::Error>> {
     log::info!("Watching for Warframe activity...");
 
@@ -35,6 +41,8 @@ pub async fn observe_warframe_activity(
     log::info!("EE.log found at {:?}", log_path);
 
     let mut current_account_id: Option<String> = None;
+    // Auth found at login, reused to refresh the inventory at logout
+    let session_auth: Arc<Mutex<Option<AuthQuery>>> = Arc::default();
     let log_filename = log_path.file_name().ok_or("Invalid log path")?.to_owned();
     let mut last_size = metadata(&log_path)?.len();
     let mut last_position = last_size;
@@ -142,8 +150,16 @@ pub async fn observe_warframe_activity(
                                     account_id
                                 );
 
+                                // A start left by an earlier session must not be reported
+                                // against this one if the login inventory cannot be saved
+                                if let Err(e) = SessionStart::clear() {
+                                    log::error!("Failed to clear previous session: {:#}", e);
+                                }
+
                                 let acc_id = account_id.clone();
                                 let user_name = username.clone();
+                                let session_auth = session_auth.clone();
+                                let catalog = catalog.clone();
                                 tokio::spawn(async move {
                                     // 1. Fetch Profile
                                     match api::fetch_player_profile(&acc_id).await {
@@ -153,6 +169,7 @@ pub async fn observe_warframe_activity(
                                                 user_name,
                                                 profile
                                             );
+                                            metrics::record_profile(&profile);
                                             if let Err(e) =
                                                 storage::save_encrypted_profile(&profile)
                                             {
@@ -195,6 +212,9 @@ pub async fn observe_warframe_activity(
 
                                                 match api::fetch_inventory(&auth).await {
                                                     Ok(inventory) => {
+                                                        metrics::record_inventory(
+                                                            &inventory, &catalog,
+                                                        );
                                                         if let Err(e) =
                                                             storage::save_inventory(&inventory)
                                                         {
@@ -202,6 +222,13 @@ pub async fn observe_warframe_activity(
                                                                 "Failed to save inventory: {}",
                                                                 e
                                                             );
+                                                        } else {
+                                                            begin_session();
+                                                        }
+                                                        if let Ok(mut session_auth) =
+                                                            session_auth.lock()
+                                                        {
+                                                            *session_auth = Some(auth);
                                                         }
                                                     }
                                                     Err(e) => {
@@ -240,6 +267,9 @@ pub async fn observe_warframe_activity(
                                 if let Err(e) = storage::delete_profile() {
                                     log::error!("Failed to delete profile: {}", e);
                                 }
+                                let auth =
+                                    session_auth.lock().ok().and_then(|mut auth| auth.take());
+                                tokio::spawn(report_session(auth, catalog.clone()));
                             }
                             None => {}
                         }
@@ -253,3 +283,39 @@ pub async fn observe_warframe_activity(
         }
     }
 }
+
+fn begin_session() {
+    let started = SnapshotStore::open_default()
+        .and_then(|store| SessionStart::begin(&store, chrono::Utc::now()));
+    if let Err(e) = started {
+        log::error!("Failed to start session: {:#}", e);
+    }
+}
+
+/// Refreshes the inventory one last time if the login auth still works,
+/// then writes the report of the session that just ended
+async fn report_session(auth: Option<AuthQuery>, catalog: Arc<Catalog>) {
+    if let Some(auth) = auth {
+        match api::fetch_inventory(&auth).await {
+            Ok(inventory) => {
+                metrics::record_inventory(&inventory, &catalog);
+                if let Err(e) = storage::save_inventory(&inventory) {
+                    log::error!("Failed to save inventory: {}", e);
+                }
+            }
+            Err(e) => log::warn!(
+                "Could not refresh inventory at logout, reporting up to the last one: {}",
+                e
+            ),
+        }
+    }
+
+    if !matches!(SessionStart::load(), Ok(Some(_))) {
+        log::info!("No session started at login, skipping the session report");
+        return;
+    }
+    match session::finish(&catalog, chrono::Utc::now()) {
+        Ok(path) => log::info!("Session report written to {}", path.display()),
+        Err(e) => log::error!("Failed to write session report: {:#}", e),
+    }
+}
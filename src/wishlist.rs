@@ -0,0 +1,295 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+use crate::crafting::{self, CraftPlan, IngredientKind, Shortfall, Stock};
+use crate::inventory::Inventory;
+use crate::itemdata::catalog::{Catalog, DropInfo};
+use crate::storage;
+
+/// One item the player wants to build.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WishlistEntry {
+    /// Catalog `uniqueName`, e.g. `/Lotus/Powersuits/Paladin/Paladin`
+    pub unique_name: String,
+    pub quantity: i64,
+}
+
+/// Items to farm for, kept in `wishlist.json` in the app cache directory.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Wishlist {
+    pub items: Vec<WishlistEntry>,
+}
+
+impl Wishlist {
+    /// `wishlist.json` in the app cache directory
+    pub fn path() -> anyhow::Result<PathBuf> {
+        Ok(storage::app_cache_dir()?.join("wishlist.json"))
+    }
+
+    /// An empty wishlist if the file does not exist yet
+    pub fn load(path: &Path) -> anyhow::Result<Wishlist> {
+        if !path.exists() {
+            return Ok(Wishlist::default());
+        }
+        let raw = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        serde_json::from_str(&raw).with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).context("Failed to create cache directory")?;
+        }
+        let json = serde_json::to_string_pretty(self).context("Failed to serialize wishlist")?;
+        fs::write(path, json).with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Adds to the quantity of an existing entry
+    pub fn add(&mut self, unique_name: &str, quantity: i64) {
+        match self
+            .items
+            .iter_mut()
+            .find(|entry| entry.unique_name == unique_name)
+        {
+            Some(entry) => entry.quantity += quantity,
+            None => self.items.push(WishlistEntry {
+                unique_name: unique_name.to_string(),
+                quantity,
+            }),
+        }
+    }
+
+    /// Whether the item was on the wishlist
+    pub fn remove(&mut self, unique_name: &str) -> bool {
+        let before = self.items.len();
+        self.items.retain(|entry| entry.unique_name != unique_name);
+        self.items.len() != before
+    }
+}
+
+/// A missing ingredient summed over the whole wishlist.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WishlistShortfall {
+    pub unique_name: String,
+    pub name: String,
+    pub kind: IngredientKind,
+    pub required: i64,
+    pub owned: i64,
+    /// Wishlist items that use it, by name
+    pub needed_for: Vec<String>,
+    /// Sorted by chance, best first
+    pub drops: Vec<DropInfo>,
+}
+
+impl WishlistShortfall {
+    pub fn missing(&self) -> i64 {
+        (self.required - self.owned).max(0)
+    }
+}
+
+/// What every wishlisted item still needs, with owned resources deducted once.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WishlistReport {
+    pub plans: Vec<CraftPlan>,
+    /// Sorted by name
+    pub missing: Vec<WishlistShortfall>,
+    pub credits: i64,
+    pub credits_owned: i64,
+    /// Entries the catalog does not know or that have no recipe
+    pub unknown: Vec<String>,
+}
+
+impl WishlistReport {
+    /// Owned ingredients are shared by the whole wishlist: each plan only gets what the
+    /// earlier ones left, so intermediates such as Forma are expanded for the combined shortfall
+    pub fn build(wishlist: &Wishlist, inventory: &Inventory, catalog: &Catalog) -> WishlistReport {
+        let mut report = WishlistReport {
+            credits_owned: inventory.regular_credits,
+            ..Default::default()
+        };
+        let mut stock = Stock::new(inventory);
+        for entry in &wishlist.items {
+            match CraftPlan::from_stock(&entry.unique_name, entry.quantity, &mut stock, catalog) {
+                Some(plan) => report.plans.push(plan),
+                None => report.unknown.push(entry.unique_name.clone()),
+            }
+        }
+        report.credits = report.plans.iter().map(|plan| plan.credits).sum();
+
+        let mut leaves: BTreeMap<&str, Shortfall> = BTreeMap::new();
+        let mut needed_for: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+        for plan in &report.plans {
+            let mut own: BTreeMap<&str, Shortfall> = BTreeMap::new();
+            crafting::collect_leaves(&plan.ingredients, &mut own);
+            // The first plan to use an ingredient saw everything owned of it
+            for (unique_name, shortfall) in own {
+                needed_for
+                    .entry(unique_name)
+                    .or_default()
+                    .push(plan.name.clone());
+                leaves
+                    .entry(unique_name)
+                    .and_modify(|total| total.required += shortfall.required)
+                    .or_insert(shortfall);
+            }
+        }
+
+        report.missing = leaves
+            .into_values()
+            .filter(|shortfall| shortfall.missing() > 0)
+            .map(|shortfall| {
+                let mut drops = catalog.drops(&shortfall.unique_name);
+                drops.sort_by(|a, b| b.chance.total_cmp(&a.chance));
+                WishlistShortfall {
+                    needed_for: needed_for
+                        .remove(shortfall.unique_name.as_str())
+                        .unwrap_or_default(),
+                    unique_name: shortfall.unique_name,
+                    name: shortfall.name,
+                    kind: shortfall.kind,
+                    required: shortfall.required,
+                    owned: shortfall.owned,
+                    drops,
+                }
+            })
+            .collect();
+        report.missing.sort_by(|a, b| a.name.cmp(&b.name));
+        report
+    }
+
+    pub fn credits_missing(&self) -> i64 {
+        (self.credits - self.credits_owned).max(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::tests::load_test_inventory;
+    use crate::itemdata::catalog::CatalogItem;
+    use crate::itemdata::catalog::tests::{forma, neural_sensors, sensor_gun};
+    use crate::itemdata::primary::{self, Primary};
+
+    fn catalog() -> Catalog {
+        [
+            sensor_gun("Gun A", "/Lotus/Weapons/Test/GunA"),
+            sensor_gun("Gun B", "/Lotus/Weapons/Test/GunB"),
+            neural_sensors(),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    #[test]
+    fn test_wishlist_add_remove_save() {
+        let mut wishlist = Wishlist::default();
+        wishlist.add("/Lotus/Weapons/Test/GunA", 1);
+        wishlist.add("/Lotus/Weapons/Test/GunB", 1);
+        wishlist.add("/Lotus/Weapons/Test/GunA", 2);
+        assert_eq!(wishlist.items.len(), 2);
+        assert_eq!(wishlist.items[0].quantity, 3);
+        assert!(wishlist.remove("/Lotus/Weapons/Test/GunB"));
+        assert!(!wishlist.remove("/Lotus/Weapons/Test/GunB"));
+
+        let path = std::env::temp_dir().join(format!(
+            "wf-info-2-wishlist-{}/wishlist.json",
+            std::process::id()
+        ));
+        assert_eq!(Wishlist::load(&path).unwrap(), Wishlist::default());
+        wishlist.save(&path).unwrap();
+        let loaded = Wishlist::load(&path).unwrap();
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+        assert_eq!(loaded, wishlist);
+    }
+
+    #[test]
+    fn test_wishlist_report() {
+        let catalog = catalog();
+        let mut inventory = load_test_inventory();
+        inventory.regular_credits = 20_000;
+
+        // 353 Neural Sensors owned, enough for either gun but not for both
+        let mut wishlist = Wishlist::default();
+        wishlist.add("/Lotus/Weapons/Test/GunA", 1);
+        wishlist.add("/Lotus/Weapons/Test/GunB", 1);
+        wishlist.add("/Lotus/Weapons/Test/Nothing", 1);
+
+        let report = WishlistReport::build(&wishlist, &inventory, &catalog);
+        assert_eq!(report.plans.len(), 2);
+        assert_eq!(report.unknown, vec!["/Lotus/Weapons/Test/Nothing"]);
+        assert_eq!(report.credits, 30_000);
+        assert_eq!(report.credits_missing(), 10_000);
+
+        let missing: Vec<(&str, i64)> = report
+            .missing
+            .iter()
+            .map(|shortfall| (shortfall.name.as_str(), shortfall.missing()))
+            .collect();
+        assert_eq!(
+            missing,
+            vec![
+                ("Gun A Blueprint", 1),
+                ("Gun B Blueprint", 1),
+                ("Neural Sensors", 47)
+            ]
+        );
+
+        let sensors = &report.missing[2];
+        assert_eq!(sensors.kind, IngredientKind::Resource);
+        assert_eq!((sensors.required, sensors.owned), (400, 353));
+        assert_eq!(sensors.needed_for, vec!["Gun A", "Gun B"]);
+        assert_eq!(sensors.drops.len(), 2);
+        assert_eq!(
+            sensors.drops[0].location,
+            "Jupiter/Themisto (Assassination)"
+        );
+        assert_eq!(report.missing[0].needed_for, vec!["Gun A"]);
+    }
+
+    #[test]
+    fn test_wishlist_shares_intermediates() {
+        let forma_gun = |name: &str| {
+            CatalogItem::Primary(Primary {
+                name: name.to_string(),
+                unique_name: format!("/Lotus/Weapons/Test/{}", name.replace(' ', "")),
+                components: vec![primary::Component {
+                    name: "Forma".to_string(),
+                    unique_name: "/Lotus/Types/Items/MiscItems/Forma".to_string(),
+                    item_count: 30,
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })
+        };
+        let catalog: Catalog = [forma_gun("Gun A"), forma_gun("Gun B"), forma()]
+            .into_iter()
+            .collect();
+        let inventory = load_test_inventory();
+
+        // 44 Forma owned, each gun needs 30
+        let mut wishlist = Wishlist::default();
+        wishlist.add("/Lotus/Weapons/Test/GunA", 1);
+        wishlist.add("/Lotus/Weapons/Test/GunB", 1);
+        let report = WishlistReport::build(&wishlist, &inventory, &catalog);
+
+        let missing: Vec<(&str, i64, i64)> = report
+            .missing
+            .iter()
+            .map(|shortfall| {
+                (
+                    shortfall.name.as_str(),
+                    shortfall.required,
+                    shortfall.missing(),
+                )
+            })
+            .collect();
+        // 16 Forma to build, one Forma blueprint is owned
+        assert_eq!(missing, vec![("Forma Blueprint", 16, 15)]);
+        assert!(report.plans[0].ingredients[0].children.is_empty());
+        assert_eq!(report.plans[1].ingredients[0].children[0].required, 16);
+    }
+}